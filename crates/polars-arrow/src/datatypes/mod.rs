@@ -60,6 +60,8 @@ pub enum ArrowDataType {
     UInt32,
     /// An [`u64`]
     UInt64,
+    /// An [`u128`]
+    UInt128,
     /// An 16-bit float
     Float16,
     /// A [`f32`]
@@ -293,6 +295,7 @@ impl ArrowDataType {
             UInt16 => PhysicalType::Primitive(PrimitiveType::UInt16),
             UInt32 => PhysicalType::Primitive(PrimitiveType::UInt32),
             UInt64 => PhysicalType::Primitive(PrimitiveType::UInt64),
+            UInt128 => PhysicalType::Primitive(PrimitiveType::UInt128),
             Float16 => PhysicalType::Primitive(PrimitiveType::Float16),
             Float32 => PhysicalType::Primitive(PrimitiveType::Float32),
             Float64 => PhysicalType::Primitive(PrimitiveType::Float64),
@@ -417,6 +420,8 @@ impl ArrowDataType {
                 | D::UInt16
                 | D::UInt32
                 | D::UInt64
+                | D::UInt128
+                | D::Float16
                 | D::Float32
                 | D::Float64
                 | D::Decimal(_, _)
@@ -448,6 +453,7 @@ impl ArrowDataType {
             | D::UInt32
             | D::UInt64
             | D::Int128
+            | D::UInt128
             | D::Float16
             | D::Float32
             | D::Float64
@@ -494,6 +500,7 @@ impl From<IntegerType> for ArrowDataType {
             IntegerType::UInt16 => ArrowDataType::UInt16,
             IntegerType::UInt32 => ArrowDataType::UInt32,
             IntegerType::UInt64 => ArrowDataType::UInt64,
+            IntegerType::UInt128 => ArrowDataType::UInt128,
         }
     }
 }
@@ -516,7 +523,7 @@ impl From<PrimitiveType> for ArrowDataType {
             PrimitiveType::Float64 => ArrowDataType::Float64,
             PrimitiveType::DaysMs => ArrowDataType::Interval(IntervalUnit::DayTime),
             PrimitiveType::MonthDayNano => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
-            PrimitiveType::UInt128 => unimplemented!(),
+            PrimitiveType::UInt128 => ArrowDataType::UInt128,
         }
     }
 }
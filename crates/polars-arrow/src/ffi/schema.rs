@@ -273,6 +273,7 @@ unsafe fn to_dtype(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
         "l" => ArrowDataType::Int64,
         "L" => ArrowDataType::UInt64,
         "_pli128" => ArrowDataType::Int128,
+        "_plu128" => ArrowDataType::UInt128,
         "e" => ArrowDataType::Float16,
         "f" => ArrowDataType::Float32,
         "g" => ArrowDataType::Float64,
@@ -292,6 +293,7 @@ unsafe fn to_dtype(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
         "tDn" => ArrowDataType::Duration(TimeUnit::Nanosecond),
         "tiM" => ArrowDataType::Interval(IntervalUnit::YearMonth),
         "tiD" => ArrowDataType::Interval(IntervalUnit::DayTime),
+        "tin" => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
         "vu" => ArrowDataType::Utf8View,
         "vz" => ArrowDataType::BinaryView,
         "+l" => {
@@ -462,6 +464,7 @@ fn to_format(dtype: &ArrowDataType) -> String {
         ArrowDataType::UInt64 => "L".to_string(),
         // Doesn't exist in arrow, '_pl' prefixed is Polars specific
         ArrowDataType::Int128 => "_pli128".to_string(),
+        ArrowDataType::UInt128 => "_plu128".to_string(),
         ArrowDataType::Float16 => "e".to_string(),
         ArrowDataType::Float32 => "f".to_string(),
         ArrowDataType::Float64 => "g".to_string(),
@@ -487,9 +490,7 @@ fn to_format(dtype: &ArrowDataType) -> String {
         ArrowDataType::Duration(TimeUnit::Nanosecond) => "tDn".to_string(),
         ArrowDataType::Interval(IntervalUnit::YearMonth) => "tiM".to_string(),
         ArrowDataType::Interval(IntervalUnit::DayTime) => "tiD".to_string(),
-        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
-            todo!("Spec for FFI for MonthDayNano still not defined.")
-        },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => "tin".to_string(),
         ArrowDataType::Timestamp(unit, tz) => {
             let unit = match unit {
                 TimeUnit::Second => "s",
@@ -725,7 +726,7 @@ mod tests {
         for interval_type in [
             IntervalUnit::DayTime,
             IntervalUnit::YearMonth,
-            //IntervalUnit::MonthDayNano, // not yet defined on the C data interface
+            IntervalUnit::MonthDayNano,
         ] {
             dts.push(ArrowDataType::Interval(interval_type));
         }
@@ -73,6 +73,7 @@ fn deserialize_integer(int: arrow_format::ipc::IntRef) -> PolarsResult<IntegerTy
         (64, true) => IntegerType::Int64,
         (64, false) => IntegerType::UInt64,
         (128, true) => IntegerType::Int128,
+        (128, false) => IntegerType::UInt128,
         _ => polars_bail!(oos = "IPC: indexType can only be 8, 16, 32, 64 or 128."),
     })
 }
@@ -1,4 +1,6 @@
 use arrow::array::PrimitiveArray as PArr;
+use arrow::types::f16;
+use num_traits::{One, Zero};
 
 use super::PrimitiveArithmeticKernelImpl;
 use crate::arity::{prim_binary_values, prim_unary_values};
@@ -41,21 +43,21 @@ macro_rules! impl_float_arith_kernel {
             }
 
             fn prim_wrapping_add_scalar(lhs: PArr<$T>, rhs: $T) -> PArr<$T> {
-                if rhs == 0.0 {
+                if rhs.is_zero() {
                     return lhs;
                 }
                 prim_unary_values(lhs, |x| x + rhs)
             }
 
             fn prim_wrapping_sub_scalar(lhs: PArr<$T>, rhs: $T) -> PArr<$T> {
-                if rhs == 0.0 {
+                if rhs.is_zero() {
                     return lhs;
                 }
                 Self::prim_wrapping_add_scalar(lhs, -rhs)
             }
 
             fn prim_wrapping_sub_scalar_lhs(lhs: $T, rhs: PArr<$T>) -> PArr<$T> {
-                if lhs == 0.0 {
+                if lhs.is_zero() {
                     Self::prim_wrapping_neg(rhs)
                 } else {
                     prim_unary_values(rhs, |x| lhs - x)
@@ -64,9 +66,9 @@ macro_rules! impl_float_arith_kernel {
 
             fn prim_wrapping_mul_scalar(lhs: PArr<$T>, rhs: $T) -> PArr<$T> {
                 // No optimization for multiplication by zero, would invalidate NaNs/infinities.
-                if rhs == 1.0 {
+                if rhs.is_one() {
                     lhs
-                } else if rhs == -1.0 {
+                } else if rhs == -<$T>::one() {
                     Self::prim_wrapping_neg(lhs)
                 } else {
                     prim_unary_values(lhs, |x| x * rhs)
@@ -74,7 +76,7 @@ macro_rules! impl_float_arith_kernel {
             }
 
             fn prim_wrapping_floor_div_scalar(lhs: PArr<$T>, rhs: $T) -> PArr<$T> {
-                let inv = 1.0 / rhs;
+                let inv = <$T>::one() / rhs;
                 prim_unary_values(lhs, |x| (x * inv).floor())
             }
 
@@ -83,7 +85,7 @@ macro_rules! impl_float_arith_kernel {
             }
 
             fn prim_wrapping_trunc_div_scalar(lhs: PArr<$T>, rhs: $T) -> PArr<$T> {
-                let inv = 1.0 / rhs;
+                let inv = <$T>::one() / rhs;
                 prim_unary_values(lhs, |x| (x * inv).trunc())
             }
 
@@ -92,7 +94,7 @@ macro_rules! impl_float_arith_kernel {
             }
 
             fn prim_wrapping_mod_scalar(lhs: PArr<$T>, rhs: $T) -> PArr<$T> {
-                let inv = 1.0 / rhs;
+                let inv = <$T>::one() / rhs;
                 prim_unary_values(lhs, |x| x - rhs * (x * inv).floor())
             }
 
@@ -109,7 +111,7 @@ macro_rules! impl_float_arith_kernel {
             }
 
             fn prim_true_div_scalar(lhs: PArr<$T>, rhs: $T) -> PArr<Self::TrueDivT> {
-                Self::prim_wrapping_mul_scalar(lhs, 1.0 / rhs)
+                Self::prim_wrapping_mul_scalar(lhs, <$T>::one() / rhs)
             }
 
             fn prim_true_div_scalar_lhs(lhs: $T, rhs: PArr<$T>) -> PArr<Self::TrueDivT> {
@@ -119,5 +121,6 @@ macro_rules! impl_float_arith_kernel {
     };
 }
 
+impl_float_arith_kernel!(f16);
 impl_float_arith_kernel!(f32);
 impl_float_arith_kernel!(f64);
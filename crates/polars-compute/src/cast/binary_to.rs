@@ -36,6 +36,8 @@ impl_parse!(u64);
 
 #[cfg(feature = "dtype-i128")]
 impl_parse!(i128);
+#[cfg(feature = "dtype-u128")]
+impl_parse!(u128);
 
 impl Parse for f32 {
     fn parse(val: &[u8]) -> Option<Self>
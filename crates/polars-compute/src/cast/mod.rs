@@ -21,6 +21,7 @@ use arrow::array::*;
 use arrow::datatypes::*;
 use arrow::match_integer_type;
 use arrow::offset::{Offset, Offsets};
+use arrow::types::f16;
 pub use binview_to::binview_to_primitive_dyn;
 use binview_to::{
     binview_to_dictionary, utf8view_to_date32_dyn, utf8view_to_dictionary,
@@ -458,6 +459,25 @@ pub fn cast(
             cast_list_uint8_to_binary::<i64>(array.as_any().downcast_ref().unwrap())
                 .map(|arr| arr.boxed())
         },
+        // Float16 has no dedicated kernels, everything goes through Float32.
+        (Float16, _) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            match to_type {
+                Float32 => Ok(f16_to_f32(from).boxed()),
+                BinaryView => Ok(primitive_to_binview::<f16>(from).boxed()),
+                Utf8View => {
+                    Ok(
+                        unsafe { primitive_to_binview::<f16>(from).to_utf8view_unchecked() }
+                            .boxed(),
+                    )
+                },
+                _ => cast(&f16_to_f32(from), to_type, options),
+            }
+        },
+        (_, Float16) => {
+            let array = cast(array, &Float32, options)?;
+            Ok(f32_to_f16(array.as_any().downcast_ref().unwrap()).boxed())
+        },
         (BinaryView, _) => match to_type {
             Utf8View => array
                 .as_any()
@@ -535,6 +555,8 @@ pub fn cast(
                 Int64 => utf8view_to_primitive_dyn::<i64>(arr, to_type, options),
                 #[cfg(feature = "dtype-i128")]
                 Int128 => utf8view_to_primitive_dyn::<i128>(arr, to_type, options),
+                #[cfg(feature = "dtype-u128")]
+                UInt128 => utf8view_to_primitive_dyn::<u128>(arr, to_type, options),
                 Float32 => utf8view_to_primitive_dyn::<f32>(arr, to_type, options),
                 Float64 => utf8view_to_primitive_dyn::<f64>(arr, to_type, options),
                 Timestamp(time_unit, None) => {
@@ -569,6 +591,8 @@ pub fn cast(
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
             #[cfg(feature = "dtype-i128")]
             Int128 => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => primitive_to_boolean_dyn::<u128>(array, to_type.clone()),
             Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
             Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
             Decimal(_, _) => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
@@ -587,6 +611,8 @@ pub fn cast(
             Int64 => boolean_to_primitive_dyn::<i64>(array),
             #[cfg(feature = "dtype-i128")]
             Int128 => boolean_to_primitive_dyn::<i128>(array),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => boolean_to_primitive_dyn::<u128>(array),
             Float32 => boolean_to_primitive_dyn::<f32>(array),
             Float64 => boolean_to_primitive_dyn::<f64>(array),
             Utf8View => boolean_to_utf8view_dyn(array),
@@ -659,6 +685,8 @@ pub fn cast(
             Int64 => binary_to_primitive_dyn::<i64, i64>(array, to_type, options),
             #[cfg(feature = "dtype-i128")]
             Int128 => binary_to_primitive_dyn::<i64, i128>(array, to_type, options),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => binary_to_primitive_dyn::<i64, u128>(array, to_type, options),
             Float32 => binary_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => binary_to_primitive_dyn::<i64, f64>(array, to_type, options),
             Binary => {
@@ -698,6 +726,8 @@ pub fn cast(
         (UInt8, Int64) => primitive_to_primitive_dyn::<u8, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt8, Int128) => primitive_to_primitive_dyn::<u8, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt8, UInt128) => primitive_to_primitive_dyn::<u8, u128>(array, to_type, as_options),
         (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type, as_options),
         (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type, as_options),
         (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s),
@@ -711,6 +741,8 @@ pub fn cast(
         (UInt16, Int64) => primitive_to_primitive_dyn::<u16, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt16, Int128) => primitive_to_primitive_dyn::<u16, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt16, UInt128) => primitive_to_primitive_dyn::<u16, u128>(array, to_type, as_options),
         (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type, as_options),
         (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type, as_options),
         (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s),
@@ -724,6 +756,8 @@ pub fn cast(
         (UInt32, Int64) => primitive_to_primitive_dyn::<u32, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt32, Int128) => primitive_to_primitive_dyn::<u32, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt32, UInt128) => primitive_to_primitive_dyn::<u32, u128>(array, to_type, as_options),
         (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type, as_options),
         (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type, as_options),
         (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s),
@@ -737,6 +771,8 @@ pub fn cast(
         (UInt64, Int64) => primitive_to_primitive_dyn::<u64, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt64, Int128) => primitive_to_primitive_dyn::<u64, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt64, UInt128) => primitive_to_primitive_dyn::<u64, u128>(array, to_type, as_options),
         (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type, as_options),
         (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type, as_options),
         (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s),
@@ -750,6 +786,8 @@ pub fn cast(
         (Int8, Int64) => primitive_to_primitive_dyn::<i8, i64>(array, to_type, as_options),
         #[cfg(feature = "dtype-i128")]
         (Int8, Int128) => primitive_to_primitive_dyn::<i8, i128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-u128")]
+        (Int8, UInt128) => primitive_to_primitive_dyn::<i8, u128>(array, to_type, options),
         (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type, as_options),
         (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type, as_options),
         (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s),
@@ -763,6 +801,8 @@ pub fn cast(
         (Int16, Int64) => primitive_to_primitive_dyn::<i16, i64>(array, to_type, as_options),
         #[cfg(feature = "dtype-i128")]
         (Int16, Int128) => primitive_to_primitive_dyn::<i16, i128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-u128")]
+        (Int16, UInt128) => primitive_to_primitive_dyn::<i16, u128>(array, to_type, options),
         (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type, as_options),
         (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type, as_options),
         (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s),
@@ -776,6 +816,8 @@ pub fn cast(
         (Int32, Int64) => primitive_to_primitive_dyn::<i32, i64>(array, to_type, as_options),
         #[cfg(feature = "dtype-i128")]
         (Int32, Int128) => primitive_to_primitive_dyn::<i32, i128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-u128")]
+        (Int32, UInt128) => primitive_to_primitive_dyn::<i32, u128>(array, to_type, options),
         (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type, as_options),
         (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type, as_options),
         (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s),
@@ -789,6 +831,8 @@ pub fn cast(
         (Int64, Int32) => primitive_to_primitive_dyn::<i64, i32>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (Int64, Int128) => primitive_to_primitive_dyn::<i64, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (Int64, UInt128) => primitive_to_primitive_dyn::<i64, u128>(array, to_type, options),
         (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type, options),
         (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type, as_options),
         (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s),
@@ -816,9 +860,35 @@ pub fn cast(
         #[cfg(feature = "dtype-i128")]
         (Int128, Decimal(p, s)) => integer_to_decimal_dyn::<i128>(array, *p, *s),
 
-        (Float16, Float32) => {
-            let from = array.as_any().downcast_ref().unwrap();
-            Ok(f16_to_f32(from).boxed())
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt8) => primitive_to_primitive_dyn::<u128, u8>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt16) => primitive_to_primitive_dyn::<u128, u16>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt32) => primitive_to_primitive_dyn::<u128, u32>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt64) => primitive_to_primitive_dyn::<u128, u64>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int8) => primitive_to_primitive_dyn::<u128, i8>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int16) => primitive_to_primitive_dyn::<u128, i16>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int32) => primitive_to_primitive_dyn::<u128, i32>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int64) => primitive_to_primitive_dyn::<u128, i64>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Float32) => primitive_to_primitive_dyn::<u128, f32>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Float64) => primitive_to_primitive_dyn::<u128, f64>(array, to_type, as_options),
+        #[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+        (UInt128, Int128) => primitive_to_primitive_dyn::<u128, i128>(array, to_type, options),
+        #[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+        (Int128, UInt128) => primitive_to_primitive_dyn::<i128, u128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Decimal(p, s)) => {
+            // Values above `i128::MAX` don't fit any decimal, so they become null.
+            let array = primitive_to_primitive_dyn::<u128, i128>(array, &Int128, options)?;
+            integer_to_decimal_dyn::<i128>(array.as_ref(), *p, *s)
         },
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
@@ -830,6 +900,8 @@ pub fn cast(
         (Float32, Int32) => primitive_to_primitive_dyn::<f32, i32>(array, to_type, options),
         (Float32, Int64) => primitive_to_primitive_dyn::<f32, i64>(array, to_type, options),
         (Float32, Int128) => primitive_to_primitive_dyn::<f32, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (Float32, UInt128) => primitive_to_primitive_dyn::<f32, u128>(array, to_type, options),
         (Float32, Float64) => primitive_to_primitive_dyn::<f32, f64>(array, to_type, as_options),
         (Float32, Decimal(p, s)) => float_to_decimal_dyn::<f32>(array, *p, *s),
 
@@ -842,6 +914,8 @@ pub fn cast(
         (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type, options),
         (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type, options),
         (Float64, Int128) => primitive_to_primitive_dyn::<f64, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (Float64, UInt128) => primitive_to_primitive_dyn::<f64, u128>(array, to_type, options),
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
         (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s),
 
@@ -854,6 +928,8 @@ pub fn cast(
         (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array),
         (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array),
         (Decimal(_, _), Int128) => decimal_to_integer_dyn::<i128>(array),
+        #[cfg(feature = "dtype-u128")]
+        (Decimal(_, _), UInt128) => decimal_to_integer_dyn::<u128>(array),
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
         (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
@@ -974,6 +1050,9 @@ fn from_to_binview(
         Int32 => primitive_to_binview_dyn::<i32>(array),
         Int64 => primitive_to_binview_dyn::<i64>(array),
         Int128 => primitive_to_binview_dyn::<i128>(array),
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => primitive_to_binview_dyn::<u128>(array),
+        Float16 => primitive_to_binview_dyn::<f16>(array),
         Float32 => primitive_to_binview_dyn::<f32>(array),
         Float64 => primitive_to_binview_dyn::<f64>(array),
         Binary => binary_to_binview::<i32>(array.as_any().downcast_ref().unwrap()),
@@ -45,6 +45,7 @@ impl_ser_primitive!(u8);
 impl_ser_primitive!(u16);
 impl_ser_primitive!(u32);
 impl_ser_primitive!(u64);
+impl_ser_primitive!(u128);
 
 impl SerPrimitive for f32 {
     fn write(f: &mut Vec<u8>, val: Self) -> usize
@@ -58,6 +59,20 @@ impl SerPrimitive for f32 {
     }
 }
 
+impl SerPrimitive for f16 {
+    fn write(f: &mut Vec<u8>, val: Self) -> usize
+    where
+        Self: Sized,
+    {
+        // Format the shortest f32 that rounds back to this value, otherwise
+        // we would print the exact binary expansion (e.g. 0.099975586 for 0.1).
+        let mut buffer = ryu::Buffer::new();
+        let value = buffer.format(val.to_shortest_f32());
+        f.extend_from_slice(value.as_bytes());
+        value.len()
+    }
+}
+
 impl SerPrimitive for f64 {
     fn write(f: &mut Vec<u8>, val: Self) -> usize
     where
@@ -594,6 +609,11 @@ pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
     unary(from, |x| x.to_f32(), ArrowDataType::Float32)
 }
 
+/// Casts f32 into f16, rounding to the nearest representable value.
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, ArrowDataType::Float16)
+}
+
 /// Returns a [`Utf8Array`] where every element is the utf8 representation of the number.
 pub(super) fn primitive_to_binview<T: NativeType + SerPrimitive>(
     from: &PrimitiveArray<T>,
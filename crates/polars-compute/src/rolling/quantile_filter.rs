@@ -6,7 +6,7 @@ use std::fmt::{Debug, Formatter};
 use std::ops::{Add, Div, Mul, Sub};
 
 use arrow::pushable::Pushable;
-use arrow::types::NativeType;
+use arrow::types::{NativeType, f16};
 use num_traits::NumCast;
 use polars_utils::index::{Bounded, Indexable, NullCount};
 use polars_utils::nulls::IsNull;
@@ -539,6 +539,8 @@ impl SealedRolling for u16 {}
 impl SealedRolling for u32 {}
 impl SealedRolling for u64 {}
 impl SealedRolling for i128 {}
+impl SealedRolling for u128 {}
+impl SealedRolling for f16 {}
 impl SealedRolling for f32 {}
 impl SealedRolling for f64 {}
 
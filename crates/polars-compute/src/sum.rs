@@ -4,7 +4,7 @@ use std::simd::prelude::*;
 
 use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::bitmask::BitMask;
-use arrow::types::NativeType;
+use arrow::types::{NativeType, f16};
 use num_traits::Zero;
 
 macro_rules! wrapping_impl {
@@ -41,6 +41,7 @@ wrapping_impl!(WrappingAdd, wrapping_add, i64);
 wrapping_impl!(WrappingAdd, wrapping_add, isize);
 wrapping_impl!(WrappingAdd, wrapping_add, i128);
 
+wrapping_impl!(WrappingAdd, add, f16);
 wrapping_impl!(WrappingAdd, add, f32);
 wrapping_impl!(WrappingAdd, add, f64);
 
@@ -138,6 +139,17 @@ impl WrappingSum for i128 {
     }
 }
 
+#[cfg(feature = "simd")]
+impl WrappingSum for f16 {
+    fn wrapping_sum(vals: &[Self]) -> Self {
+        vals.iter().copied().fold(f16::ZERO, |a, b| a + b)
+    }
+
+    fn wrapping_sum_with_validity(vals: &[Self], mask: &BitMask) -> Self {
+        wrapping_sum_with_mask_scalar(vals, mask)
+    }
+}
+
 pub trait WrappingSum: Sized {
     fn wrapping_sum(vals: &[Self]) -> Self;
     fn wrapping_sum_with_validity(vals: &[Self], mask: &BitMask) -> Self;
@@ -1,13 +1,64 @@
 use super::*;
+use crate::prelude::arity::broadcast_try_binary_elementwise;
+
+/// The maximum precision a decimal backed by an `i128` can hold.
+const MAX_DECIMAL_PRECISION: usize = 38;
+
+#[inline]
+fn pow10(exp: usize) -> Option<i128> {
+    10_i128.checked_pow(exp as u32)
+}
+
+/// Apply `op` on the physical values of `lhs` and `rhs` and check that every result fits in
+/// `precision` (or the maximum precision if unknown).
+fn checked_decimal_arithmetic<F>(
+    lhs: &DecimalChunked,
+    rhs: &DecimalChunked,
+    precision: Option<usize>,
+    scale: usize,
+    op_name: &str,
+    mut op: F,
+) -> PolarsResult<DecimalChunked>
+where
+    F: FnMut(i128, i128) -> Option<Option<i128>>,
+{
+    let bound = pow10(
+        precision
+            .unwrap_or(MAX_DECIMAL_PRECISION)
+            .min(MAX_DECIMAL_PRECISION),
+    )
+    .unwrap() as u128;
+    let out: Int128Chunked =
+        broadcast_try_binary_elementwise(&lhs.phys, &rhs.phys, |l, r| match (l, r) {
+            (Some(l), Some(r)) => match op(l, r) {
+                Some(Some(v)) if v.unsigned_abs() < bound => Ok(Some(v)),
+                Some(None) => Ok(None),
+                _ => Err(polars_err!(
+                    ComputeError: "decimal overflow in {}: result does not fit in {:?}",
+                    op_name, DataType::Decimal(precision, Some(scale))
+                )),
+            },
+            _ => Ok(None),
+        })?;
+    Ok(out.into_decimal_unchecked(precision, scale))
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.phys + &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
+        let fl = pow10(scale - self.scale());
+        let fr = pow10(scale - rhs.scale());
+        checked_decimal_arithmetic(self, rhs, precision, scale, "addition", |l, r| {
+            Some(l.checked_mul(fl?)?.checked_add(r.checked_mul(fr?)?))
+        })
     }
 }
 
@@ -16,9 +67,17 @@ impl Sub for &DecimalChunked {
 
     fn sub(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.phys - &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
+        let fl = pow10(scale - self.scale());
+        let fr = pow10(scale - rhs.scale());
+        checked_decimal_arithmetic(self, rhs, precision, scale, "subtraction", |l, r| {
+            Some(l.checked_mul(fl?)?.checked_sub(r.checked_mul(fr?)?))
+        })
     }
 }
 
@@ -27,7 +86,10 @@ impl Mul for &DecimalChunked {
 
     fn mul(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_mul(self.scale(), rhs.scale());
-        Ok((&self.phys * &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_mul(self.precision(), rhs.precision());
+        checked_decimal_arithmetic(self, rhs, precision, scale, "multiplication", |l, r| {
+            Some(l.checked_mul(r))
+        })
     }
 }
 
@@ -36,8 +98,21 @@ impl Div for &DecimalChunked {
 
     fn div(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_div(self.scale());
-        let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.phys / &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_div(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
+        let factor = pow10(scale + rhs.scale() - self.scale());
+        checked_decimal_arithmetic(self, rhs, precision, scale, "division", |l, r| {
+            if r == 0 {
+                // Division by zero yields null, like the integer kernels.
+                return Some(None);
+            }
+            // Truncates towards zero.
+            Some(l.checked_mul(factor?)?.checked_div(r))
+        })
     }
 }
 
@@ -54,3 +129,95 @@ pub fn _get_decimal_scale_div(scale_left: usize) -> usize {
     // Follow postgres and MySQL adding a fixed scale increment of 4
     scale_left + 4
 }
+
+// The precision rules follow the SQL standard, capped at the maximum precision of 38. If either
+// precision is unknown, so is the result precision.
+pub fn _get_decimal_precision_add_sub(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    precision_right: Option<usize>,
+    scale_right: usize,
+) -> Option<usize> {
+    let int_digits = precision_left?
+        .saturating_sub(scale_left)
+        .max(precision_right?.saturating_sub(scale_right));
+    let scale = _get_decimal_scale_add_sub(scale_left, scale_right);
+    Some((int_digits + scale + 1).min(MAX_DECIMAL_PRECISION))
+}
+
+pub fn _get_decimal_precision_mul(
+    precision_left: Option<usize>,
+    precision_right: Option<usize>,
+) -> Option<usize> {
+    Some((precision_left? + precision_right? + 1).min(MAX_DECIMAL_PRECISION))
+}
+
+pub fn _get_decimal_precision_div(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    precision_right: Option<usize>,
+    scale_right: usize,
+) -> Option<usize> {
+    // Dividing by the smallest non-zero right value scales the integer digits up by `scale_right`.
+    precision_right?;
+    let int_digits = precision_left?.saturating_sub(scale_left) + scale_right;
+    let scale = _get_decimal_scale_div(scale_left);
+    Some((int_digits + scale).min(MAX_DECIMAL_PRECISION))
+}
+
+// The mean of a decimal gains the same scale increment as division, so that e.g. the mean of
+// `Decimal(10, 2)` is `Decimal(14, 6)`.
+pub fn _get_decimal_precision_mean(precision: Option<usize>) -> Option<usize> {
+    Some((precision? + 4).min(MAX_DECIMAL_PRECISION))
+}
+
+pub fn _get_decimal_scale_mean(scale: usize) -> usize {
+    _get_decimal_scale_div(scale)
+}
+
+/// Compute the mean of `count` decimals summing to `sum` at `scale`, at the scale given by
+/// [`_get_decimal_scale_mean`]. The result is truncated towards zero, like decimal division.
+///
+/// Returns `None` if `count` is zero or the result does not fit in an `i128`.
+pub fn _decimal_mean(sum: i128, count: usize, scale: usize) -> Option<i128> {
+    if count == 0 {
+        return None;
+    }
+    let factor = pow10(_get_decimal_scale_mean(scale) - scale)?;
+    let count = count as i128;
+    // Split off the remainder first so that only the result itself can overflow.
+    let (quot, rem) = (sum / count, sum % count);
+    quot.checked_mul(factor)?.checked_add(rem * factor / count)
+}
+
+impl DecimalChunked {
+    /// The sum of the physical values, raising an error instead of wrapping on overflow.
+    pub fn checked_sum(&self) -> PolarsResult<i128> {
+        self.physical()
+            .downcast_iter()
+            .try_fold(0i128, |acc, arr| {
+                arr.non_null_values_iter()
+                    .try_fold(acc, |acc, v| acc.checked_add(v))
+            })
+            .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in sum"))
+    }
+
+    /// The mean as a decimal, see [`_decimal_mean`].
+    pub fn mean_decimal(&self) -> PolarsResult<Option<i128>> {
+        let count = self.len() - self.null_count();
+        if count == 0 {
+            return Ok(None);
+        }
+        _decimal_mean(self.checked_sum()?, count, self.scale())
+            .map(Some)
+            .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in mean"))
+    }
+
+    /// The dtype of [`DecimalChunked::mean_decimal`].
+    pub fn mean_dtype(&self) -> DataType {
+        DataType::Decimal(
+            _get_decimal_precision_mean(self.precision()),
+            Some(_get_decimal_scale_mean(self.scale())),
+        )
+    }
+}
@@ -112,6 +112,8 @@ fn cast_impl_inner(
         Duration(tu) => out.into_duration(*tu),
         #[cfg(feature = "dtype-time")]
         Time => out.into_time(),
+        #[cfg(feature = "dtype-interval")]
+        Interval => out.into_interval(),
         #[cfg(feature = "dtype-decimal")]
         Decimal(precision, scale) => out.into_decimal(*precision, scale.unwrap_or(0))?,
         _ => out,
@@ -493,6 +495,11 @@ impl ChunkCast for ListChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => ca
+                .into_owned()
+                .into_map()?
+                .cast_with_options(dtype, options),
             #[cfg(feature = "dtype-u8")]
             Binary => {
                 polars_ensure!(
@@ -1,5 +1,6 @@
 use arrow::legacy::kernels::set::set_at_nulls;
 use num_traits::Float;
+use polars_utils::float16::canonical_f16;
 use polars_utils::total_ord::{canonical_f32, canonical_f64};
 
 use crate::prelude::arity::unary_elementwise_values;
@@ -37,6 +38,13 @@ pub trait Canonical {
     fn canonical(self) -> Self;
 }
 
+impl Canonical for f16 {
+    #[inline]
+    fn canonical(self) -> Self {
+        canonical_f16(self)
+    }
+}
+
 impl Canonical for f32 {
     #[inline]
     fn canonical(self) -> Self {
@@ -54,6 +54,15 @@ impl LogicalType for DurationChunked {
                 };
                 Ok(out.into_duration(to_unit).into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ns = self.cast_with_options(&Duration(Nanoseconds), cast_options)?;
+                let phys = ns
+                    .duration()?
+                    .physical()
+                    .cast_with_options(&Int128, cast_options)?;
+                Ok(phys.i128()?.clone().into_interval().into_series())
+            },
             dt if dt.is_primitive_numeric() => self.phys.cast_with_options(dtype, cast_options),
             dt => {
                 polars_bail!(
@@ -0,0 +1,96 @@
+use super::*;
+use crate::fmt::fmt_interval_string;
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+/// Pack an interval into its physical `i128` representation.
+///
+/// The interval is stored as `months * 2^96 + days * 2^64 + nanoseconds`, so that the zero
+/// interval maps to `0` and integer ordering matches the ordering of
+/// `(months, days, nanoseconds)`.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96)
+        .wrapping_add((v.days() as i128) << 64)
+        .wrapping_add(v.ns() as i128)
+}
+
+/// Unpack an interval from its physical `i128` representation.
+#[inline]
+pub fn i128_to_interval(v: i128) -> months_days_ns {
+    let ns = v as i64;
+    let rest = v.wrapping_sub(ns as i128) >> 64;
+    let days = rest as i32;
+    let months = (rest.wrapping_sub(days as i128) >> 32) as i32;
+    months_days_ns::new(months, days, ns)
+}
+
+impl Int128Chunked {
+    pub fn into_interval(self) -> IntervalChunked {
+        // SAFETY: every i128 is a valid packed interval.
+        unsafe { IntervalChunked::new_logical(self, DataType::Interval) }
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(|av| av.as_interval())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i).as_interval()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Interval => Ok(self.clone().into_series()),
+            DataType::Int128 => self.phys.cast_with_options(dtype, cast_options),
+            DataType::String => Ok(self
+                .phys
+                .apply_into_string_amortized(|v, buf| {
+                    fmt_interval_string(buf, i128_to_interval(v)).unwrap()
+                })
+                .into_series()),
+            dt => polars_bail!(
+                InvalidOperation: "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+impl IntervalChunked {
+    /// The months component of every interval.
+    pub fn months(&self) -> Int32Chunked {
+        self.phys
+            .apply_nonnull_values_generic(DataType::Int32, |v| i128_to_interval(v).months())
+    }
+
+    /// The days component of every interval.
+    pub fn days(&self) -> Int32Chunked {
+        self.phys
+            .apply_nonnull_values_generic(DataType::Int32, |v| i128_to_interval(v).days())
+    }
+
+    /// The nanoseconds component of every interval.
+    pub fn nanoseconds(&self) -> Int64Chunked {
+        self.phys
+            .apply_nonnull_values_generic(DataType::Int64, |v| i128_to_interval(v).ns())
+    }
+
+    /// Negate every component of every interval.
+    pub fn negate(&self) -> Self {
+        self.phys
+            .apply_values(|v| interval_to_i128(-i128_to_interval(v)))
+            .into_interval()
+    }
+}
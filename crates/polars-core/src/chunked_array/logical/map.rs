@@ -0,0 +1,119 @@
+use super::*;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+const MAP_KEY_NAME: PlSmallStr = PlSmallStr::from_static("key");
+const MAP_VALUE_NAME: PlSmallStr = PlSmallStr::from_static("value");
+
+impl ListChunked {
+    /// Interpret a list of two-field structs as a [`MapChunked`].
+    ///
+    /// The first struct field is taken as the key and the second as the value. The fields
+    /// are renamed to `key` and `value`.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let DataType::Struct(fields) = self.inner_dtype() else {
+            polars_bail!(
+                InvalidOperation: "cannot interpret a list of '{}' as a map, expected a struct with a key and a value field",
+                self.inner_dtype()
+            );
+        };
+        polars_ensure!(
+            fields.len() == 2,
+            InvalidOperation: "cannot interpret a list of '{}' as a map, expected a struct with a key and a value field",
+            self.inner_dtype()
+        );
+        let dtype = DataType::Map(
+            Box::new(fields[0].dtype().clone()),
+            Box::new(fields[1].dtype().clone()),
+        );
+
+        let ca = if fields[0].name() == &MAP_KEY_NAME && fields[1].name() == &MAP_VALUE_NAME {
+            self
+        } else {
+            self.apply_to_inner(&|s| {
+                let ca = s.struct_()?;
+                let mut fields = ca.fields_as_series();
+                fields[0].rename(MAP_KEY_NAME);
+                fields[1].rename(MAP_VALUE_NAME);
+                let mut out =
+                    StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+                out.zip_outer_validity(ca);
+                Ok(out.into_series())
+            })?
+        };
+
+        let keys = ca.get_inner();
+        polars_ensure!(
+            keys.struct_()?.fields_as_series()[0].null_count() == 0,
+            ComputeError: "map keys cannot be null"
+        );
+
+        // SAFETY: the entries are `{key, value}` structs matching `dtype`.
+        Ok(unsafe { MapChunked::new_logical(ca, dtype) })
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Map(key, value) => {
+                if dtype == self.dtype() {
+                    return Ok(self.clone().into_series());
+                }
+                let entries = DataType::map_entries(key, value);
+                let out = self.phys.cast_with_options(&entries, cast_options)?;
+                Ok(out.list()?.clone().into_map()?.into_series())
+            },
+            dt => self.phys.cast_with_options(dt, cast_options),
+        }
+    }
+}
+
+impl MapChunked {
+    pub fn key_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the keys of every map as a [`ListChunked`].
+    pub fn keys(&self) -> ListChunked {
+        self.entry_field(0)
+    }
+
+    /// Get the values of every map as a [`ListChunked`].
+    pub fn values(&self) -> ListChunked {
+        self.entry_field(1)
+    }
+
+    fn entry_field(&self, idx: usize) -> ListChunked {
+        self.phys
+            .apply_to_inner(&|s| Ok(s.struct_()?.fields_as_series().swap_remove(idx)))
+            .unwrap()
+    }
+}
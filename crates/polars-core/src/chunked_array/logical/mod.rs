@@ -14,6 +14,14 @@ pub use decimal::*;
 mod duration;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-time")]
@@ -60,8 +60,14 @@ where
                     std::mem::transmute::<&PrimitiveArray<T>, &PrimitiveArray<f64>>(array);
                 let sum = float_sum::sum_arr_as_f64(f64_arr);
                 std::mem::transmute_copy::<f64, T>(&sum)
+            } else if T::is_f16() {
+                // Half-precision sums are accumulated in f32.
+                let f16_arr =
+                    std::mem::transmute::<&PrimitiveArray<T>, &PrimitiveArray<f16>>(array);
+                let sum = f16::from_f32(float_sum::sum_arr_as_f32(f16_arr));
+                std::mem::transmute_copy::<f16, T>(&sum)
             } else {
-                unreachable!("only supported float types are f32 and f64");
+                unreachable!("only supported float types are f16, f32 and f64");
             }
         }
     } else {
@@ -297,6 +303,19 @@ where
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl VarAggSeries for Float16Chunked {
+    fn var_reduce(&self, ddof: u8) -> Scalar {
+        let v = self.var(ddof).map(f16::from_f64);
+        Scalar::new(DataType::Float16, v.into())
+    }
+
+    fn std_reduce(&self, ddof: u8) -> Scalar {
+        let v = self.std(ddof).map(f16::from_f64);
+        Scalar::new(DataType::Float16, v.into())
+    }
+}
+
 impl VarAggSeries for Float32Chunked {
     fn var_reduce(&self, ddof: u8) -> Scalar {
         let v = self.var(ddof).map(|v| v as f32);
@@ -337,6 +356,19 @@ where
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl QuantileAggSeries for Float16Chunked {
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        let v = self.quantile(quantile, method)?;
+        Ok(Scalar::new(DataType::Float16, v.into()))
+    }
+
+    fn median_reduce(&self) -> Scalar {
+        let v = self.median();
+        Scalar::new(DataType::Float16, v.into())
+    }
+}
+
 impl QuantileAggSeries for Float32Chunked {
     fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
         let v = self.quantile(quantile, method)?;
@@ -209,6 +209,24 @@ impl ChunkQuantile<f32> for Float32Chunked {
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl ChunkQuantile<f16> for Float16Chunked {
+    fn quantile(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Option<f16>> {
+        // in case of sorted data, the sort is free, so don't take quickselect route
+        let out = if let (Ok(slice), false) = (self.cont_slice(), self.is_sorted_ascending_flag()) {
+            let mut owned = slice.to_vec();
+            quantile_slice(&mut owned, quantile, method)
+        } else {
+            generic_quantile(self.clone(), quantile, method)
+        };
+        out.map(|v| v.map(f16::from_f64))
+    }
+
+    fn median(&self) -> Option<f16> {
+        self.quantile(0.5, QuantileMethod::Linear).unwrap() // unwrap fine since quantile in range
+    }
+}
+
 impl ChunkQuantile<f64> for Float64Chunked {
     fn quantile(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Option<f64>> {
         // in case of sorted data, the sort is free, so don't take quickselect route
@@ -265,6 +283,27 @@ impl Float32Chunked {
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl Float16Chunked {
+    pub(crate) fn quantile_faster(
+        mut self,
+        quantile: f64,
+        method: QuantileMethod,
+    ) -> PolarsResult<Option<f16>> {
+        // in case of sorted data, the sort is free, so don't take quickselect route
+        let is_sorted = self.is_sorted_ascending_flag();
+        if let (Some(slice), false) = (self.cont_slice_mut(), is_sorted) {
+            quantile_slice(slice, quantile, method).map(|v| v.map(f16::from_f64))
+        } else {
+            self.quantile(quantile, method)
+        }
+    }
+
+    pub(crate) fn median_faster(self) -> Option<f16> {
+        self.quantile_faster(0.5, QuantileMethod::Linear).unwrap()
+    }
+}
+
 impl ChunkQuantile<String> for StringChunked {}
 impl ChunkQuantile<Series> for ListChunked {}
 #[cfg(feature = "dtype-array")]
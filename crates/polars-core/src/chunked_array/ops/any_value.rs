@@ -44,6 +44,8 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
         DataType::Int32 => downcast_and_pack!(Int32Array, Int32),
         DataType::Int64 => downcast_and_pack!(Int64Array, Int64),
         DataType::Int128 => downcast_and_pack!(Int128Array, Int128),
+        DataType::UInt128 => downcast_and_pack!(UInt128Array, UInt128),
+        DataType::Float16 => downcast_and_pack!(Float16Array, Float16),
         DataType::Float32 => downcast_and_pack!(Float32Array, Float32),
         DataType::Float64 => downcast_and_pack!(Float64Array, Float64),
         DataType::List(dt) => {
@@ -62,6 +64,19 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
                 AnyValue::List(s)
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            let v: ArrayRef = downcast!(LargeListArray);
+            let dt = DataType::map_entry(key, value);
+            let s = Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![v],
+                &dt.to_physical(),
+            )
+            .from_physical_unchecked(&dt)
+            .unwrap();
+            AnyValue::List(s)
+        },
         #[cfg(feature = "dtype-array")]
         DataType::Array(dt, width) => {
             let v: ArrayRef = downcast!(FixedSizeListArray);
@@ -126,6 +141,12 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Time(v)
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(i128_to_interval(v))
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let arr = &*(arr as *const dyn Array as *const Int128Array);
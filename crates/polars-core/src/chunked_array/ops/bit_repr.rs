@@ -146,6 +146,19 @@ where
                     }
 
                     reinterpret_chunked_array(self)
+                } else if self.dtype().is_float() {
+                    // Half-precision floats must keep their bits, so we go through
+                    // their unsigned integer representation first.
+                    let ca: UInt16Chunked = reinterpret_chunked_array(self);
+                    // SAFETY: an unchecked cast to uint32 (which has no invariants) is
+                    // always sound.
+                    unsafe {
+                        ca.cast_unchecked(&DataType::UInt32)
+                            .unwrap()
+                            .u32()
+                            .unwrap()
+                            .clone()
+                    }
                 } else {
                     // SAFETY: an unchecked cast to uint32 (which has no invariants) is
                     // always sound.
@@ -224,10 +237,23 @@ impl Reinterpret for Float32Chunked {
     }
 }
 
+#[cfg(all(feature = "reinterpret", feature = "dtype-f16"))]
+impl Reinterpret for Float16Chunked {
+    fn reinterpret_signed(&self) -> Series {
+        reinterpret_chunked_array::<_, Int16Type>(self).into_series()
+    }
+
+    fn reinterpret_unsigned(&self) -> Series {
+        reinterpret_chunked_array::<_, UInt16Type>(self).into_series()
+    }
+}
+
 #[cfg(feature = "reinterpret")]
 impl Reinterpret for ListChunked {
     fn reinterpret_signed(&self) -> Series {
         match self.inner_dtype() {
+            #[cfg(all(feature = "dtype-f16", feature = "dtype-i16"))]
+            DataType::Float16 => reinterpret_list_chunked::<Float16Type, Int16Type>(self),
             DataType::Float32 => reinterpret_list_chunked::<Float32Type, Int32Type>(self),
             DataType::Float64 => reinterpret_list_chunked::<Float64Type, Int64Type>(self),
             _ => unimplemented!(),
@@ -237,6 +263,8 @@ impl Reinterpret for ListChunked {
 
     fn reinterpret_unsigned(&self) -> Series {
         match self.inner_dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => reinterpret_list_chunked::<Float16Type, UInt16Type>(self),
             DataType::Float32 => reinterpret_list_chunked::<Float32Type, UInt32Type>(self),
             DataType::Float64 => reinterpret_list_chunked::<Float64Type, UInt64Type>(self),
             _ => unimplemented!(),
@@ -271,6 +299,18 @@ impl UInt32Chunked {
 
 /// Used to save compilation paths. Use carefully. Although this is safe,
 /// if misused it can lead to incorrect results.
+#[cfg(feature = "dtype-f16")]
+impl Float16Chunked {
+    pub fn apply_as_ints<F>(&self, f: F) -> Series
+    where
+        F: Fn(&Series) -> Series,
+    {
+        let s = reinterpret_chunked_array::<_, UInt16Type>(self).into_series();
+        let out = f(&s);
+        let out = out.u16().unwrap();
+        reinterpret_chunked_array::<_, Float16Type>(out).into_series()
+    }
+}
 impl Float32Chunked {
     pub fn apply_as_ints<F>(&self, f: F) -> Series
     where
@@ -149,6 +149,15 @@ where
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl ExplodeByOffsets for Float16Chunked {
+    fn explode_by_offsets(&self, offsets: &[i64], skip_empty: bool) -> Series {
+        self.apply_as_ints(|s| {
+            let ca = s.u16().unwrap();
+            ca.explode_by_offsets(offsets, skip_empty)
+        })
+    }
+}
 impl ExplodeByOffsets for Float32Chunked {
     fn explode_by_offsets(&self, offsets: &[i64], skip_empty: bool) -> Series {
         self.apply_as_ints(|s| {
@@ -84,6 +84,8 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Int32
         | DataType::Int64
         | DataType::Int128
+        | DataType::UInt128
+        | DataType::Float16
         | DataType::Float32
         | DataType::Float64
         | DataType::String
@@ -94,6 +96,8 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
 
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
@@ -120,6 +124,8 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_context(dtype),
         DataType::List(dtype) => get_row_encoding_context(dtype),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => get_row_encoding_context(&DataType::map_entries(key, value)),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fs) => {
             let mut ctxts = Vec::new();
@@ -99,11 +99,13 @@ enum SerializableDataType {
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Int8,
     Int16,
     Int32,
     Int64,
     Int128,
+    Float16,
     Float32,
     Float64,
     String,
@@ -119,6 +121,9 @@ enum SerializableDataType {
     Duration(TimeUnit),
     /// A 64-bit time representing elapsed time since midnight in the given TimeUnit.
     Time,
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     List(Box<SerializableDataType>),
     #[cfg(feature = "dtype-array")]
     Array(Box<SerializableDataType>, usize),
@@ -141,6 +146,8 @@ enum SerializableDataType {
     Decimal(Option<usize>, Option<usize>),
     #[cfg(feature = "object")]
     Object(String),
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -152,11 +159,13 @@ impl From<&DataType> for SerializableDataType {
             UInt16 => Self::UInt16,
             UInt32 => Self::UInt32,
             UInt64 => Self::UInt64,
+            UInt128 => Self::UInt128,
             Int8 => Self::Int8,
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -166,6 +175,8 @@ impl From<&DataType> for SerializableDataType {
             Datetime(tu, tz) => Self::Datetime(*tu, tz.clone()),
             Duration(tu) => Self::Duration(*tu),
             Time => Self::Time,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
             List(dt) => Self::List(Box::new(dt.as_ref().into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new(dt.as_ref().into()), *width),
@@ -191,6 +202,11 @@ impl From<&DataType> for SerializableDataType {
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
         }
     }
 }
@@ -203,11 +219,13 @@ impl From<SerializableDataType> for DataType {
             UInt16 => Self::UInt16,
             UInt32 => Self::UInt32,
             UInt64 => Self::UInt64,
+            UInt128 => Self::UInt128,
             Int8 => Self::Int8,
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -217,6 +235,8 @@ impl From<SerializableDataType> for DataType {
             Datetime(tu, tz) => Self::Datetime(tu, tz),
             Duration(tu) => Self::Duration(tu),
             Time => Self::Time,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
             List(dt) => Self::List(Box::new((*dt).into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new((*dt).into()), width),
@@ -249,6 +269,8 @@ impl From<SerializableDataType> for DataType {
             Decimal(precision, scale) => Self::Decimal(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
         }
     }
 }
@@ -38,6 +38,8 @@ pub enum AnyValue<'a> {
     UInt32(u32),
     /// An unsigned 64-bit integer number.
     UInt64(u64),
+    /// An unsigned 128-bit integer number.
+    UInt128(u128),
     /// An 8-bit integer number.
     Int8(i8),
     /// A 16-bit integer number.
@@ -48,6 +50,8 @@ pub enum AnyValue<'a> {
     Int64(i64),
     /// A 128-bit integer number.
     Int128(i128),
+    /// A 16-bit floating point number.
+    Float16(f16),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -70,6 +74,9 @@ pub enum AnyValue<'a> {
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
     #[cfg(feature = "dtype-categorical")]
     Categorical(CatSize, &'a Arc<CategoricalMapping>),
     #[cfg(feature = "dtype-categorical")]
@@ -118,6 +125,8 @@ impl AnyValue<'static> {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(unit) => AnyValue::Duration(0, *unit),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => AnyValue::Interval(months_days_ns::default()),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_p, s) => {
                 AnyValue::Decimal(0, s.expect("unknown scale during execution"))
@@ -151,6 +160,8 @@ impl<'a> AnyValue<'a> {
             UInt16(_) => DataType::UInt16,
             UInt32(_) => DataType::UInt32,
             UInt64(_) => DataType::UInt64,
+            UInt128(_) => DataType::UInt128,
+            Float16(_) => DataType::Float16,
             Float32(_) => DataType::Float32,
             Float64(_) => DataType::Float64,
             String(_) | StringOwned(_) => DataType::String,
@@ -167,6 +178,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-duration")]
             Duration(_, tu) => DataType::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | CategoricalOwned(_, _) => {
                 unimplemented!("can not get dtype of Categorical AnyValue")
@@ -204,6 +217,8 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => NumCast::from(*v),
             UInt32(v) => NumCast::from(*v),
             UInt64(v) => NumCast::from(*v),
+            UInt128(v) => NumCast::from(*v),
+            Float16(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-date")]
@@ -227,6 +242,8 @@ impl<'a> AnyValue<'a> {
             String(v) => {
                 if let Ok(val) = (*v).parse::<i128>() {
                     NumCast::from(val)
+                } else if let Ok(val) = (*v).parse::<u128>() {
+                    NumCast::from(val)
                 } else {
                     NumCast::from((*v).parse::<f64>().ok()?)
                 }
@@ -255,7 +272,10 @@ impl<'a> AnyValue<'a> {
     }
 
     pub fn is_float(&self) -> bool {
-        matches!(self, AnyValue::Float32(_) | AnyValue::Float64(_))
+        matches!(
+            self,
+            AnyValue::Float16(_) | AnyValue::Float32(_) | AnyValue::Float64(_)
+        )
     }
 
     pub fn is_integer(&self) -> bool {
@@ -276,12 +296,17 @@ impl<'a> AnyValue<'a> {
     pub fn is_unsigned_integer(&self) -> bool {
         matches!(
             self,
-            AnyValue::UInt8(_) | AnyValue::UInt16(_) | AnyValue::UInt32(_) | AnyValue::UInt64(_)
+            AnyValue::UInt8(_)
+                | AnyValue::UInt16(_)
+                | AnyValue::UInt32(_)
+                | AnyValue::UInt64(_)
+                | AnyValue::UInt128(_)
         )
     }
 
     pub fn is_nan(&self) -> bool {
         match self {
+            AnyValue::Float16(f) => f.is_nan(),
             AnyValue::Float32(f) => f.is_nan(),
             AnyValue::Float64(f) => f.is_nan(),
             _ => false,
@@ -313,11 +338,13 @@ impl<'a> AnyValue<'a> {
             (av, DataType::UInt16) => AnyValue::UInt16(av.extract::<u16>()?),
             (av, DataType::UInt32) => AnyValue::UInt32(av.extract::<u32>()?),
             (av, DataType::UInt64) => AnyValue::UInt64(av.extract::<u64>()?),
+            (av, DataType::UInt128) => AnyValue::UInt128(av.extract::<u128>()?),
             (av, DataType::Int8) => AnyValue::Int8(av.extract::<i8>()?),
             (av, DataType::Int16) => AnyValue::Int16(av.extract::<i16>()?),
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
+            (av, DataType::Float16) => AnyValue::Float16(av.extract::<f16>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
@@ -326,11 +353,13 @@ impl<'a> AnyValue<'a> {
             (AnyValue::UInt16(v), DataType::Boolean) => AnyValue::Boolean(*v != u16::default()),
             (AnyValue::UInt32(v), DataType::Boolean) => AnyValue::Boolean(*v != u32::default()),
             (AnyValue::UInt64(v), DataType::Boolean) => AnyValue::Boolean(*v != u64::default()),
+            (AnyValue::UInt128(v), DataType::Boolean) => AnyValue::Boolean(*v != u128::default()),
             (AnyValue::Int8(v), DataType::Boolean) => AnyValue::Boolean(*v != i8::default()),
             (AnyValue::Int16(v), DataType::Boolean) => AnyValue::Boolean(*v != i16::default()),
             (AnyValue::Int32(v), DataType::Boolean) => AnyValue::Boolean(*v != i32::default()),
             (AnyValue::Int64(v), DataType::Boolean) => AnyValue::Boolean(*v != i64::default()),
             (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
+            (AnyValue::Float16(v), DataType::Boolean) => AnyValue::Boolean(*v != f16::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
 
@@ -404,6 +433,8 @@ impl<'a> AnyValue<'a> {
                 if av.is_unsigned_integer() {
                     let val = av.extract::<u64>()?;
                     SerPrimitive::write(&mut tmp, val);
+                } else if let AnyValue::Float16(val) = av {
+                    SerPrimitive::write(&mut tmp, *val);
                 } else if av.is_float() {
                     let val = av.extract::<f64>()?;
                     SerPrimitive::write(&mut tmp, val);
@@ -598,11 +629,13 @@ impl<'a> AnyValue<'a> {
             | Self::UInt16(_)
             | Self::UInt32(_)
             | Self::UInt64(_)
+            | Self::UInt128(_)
             | Self::Int8(_)
             | Self::Int16(_)
             | Self::Int32(_)
             | Self::Int64(_)
             | Self::Int128(_)
+            | Self::Float16(_)
             | Self::Float32(_)
             | Self::Float64(_) => self,
 
@@ -618,6 +651,8 @@ impl<'a> AnyValue<'a> {
             Self::Duration(v, _) => Self::Int64(v),
             #[cfg(feature = "dtype-time")]
             Self::Time(v) => Self::Int64(v),
+            #[cfg(feature = "dtype-interval")]
+            Self::Interval(v) => Self::Int128(interval_to_i128(v)),
 
             #[cfg(feature = "dtype-categorical")]
             Self::Categorical(v, _)
@@ -704,8 +739,10 @@ impl AnyValue<'_> {
             UInt16(v) => v.hash(state),
             UInt32(v) => v.hash(state),
             UInt64(v) => v.hash(state),
+            UInt128(v) => feature_gated!("dtype-u128", v.hash(state)),
             String(v) => v.hash(state),
             StringOwned(v) => v.hash(state),
+            Float16(v) => v.to_bits().hash(state),
             Float32(v) => v.to_ne_bytes().hash(state),
             Float64(v) => v.to_ne_bytes().hash(state),
             Binary(v) => v.hash(state),
@@ -744,6 +781,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             Time(v) => v.hash(state),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(v, _) | CategoricalOwned(v, _) | Enum(v, _) | EnumOwned(v, _) => {
                 v.hash(state)
@@ -830,6 +869,15 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn as_interval(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Interval(i128_to_interval(*v)),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create interval from other type. dtype: {dt}"),
+        }
+    }
+
     pub(crate) fn to_i128(&self) -> Option<i128> {
         match self {
             AnyValue::UInt8(v) => Some((*v).into()),
@@ -841,12 +889,14 @@ impl<'a> AnyValue<'a> {
             AnyValue::Int32(v) => Some((*v).into()),
             AnyValue::Int64(v) => Some((*v).into()),
             AnyValue::Int128(v) => Some(*v),
+            AnyValue::UInt128(v) => (*v).try_into().ok(),
             _ => None,
         }
     }
 
     pub(crate) fn to_f64(&self) -> Option<f64> {
         match self {
+            AnyValue::Float16(v) => Some((*v).into()),
             AnyValue::Float32(v) => Some((*v).into()),
             AnyValue::Float64(v) => Some(*v),
             _ => None,
@@ -863,6 +913,7 @@ impl<'a> AnyValue<'a> {
             (Int64(l), Int64(r)) => Int64(l + r),
             (UInt32(l), UInt32(r)) => UInt32(l + r),
             (UInt64(l), UInt64(r)) => UInt64(l + r),
+            (Float16(l), Float16(r)) => Float16(*l + *r),
             (Float32(l), Float32(r)) => Float32(l + r),
             (Float64(l), Float64(r)) => Float64(l + r),
             #[cfg(feature = "dtype-duration")]
@@ -918,7 +969,9 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => UInt16(v),
             UInt32(v) => UInt32(v),
             UInt64(v) => UInt64(v),
+            UInt128(v) => UInt128(v),
             Boolean(v) => Boolean(v),
+            Float16(v) => Float16(v),
             Float32(v) => Float32(v),
             Float64(v) => Float64(v),
             #[cfg(feature = "dtype-datetime")]
@@ -931,6 +984,8 @@ impl<'a> AnyValue<'a> {
             Duration(v, tu) => Duration(v, tu),
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
             List(v) => List(v),
             #[cfg(feature = "dtype-array")]
             Array(s, size) => Array(s, size),
@@ -1074,11 +1129,13 @@ impl AnyValue<'_> {
             (UInt16(l), UInt16(r)) => *l == *r,
             (UInt32(l), UInt32(r)) => *l == *r,
             (UInt64(l), UInt64(r)) => *l == *r,
+            (UInt128(l), UInt128(r)) => *l == *r,
             (Int8(l), Int8(r)) => *l == *r,
             (Int16(l), Int16(r)) => *l == *r,
             (Int32(l), Int32(r)) => *l == *r,
             (Int64(l), Int64(r)) => *l == *r,
             (Int128(l), Int128(r)) => *l == *r,
+            (Float16(l), Float16(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float32(l), Float32(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float64(l), Float64(r)) => l.to_total_ord() == r.to_total_ord(),
             (String(l), String(r)) => l == r,
@@ -1118,6 +1175,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
 
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => struct_eq_missing(
@@ -1254,11 +1313,13 @@ impl PartialOrd for AnyValue<'_> {
             (UInt16(l), UInt16(r)) => l.partial_cmp(r),
             (UInt32(l), UInt32(r)) => l.partial_cmp(r),
             (UInt64(l), UInt64(r)) => l.partial_cmp(r),
+            (UInt128(l), UInt128(r)) => l.partial_cmp(r),
             (Int8(l), Int8(r)) => l.partial_cmp(r),
             (Int16(l), Int16(r)) => l.partial_cmp(r),
             (Int32(l), Int32(r)) => l.partial_cmp(r),
             (Int64(l), Int64(r)) => l.partial_cmp(r),
             (Int128(l), Int128(r)) => l.partial_cmp(r),
+            (Float16(l), Float16(r)) => Some(l.tot_cmp(r)),
             (Float32(l), Float32(r)) => Some(l.tot_cmp(r)),
             (Float64(l), Float64(r)) => Some(l.tot_cmp(r)),
             (String(l), String(r)) => l.partial_cmp(r),
@@ -1285,6 +1346,8 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
             (Categorical(l_cat, l_map), Categorical(r_cat, r_map)) => unsafe {
                 let l_str = l_map.cat_to_str_unchecked(*l_cat);
@@ -1480,6 +1543,26 @@ impl GetAnyValue for ArrayRef {
                     Some(v) => AnyValue::UInt64(v),
                 }
             },
+            ArrowDataType::UInt128 => {
+                let arr = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u128>>()
+                    .unwrap_unchecked();
+                match arr.get_unchecked(index) {
+                    None => AnyValue::Null,
+                    Some(v) => AnyValue::UInt128(v),
+                }
+            },
+            ArrowDataType::Float16 => {
+                let arr = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<f16>>()
+                    .unwrap_unchecked();
+                match arr.get_unchecked(index) {
+                    None => AnyValue::Null,
+                    Some(v) => AnyValue::Float16(v),
+                }
+            },
             ArrowDataType::Float32 => {
                 let arr = self
                     .as_any()
@@ -1538,6 +1621,12 @@ impl<K: NumericNative> From<K> for AnyValue<'static> {
                 PrimitiveType::UInt16 => AnyValue::UInt16(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt32 => AnyValue::UInt32(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt64 => AnyValue::UInt64(NumCast::from(value).unwrap_unchecked()),
+                PrimitiveType::UInt128 => {
+                    AnyValue::UInt128(NumCast::from(value).unwrap_unchecked())
+                },
+                PrimitiveType::Float16 => {
+                    AnyValue::Float16(NumCast::from(value).unwrap_unchecked())
+                },
                 PrimitiveType::Float32 => {
                     AnyValue::Float32(NumCast::from(value).unwrap_unchecked())
                 },
@@ -93,11 +93,13 @@ pub enum DataType {
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Int8,
     Int16,
     Int32,
     Int64,
     Int128,
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -119,11 +121,17 @@ pub enum DataType {
     Duration(TimeUnit),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
+    /// A calendar interval of months, days and nanoseconds, packed in a signed 128-bit integer.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A nested list with a fixed size in each row
     #[cfg(feature = "dtype-array")]
     Array(Box<DataType>, usize),
     /// A nested list with a variable size in each row
     List(Box<DataType>),
+    /// A map from keys to values, stored as a list of `{key, value}` structs in each row
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
     /// A generic type that can be used in a `Series`
     /// &'static str can be used to determine/set inner type
     #[cfg(feature = "object")]
@@ -186,6 +194,10 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(left_key, left_value), Map(right_key, right_value)) => {
+                    left_key == right_key && left_value == right_value
+                },
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -218,6 +230,8 @@ impl DataType {
             UInt16 => other.extract::<u16>().is_some(),
             UInt32 => other.extract::<u32>().is_some(),
             UInt64 => other.extract::<u64>().is_some(),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => other.extract::<u128>().is_some(),
             #[cfg(feature = "dtype-i8")]
             Int8 => other.extract::<i8>().is_some(),
             #[cfg(feature = "dtype-i16")]
@@ -234,6 +248,8 @@ impl DataType {
             DataType::List(inner) => inner.is_known(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, _) => inner.is_known(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
             DataType::Unknown(_) => false,
@@ -263,6 +279,11 @@ impl DataType {
                 Box::new(inner.materialize_unknown(allow_unknown)?),
                 size,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+            )),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => Ok(DataType::Struct(
                 fields
@@ -402,6 +423,10 @@ impl DataType {
             (D::Array(from, l_width), D::Array(to, r_width)) => {
                 l_width == r_width && from.can_cast_to(to)?
             },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(l_key, l_value), D::Map(r_key, r_value)) => {
+                l_key.can_cast_to(r_key)? && l_value.can_cast_to(r_value)?
+            },
             #[cfg(feature = "dtype-struct")]
             (D::Struct(l_fields), D::Struct(r_fields)) => {
                 if l_fields.is_empty() {
@@ -439,6 +464,8 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
             #[cfg(feature = "dtype-categorical")]
@@ -448,6 +475,8 @@ impl DataType {
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Array(Box::new(dt.to_physical()), *width),
             List(dt) => List(Box::new(dt.to_physical())),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => DataType::map_entries(key, value).to_physical(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
                 let new_fields = fields
@@ -512,8 +541,36 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
+    /// The `Struct{key, value}` type of a single map entry.
+    #[cfg(feature = "dtype-map")]
+    pub fn map_entry(key: &DataType, value: &DataType) -> DataType {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), key.clone()),
+            Field::new(PlSmallStr::from_static("value"), value.clone()),
+        ])
+    }
+
+    /// The `List(Struct{key, value})` representation backing a map with the given key and
+    /// value types.
+    #[cfg(feature = "dtype-map")]
+    pub fn map_entries(key: &DataType, value: &DataType) -> DataType {
+        DataType::List(Box::new(DataType::map_entry(key, value)))
+    }
+
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map()
     }
 
     /// Check if this [`DataType`] is a struct
@@ -543,6 +600,18 @@ impl DataType {
         matches!(self, DataType::Duration(..))
     }
 
+    /// Check if this [`DataType`] is a calendar interval.
+    pub fn is_interval(&self) -> bool {
+        #[cfg(feature = "dtype-interval")]
+        {
+            matches!(self, DataType::Interval)
+        }
+        #[cfg(not(feature = "dtype-interval"))]
+        {
+            false
+        }
+    }
+
     pub fn is_object(&self) -> bool {
         #[cfg(feature = "object")]
         {
@@ -565,6 +634,8 @@ impl DataType {
             List(inner) => inner.contains_views(),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_views(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
             _ => false,
@@ -579,6 +650,8 @@ impl DataType {
             List(inner) => inner.contains_categoricals(),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_categoricals(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields
                 .iter()
@@ -595,6 +668,8 @@ impl DataType {
             List(inner) => inner.contains_objects(),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_objects(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
             _ => false,
@@ -607,6 +682,8 @@ impl DataType {
             D::List(_) => true,
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_list_recursive(),
+            #[cfg(feature = "dtype-map")]
+            D::Map(_, _) => true,
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields
                 .iter()
@@ -622,6 +699,8 @@ impl DataType {
             D::List(inner) => inner.contains_unknown(),
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_unknown(),
+            #[cfg(feature = "dtype-map")]
+            D::Map(key, value) => key.contains_unknown() || value.contains_unknown(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
             _ => false,
@@ -653,7 +732,10 @@ impl DataType {
     pub fn is_float(&self) -> bool {
         matches!(
             self,
-            DataType::Float32 | DataType::Float64 | DataType::Unknown(UnknownKind::Float)
+            DataType::Float16
+                | DataType::Float32
+                | DataType::Float64
+                | DataType::Unknown(UnknownKind::Float)
         )
     }
 
@@ -670,6 +752,7 @@ impl DataType {
                 | DataType::UInt16
                 | DataType::UInt32
                 | DataType::UInt64
+                | DataType::UInt128
                 | DataType::Unknown(UnknownKind::Int(_))
         )
     }
@@ -685,7 +768,11 @@ impl DataType {
     pub fn is_unsigned_integer(&self) -> bool {
         matches!(
             self,
-            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64,
+            DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::UInt128,
         )
     }
 
@@ -782,6 +869,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MAX),
             UInt32 => Scalar::from(u32::MAX),
             UInt64 => Scalar::from(u64::MAX),
+            UInt128 => Scalar::from(u128::MAX),
+            Float16 => Scalar::from(f16::INFINITY),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -804,6 +893,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MIN),
             UInt32 => Scalar::from(u32::MIN),
             UInt64 => Scalar::from(u64::MIN),
+            UInt128 => Scalar::from(u128::MIN),
+            Float16 => Scalar::from(f16::NEG_INFINITY),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -828,11 +919,13 @@ impl DataType {
             UInt16 => Ok(ArrowDataType::UInt16),
             UInt32 => Ok(ArrowDataType::UInt32),
             UInt64 => Ok(ArrowDataType::UInt64),
+            UInt128 => Ok(ArrowDataType::UInt128),
             Int8 => Ok(ArrowDataType::Int8),
             Int16 => Ok(ArrowDataType::Int16),
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
             Int128 => Ok(ArrowDataType::Int128),
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -868,6 +961,10 @@ impl DataType {
             )),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(
+                arrow::datatypes::IntervalUnit::MonthDayNano,
+            )),
             #[cfg(feature = "dtype-array")]
             Array(dt, size) => Ok(dt
                 .try_to_arrow(compat_level)?
@@ -875,6 +972,19 @@ impl DataType {
             List(dt) => Ok(ArrowDataType::LargeList(Box::new(
                 dt.to_arrow_field(LIST_VALUES_NAME, compat_level),
             ))),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let mut key = key.to_arrow_field(PlSmallStr::from_static("key"), compat_level);
+                // Map keys are never null.
+                key.is_nullable = false;
+                let value = value.to_arrow_field(PlSmallStr::from_static("value"), compat_level);
+                let entries = ArrowField::new(
+                    PlSmallStr::from_static("entries"),
+                    ArrowDataType::Struct(vec![key, value]),
+                    false,
+                );
+                Ok(ArrowDataType::Map(Box::new(entries), false))
+            },
             Null => Ok(ArrowDataType::Null),
             #[cfg(feature = "object")]
             Object(_) => Ok(get_object_physical_type()),
@@ -928,6 +1038,8 @@ impl DataType {
             List(field) => field.is_nested_null(),
             #[cfg(feature = "dtype-array")]
             Array(field, _) => field.is_nested_null(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.is_nested_null() && value.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
             _ => false,
@@ -947,6 +1059,10 @@ impl DataType {
             (DataType::Array(l, sl), DataType::Array(r, sr)) => {
                 Ok(l.matches_schema_type(r)? && sl == sr)
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(l), DataType::Struct(r)) => {
                 if l.len() != r.len() {
@@ -1046,11 +1162,13 @@ impl Display for DataType {
             DataType::UInt16 => "u16",
             DataType::UInt32 => "u32",
             DataType::UInt64 => "u64",
+            DataType::UInt128 => "u128",
             DataType::Int8 => "i8",
             DataType::Int16 => "i16",
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
             DataType::Int128 => "i128",
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -1075,6 +1193,8 @@ impl Display for DataType {
             },
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
             DataType::Time => "time",
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let tp = self.array_leaf_dtype().unwrap();
@@ -1088,6 +1208,8 @@ impl Display for DataType {
                 return write!(f, "array[{tp}, {shape}]");
             },
             DataType::List(tp) => return write!(f, "list[{tp}]"),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
             #[cfg(feature = "object")]
             DataType::Object(s) => s,
             #[cfg(feature = "dtype-categorical")]
@@ -1142,6 +1264,11 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             Array(Box::new(merged), *width_l)
         },
+        #[cfg(feature = "dtype-map")]
+        (Map(key_l, value_l), Map(key_r, value_r)) => Map(
+            Box::new(merge_dtypes(key_l, key_r)?),
+            Box::new(merge_dtypes(value_l, value_r)?),
+        ),
         (left, right) if left == right => left.clone(),
         _ => polars_bail!(ComputeError: "unable to merge datatypes"),
     })
@@ -1166,6 +1293,14 @@ fn collect_nested_types(
             }
             collect_nested_types(inner, result, include_compound_types);
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            collect_nested_types(key, result, include_compound_types);
+            collect_nested_types(value, result, include_compound_types);
+        },
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fields) => {
             if include_compound_types {
@@ -161,7 +161,12 @@ impl DataType {
             ArrowDataType::Int64 => DataType::Int64,
             #[cfg(feature = "dtype-i128")]
             ArrowDataType::Int128 => DataType::Int128,
+            #[cfg(feature = "dtype-u128")]
+            ArrowDataType::UInt128 => DataType::UInt128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
@@ -179,6 +184,8 @@ impl DataType {
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
             ArrowDataType::Date64 => DataType::Datetime(TimeUnit::Milliseconds, None),
             ArrowDataType::Time64(_) | ArrowDataType::Time32(_) => DataType::Time,
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(_) => DataType::Interval,
 
             #[cfg(feature = "dtype-categorical")]
             ArrowDataType::Dictionary(_, value_type, _) => {
@@ -267,6 +274,15 @@ impl DataType {
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) => match inner.dtype() {
+                ArrowDataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    Box::new(Self::from_arrow_field(&fields[0])),
+                    Box::new(Self::from_arrow_field(&fields[1])),
+                ),
+                _ => DataType::List(Self::from_arrow_field(inner).boxed()),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
@@ -32,6 +32,9 @@ use arrow::datatypes::IntegerType;
 pub use arrow::datatypes::reshape::*;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
+pub use arrow::types::f16;
+#[cfg(feature = "dtype-interval")]
+pub use arrow::types::months_days_ns;
 use bytemuck::Zeroable;
 pub use dtype::*;
 pub use field::*;
@@ -209,12 +212,16 @@ impl_polars_num_datatype!(PolarsIntegerType, UInt8Type, UInt8, u8, u8);
 impl_polars_num_datatype!(PolarsIntegerType, UInt16Type, UInt16, u16, u16);
 impl_polars_num_datatype!(PolarsIntegerType, UInt32Type, UInt32, u32, u32);
 impl_polars_num_datatype!(PolarsIntegerType, UInt64Type, UInt64, u64, u64);
+#[cfg(feature = "dtype-u128")]
+impl_polars_num_datatype!(PolarsIntegerType, UInt128Type, UInt128, u128, u128);
 impl_polars_num_datatype!(PolarsIntegerType, Int8Type, Int8, i8, i8);
 impl_polars_num_datatype!(PolarsIntegerType, Int16Type, Int16, i16, i16);
 impl_polars_num_datatype!(PolarsIntegerType, Int32Type, Int32, i32, i32);
 impl_polars_num_datatype!(PolarsIntegerType, Int64Type, Int64, i64, i64);
 #[cfg(feature = "dtype-i128")]
 impl_polars_num_datatype!(PolarsIntegerType, Int128Type, Int128, i128, i128);
+#[cfg(feature = "dtype-f16")]
+impl_polars_num_datatype!(PolarsFloatType, Float16Type, Float16, f16, f16);
 impl_polars_num_datatype!(PolarsFloatType, Float32Type, Float32, f32, f32);
 impl_polars_num_datatype!(PolarsFloatType, Float64Type, Float64, f64, f64);
 
@@ -230,6 +237,8 @@ impl_polars_datatype!(DurationType, unimplemented!(), PrimitiveArray<i64>, 'a, i
 impl_polars_datatype!(CategoricalType, unimplemented!(), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
 impl_polars_datatype!(DateType, DataType::Date, PrimitiveArray<i32>, 'a, i32, i32, i32, FalseT);
 impl_polars_datatype!(TimeType, DataType::Time, PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype!(IntervalType, DataType::Interval, PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
 
 impl_polars_categorical_datatype!(Categorical8Type, UInt8Type, u8, U8);
 impl_polars_categorical_datatype!(Categorical16Type, UInt16Type, u16, U16);
@@ -304,6 +313,25 @@ unsafe impl PolarsDataType for FixedSizeListType {
     }
 }
 
+#[cfg(feature = "dtype-map")]
+pub struct MapType {}
+#[cfg(feature = "dtype-map")]
+unsafe impl PolarsDataType for MapType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = ListArray<i64>;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+
+    fn get_static_dtype() -> DataType {
+        // Null as we cannot know anything without self.
+        DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null))
+    }
+}
+
 #[cfg(feature = "object")]
 pub struct ObjectType<T>(T);
 #[cfg(feature = "object")]
@@ -370,6 +398,10 @@ impl_phys_dtype!(BooleanType);
 
 #[cfg(feature = "dtype-i128")]
 impl_phys_dtype!(Int128Type);
+#[cfg(feature = "dtype-u128")]
+impl_phys_dtype!(UInt128Type);
+#[cfg(feature = "dtype-f16")]
+impl_phys_dtype!(Float16Type);
 
 #[cfg(feature = "dtype-array")]
 impl_phys_dtype!(FixedSizeListType);
@@ -392,12 +424,16 @@ pub type UInt8Chunked = ChunkedArray<UInt8Type>;
 pub type UInt16Chunked = ChunkedArray<UInt16Type>;
 pub type UInt32Chunked = ChunkedArray<UInt32Type>;
 pub type UInt64Chunked = ChunkedArray<UInt64Type>;
+#[cfg(feature = "dtype-u128")]
+pub type UInt128Chunked = ChunkedArray<UInt128Type>;
 pub type Int8Chunked = ChunkedArray<Int8Type>;
 pub type Int16Chunked = ChunkedArray<Int16Type>;
 pub type Int32Chunked = ChunkedArray<Int32Type>;
 pub type Int64Chunked = ChunkedArray<Int64Type>;
 #[cfg(feature = "dtype-i128")]
 pub type Int128Chunked = ChunkedArray<Int128Type>;
+#[cfg(feature = "dtype-f16")]
+pub type Float16Chunked = ChunkedArray<Float16Type>;
 pub type Float32Chunked = ChunkedArray<Float32Type>;
 pub type Float64Chunked = ChunkedArray<Float64Type>;
 pub type StringChunked = ChunkedArray<StringType>;
@@ -476,6 +512,16 @@ impl NumericNative for u64 {
     type PolarsType = UInt64Type;
     type TrueDivPolarsType = Float64Type;
 }
+#[cfg(feature = "dtype-u128")]
+impl NumericNative for u128 {
+    type PolarsType = UInt128Type;
+    type TrueDivPolarsType = Float64Type;
+}
+#[cfg(feature = "dtype-f16")]
+impl NumericNative for f16 {
+    type PolarsType = Float16Type;
+    type TrueDivPolarsType = Float16Type;
+}
 impl NumericNative for f32 {
     type PolarsType = Float32Type;
     type TrueDivPolarsType = Float32Type;
@@ -365,6 +365,18 @@ impl Debug for Series {
                     format_array!(f, self.i128().unwrap(), "i128", self.name(), "Series")
                 )
             },
+            DataType::UInt128 => {
+                feature_gated!(
+                    "dtype-u128",
+                    format_array!(f, self.u128().unwrap(), "u128", self.name(), "Series")
+                )
+            },
+            DataType::Float16 => {
+                feature_gated!(
+                    "dtype-f16",
+                    format_array!(f, self.f16().unwrap(), "f16", self.name(), "Series")
+                )
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
@@ -385,6 +397,16 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.duration().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -399,6 +421,11 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -1052,6 +1079,33 @@ pub fn fmt_duration_string<W: Write>(f: &mut W, v: i64, unit: TimeUnit) -> fmt::
     Ok(())
 }
 
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // eg: "1mo 2d 3h", the nanosecond part is written as a duration
+    if v == months_days_ns::default() {
+        return f.write_str("0d");
+    }
+    let mut buffer = itoa::Buffer::new();
+    if v.months() != 0 {
+        f.write_str(buffer.format(v.months()))?;
+        f.write_str("mo")?;
+        if v.days() != 0 || v.ns() != 0 {
+            f.write_char(' ')?;
+        }
+    }
+    if v.days() != 0 {
+        f.write_str(buffer.format(v.days()))?;
+        f.write_char('d')?;
+        if v.ns() != 0 {
+            f.write_char(' ')?;
+        }
+    }
+    if v.ns() != 0 {
+        fmt_duration_string(f, v.ns(), TimeUnit::Nanoseconds)?;
+    }
+    Ok(())
+}
+
 #[cfg(feature = "dtype-duration")]
 pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     if v == 0 {
@@ -1159,6 +1213,8 @@ impl Display for AnyValue<'_> {
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
             AnyValue::Int128(v) => feature_gated!("dtype-i128", fmt_integer(f, width, *v)),
+            AnyValue::UInt128(v) => feature_gated!("dtype-u128", fmt_integer(f, width, *v)),
+            AnyValue::Float16(v) => fmt_float(f, width, v.to_shortest_f32()),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
@@ -1176,6 +1232,8 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
@@ -334,6 +334,10 @@ impl Column {
     pub fn try_u64(&self) -> Option<&UInt64Chunked> {
         self.as_materialized_series().try_u64()
     }
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_f16(&self) -> Option<&Float16Chunked> {
+        self.as_materialized_series().try_f16()
+    }
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         self.as_materialized_series().try_f32()
     }
@@ -367,6 +371,14 @@ impl Column {
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
         self.as_materialized_series().try_decimal()
     }
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        self.as_materialized_series().try_interval()
+    }
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        self.as_materialized_series().try_map()
+    }
     #[cfg(feature = "dtype-array")]
     pub fn try_array(&self) -> Option<&ArrayChunked> {
         self.as_materialized_series().try_array()
@@ -416,6 +428,10 @@ impl Column {
     pub fn i128(&self) -> PolarsResult<&Int128Chunked> {
         self.as_materialized_series().i128()
     }
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.as_materialized_series().u128()
+    }
     pub fn u8(&self) -> PolarsResult<&UInt8Chunked> {
         self.as_materialized_series().u8()
     }
@@ -428,6 +444,10 @@ impl Column {
     pub fn u64(&self) -> PolarsResult<&UInt64Chunked> {
         self.as_materialized_series().u64()
     }
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.as_materialized_series().f16()
+    }
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.as_materialized_series().f32()
     }
@@ -461,6 +481,14 @@ impl Column {
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
         self.as_materialized_series().decimal()
     }
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.as_materialized_series().interval()
+    }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
     #[cfg(feature = "dtype-array")]
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
@@ -133,11 +133,31 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => SeriesWrap(s.f16().unwrap().clone()).agg_mean(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
             #[cfg(feature = "dtype-decimal")]
-            Decimal(_, _) => self.cast(&Float64).unwrap().agg_mean(groups),
+            Decimal(_, _) => {
+                let ca = s.decimal().unwrap();
+                let out = match groups {
+                    GroupsType::Idx(groups) => {
+                        _agg_helper_idx::<Int128Type, _>(groups, |(_, idx)| {
+                            let take = s.take_slice_unchecked(idx);
+                            take.decimal().unwrap().mean_decimal().ok().flatten()
+                        })
+                    },
+                    GroupsType::Slice { groups, .. } => {
+                        _agg_helper_slice::<Int128Type, _>(groups, |[first, len]| {
+                            let take = s.slice_from_offsets(first, len);
+                            take.decimal().unwrap().mean_decimal().ok().flatten()
+                        })
+                    },
+                };
+                // SAFETY: the physical type of a decimal is Int128.
+                unsafe { out.from_physical_unchecked(&ca.mean_dtype()).unwrap() }
+            },
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
@@ -187,6 +207,8 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => SeriesWrap(s.f16().unwrap().clone()).agg_median(groups),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_median(groups),
             dt if dt.is_primitive_numeric() => {
@@ -247,6 +269,8 @@ impl Series {
 
         use DataType::*;
         match s.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.f16().unwrap().agg_quantile(groups, quantile, method),
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, method),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, method),
             dt if dt.is_primitive_numeric() || dt.is_temporal() => {
@@ -241,6 +241,15 @@ where
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl QuantileDispatcher<f16> for Float16Chunked {
+    fn _quantile(self, quantile: f64, method: QuantileMethod) -> PolarsResult<Option<f16>> {
+        self.quantile_faster(quantile, method)
+    }
+    fn _median(self) -> Option<f16> {
+        self.median_faster()
+    }
+}
 impl QuantileDispatcher<f32> for Float32Chunked {
     fn _quantile(self, quantile: f64, method: QuantileMethod) -> PolarsResult<Option<f32>> {
         self.quantile_faster(quantile, method)
@@ -891,6 +900,20 @@ where
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl Float16Chunked {
+    pub(crate) unsafe fn agg_quantile(
+        &self,
+        groups: &GroupsType,
+        quantile: f64,
+        method: QuantileMethod,
+    ) -> Series {
+        agg_quantile_generic::<_, Float16Type>(self, groups, quantile, method)
+    }
+    pub(crate) unsafe fn agg_median(&self, groups: &GroupsType) -> Series {
+        agg_median_generic::<_, Float16Type>(self, groups)
+    }
+}
 impl Float32Chunked {
     pub(crate) unsafe fn agg_quantile(
         &self,
@@ -192,6 +192,14 @@ where
                 };
                 num_groups_proxy(ca, multithreaded, sorted)
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                // convince the compiler that we are this type.
+                let ca: &Float16Chunked = unsafe {
+                    &*(self as *const ChunkedArray<T> as *const ChunkedArray<Float16Type>)
+                };
+                num_groups_proxy(ca, multithreaded, sorted)
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 // convince the compiler that we are this type.
@@ -200,6 +208,14 @@ where
                 };
                 num_groups_proxy(ca, multithreaded, sorted)
             },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                // convince the compiler that we are this type.
+                let ca: &UInt128Chunked = unsafe {
+                    &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt128Type>)
+                };
+                num_groups_proxy(ca, multithreaded, sorted)
+            },
             #[cfg(all(feature = "performant", feature = "dtype-i8", feature = "dtype-u8"))]
             DataType::Int8 => {
                 // convince the compiler that we are this type.
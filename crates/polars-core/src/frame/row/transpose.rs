@@ -43,6 +43,8 @@ impl DataFrame {
             DataType::UInt16 => numeric_transpose::<UInt16Type>(cols, names_out, &mut cols_t),
             DataType::UInt32 => numeric_transpose::<UInt32Type>(cols, names_out, &mut cols_t),
             DataType::UInt64 => numeric_transpose::<UInt64Type>(cols, names_out, &mut cols_t),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => numeric_transpose::<Float16Type>(cols, names_out, &mut cols_t),
             DataType::Float32 => numeric_transpose::<Float32Type>(cols, names_out, &mut cols_t),
             DataType::Float64 => numeric_transpose::<Float64Type>(cols, names_out, &mut cols_t),
             #[cfg(feature = "object")]
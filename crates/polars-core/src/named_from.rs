@@ -40,12 +40,16 @@ impl_named_from_owned!(Vec<i32>, Int32Type);
 impl_named_from_owned!(Vec<i64>, Int64Type);
 #[cfg(feature = "dtype-i128")]
 impl_named_from_owned!(Vec<i128>, Int128Type);
+#[cfg(feature = "dtype-u128")]
+impl_named_from_owned!(Vec<u128>, UInt128Type);
 #[cfg(feature = "dtype-u8")]
 impl_named_from_owned!(Vec<u8>, UInt8Type);
 #[cfg(feature = "dtype-u16")]
 impl_named_from_owned!(Vec<u16>, UInt16Type);
 impl_named_from_owned!(Vec<u32>, UInt32Type);
 impl_named_from_owned!(Vec<u64>, UInt64Type);
+#[cfg(feature = "dtype-f16")]
+impl_named_from_owned!(Vec<f16>, Float16Type);
 impl_named_from_owned!(Vec<f32>, Float32Type);
 impl_named_from_owned!(Vec<f64>, Float64Type);
 
@@ -81,6 +85,8 @@ impl_named_from!([i32], Int32Type, from_slice);
 impl_named_from!([i64], Int64Type, from_slice);
 #[cfg(feature = "dtype-decimal")]
 impl_named_from!([i128], Int128Type, from_slice);
+#[cfg(feature = "dtype-f16")]
+impl_named_from!([f16], Float16Type, from_slice);
 impl_named_from!([f32], Float32Type, from_slice);
 impl_named_from!([f64], Float64Type, from_slice);
 impl_named_from!([Option<String>], StringType, from_slice_options);
@@ -100,6 +106,8 @@ impl_named_from!([Option<i32>], Int32Type, from_slice_options);
 impl_named_from!([Option<i64>], Int64Type, from_slice_options);
 #[cfg(feature = "dtype-decimal")]
 impl_named_from!([Option<i128>], Int128Type, from_slice_options);
+#[cfg(feature = "dtype-f16")]
+impl_named_from!([Option<f16>], Float16Type, from_slice_options);
 impl_named_from!([Option<f32>], Float32Type, from_slice_options);
 impl_named_from!([Option<f64>], Float64Type, from_slice_options);
 
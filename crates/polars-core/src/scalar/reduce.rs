@@ -6,6 +6,11 @@ use crate::prelude::{DataType, Scalar};
 
 pub fn mean_reduce(value: Option<f64>, dtype: DataType) -> Scalar {
     match dtype {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let val = value.map(crate::datatypes::f16::from_f64);
+            Scalar::new(dtype, val.into())
+        },
         DataType::Float32 => {
             let val = value.map(|m| m as f32);
             Scalar::new(dtype, val.into())
@@ -5,7 +5,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::Scalar;
-use crate::prelude::{AnyValue, DataType, Field};
+use crate::prelude::{AnyValue, DataType, Field, f16};
 use crate::series::Series;
 
 #[cfg(feature = "dsl-schema")]
@@ -73,6 +73,10 @@ pub enum SerializableScalar {
     UInt32(u32),
     /// An unsigned 64-bit integer number.
     UInt64(u64),
+    /// An unsigned 128-bit integer number.
+    UInt128(u128),
+    /// A 16-bit floating point number, stored widened to 32 bits.
+    Float16(f32),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -107,6 +111,10 @@ pub enum SerializableScalar {
     #[cfg(feature = "dtype-time")]
     Time(i64),
 
+    /// A calendar interval of months, days and nanoseconds
+    #[cfg(feature = "dtype-interval")]
+    Interval(i32, i32, i64),
+
     #[cfg(feature = "dtype-array")]
     Array(Series, usize),
 
@@ -146,6 +154,8 @@ impl TryFrom<Scalar> for SerializableScalar {
             AnyValue::UInt16(v) => Self::UInt16(v),
             AnyValue::UInt32(v) => Self::UInt32(v),
             AnyValue::UInt64(v) => Self::UInt64(v),
+            AnyValue::UInt128(v) => Self::UInt128(v),
+            AnyValue::Float16(v) => Self::Float16(v.to_f32()),
             AnyValue::Float32(v) => Self::Float32(v),
             AnyValue::Float64(v) => Self::Float64(v),
             AnyValue::List(series) => Self::List(series),
@@ -171,6 +181,9 @@ impl TryFrom<Scalar> for SerializableScalar {
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(v) => Self::Time(v),
 
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => Self::Interval(v.months(), v.days(), v.ns()),
+
             #[cfg(feature = "dtype-categorical")]
             AnyValue::Categorical(cat, _) | AnyValue::CategoricalOwned(cat, _) => {
                 let DataType::Categorical(categories, mapping) = value.dtype() else {
@@ -276,6 +289,8 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::UInt16(v) => Self::from(v),
             S::UInt32(v) => Self::from(v),
             S::UInt64(v) => Self::from(v),
+            S::UInt128(v) => Self::from(v),
+            S::Float16(v) => Self::from(f16::from_f32(v)),
             S::Float32(v) => Self::from(v),
             S::Float64(v) => Self::from(v),
             S::List(v) => Self::new_list(v),
@@ -290,6 +305,10 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::Duration(v, time_unit) => Self::new_duration(v, time_unit),
             #[cfg(feature = "dtype-time")]
             S::Time(v) => Self::new_time(v),
+            #[cfg(feature = "dtype-interval")]
+            S::Interval(months, days, ns) => {
+                Self::new_interval(crate::prelude::months_days_ns::new(months, days, ns))
+            },
             #[cfg(feature = "dtype-array")]
             S::Array(v, width) => Self::new_array(v, width),
             #[cfg(feature = "dtype-decimal")]
@@ -103,12 +103,18 @@ impl Series {
             DataType::Int64 => any_values_to_integer::<Int64Type>(values, strict)?.into_series(),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => any_values_to_integer::<Int128Type>(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                any_values_to_integer::<UInt128Type>(values, strict)?.into_series()
+            },
             #[cfg(feature = "dtype-u8")]
             DataType::UInt8 => any_values_to_integer::<UInt8Type>(values, strict)?.into_series(),
             #[cfg(feature = "dtype-u16")]
             DataType::UInt16 => any_values_to_integer::<UInt16Type>(values, strict)?.into_series(),
             DataType::UInt32 => any_values_to_integer::<UInt32Type>(values, strict)?.into_series(),
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f16(values, strict)?.into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?.into_series(),
             DataType::Float64 => any_values_to_f64(values, strict)?.into_series(),
             DataType::Boolean => any_values_to_bool(values, strict)?.into_series(),
@@ -125,6 +131,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                 any_values_to_categorical(values, dt, strict)?
@@ -134,6 +142,13 @@ impl Series {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = DataType::map_entry(key, value);
+                any_values_to_list(values, &entries, strict)?
+                    .into_map()?
+                    .into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -194,6 +209,26 @@ fn any_values_to_integer<T: PolarsIntegerType>(
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+fn any_values_to_f16(values: &[AnyValue], strict: bool) -> PolarsResult<Float16Chunked> {
+    fn any_values_to_f16_strict(values: &[AnyValue]) -> PolarsResult<Float16Chunked> {
+        let mut builder =
+            PrimitiveChunkedBuilder::<Float16Type>::new(PlSmallStr::EMPTY, values.len());
+        for av in values {
+            match av {
+                AnyValue::Float16(i) => builder.append_value(*i),
+                AnyValue::Null => builder.append_null(),
+                av => return Err(invalid_value_error(&DataType::Float16, av)),
+            }
+        }
+        Ok(builder.finish())
+    }
+    if strict {
+        any_values_to_f16_strict(values)
+    } else {
+        Ok(any_values_to_primitive_nonstrict::<Float16Type>(values))
+    }
+}
 fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32Chunked> {
     fn any_values_to_f32_strict(values: &[AnyValue]) -> PolarsResult<Float32Chunked> {
         let mut builder =
@@ -201,6 +236,7 @@ fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32C
         for av in values {
             match av {
                 AnyValue::Float32(i) => builder.append_value(*i),
+                AnyValue::Float16(i) => builder.append_value(i.to_f32()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float32, av)),
             }
@@ -221,6 +257,7 @@ fn any_values_to_f64(values: &[AnyValue], strict: bool) -> PolarsResult<Float64C
             match av {
                 AnyValue::Float64(i) => builder.append_value(*i),
                 AnyValue::Float32(i) => builder.append_value(*i as f64),
+                AnyValue::Float16(i) => builder.append_value(i.to_f64()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float64, av)),
             }
@@ -391,6 +428,24 @@ fn any_values_to_time(values: &[AnyValue], strict: bool) -> PolarsResult<TimeChu
     Ok(builder.finish().into_time())
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Interval, av));
+                }
+                builder.append_null()
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
 #[cfg(feature = "dtype-datetime")]
 fn any_values_to_datetime(
     values: &[AnyValue],
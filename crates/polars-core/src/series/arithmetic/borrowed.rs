@@ -201,6 +201,28 @@ pub mod checked {
         }
     }
 
+    #[cfg(feature = "dtype-f16")]
+    impl NumOpsDispatchCheckedInner for Float16Type {
+        fn checked_div(lhs: &Float16Chunked, rhs: &Series) -> PolarsResult<Series> {
+            // SAFETY:
+            // see check_div for chunkedarray<T>
+            let rhs = unsafe { lhs.unpack_series_matching_physical_type(rhs) };
+
+            let ca: Float16Chunked =
+                arity::binary_elementwise(lhs, rhs, |opt_l, opt_r| match (opt_l, opt_r) {
+                    (Some(l), Some(r)) => {
+                        if r.is_zero() {
+                            None
+                        } else {
+                            Some(l / r)
+                        }
+                    },
+                    _ => None,
+                });
+            Ok(ca.into_series())
+        }
+    }
+
     impl NumOpsDispatchCheckedInner for Float32Type {
         fn checked_div(lhs: &Float32Chunked, rhs: &Series) -> PolarsResult<Series> {
             // SAFETY:
@@ -298,6 +320,21 @@ pub mod checked {
                     .unwrap()
                     .apply(|opt_v| opt_v.and_then(|v| v.checked_div(rhs.to_i64().unwrap())))
                     .into_series(),
+                #[cfg(feature = "dtype-f16")]
+                Float16 => s
+                    .f16()
+                    .unwrap()
+                    .apply(|opt_v| {
+                        opt_v.and_then(|v| {
+                            let res = rhs.to_f32().unwrap();
+                            if res.is_zero() {
+                                None
+                            } else {
+                                Some(f16::from_f32(v.to_f32() / res))
+                            }
+                        })
+                    })
+                    .into_series(),
                 Float32 => s
                     .f32()
                     .unwrap()
@@ -76,6 +76,8 @@ impl Series {
             UInt64 => UInt64Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-i128")]
             Int128 => Int128Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => UInt128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-date")]
             Date => Int32Chunked::from_chunks(name, chunks)
                 .into_date()
@@ -92,6 +94,10 @@ impl Series {
             Datetime(tu, tz) => Int64Chunked::from_chunks(name, chunks)
                 .into_datetime(*tu, tz.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Int128Chunked::from_chunks(name, chunks)
                 .into_decimal_unchecked(
@@ -106,6 +112,12 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let entries = DataType::map_entries(key, value);
+                let ca = ListChunked::from_chunks_and_dtype_unchecked(name, chunks, entries);
+                MapChunked::new_logical(ca, dtype.clone()).into_series()
+            },
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -116,6 +128,8 @@ impl Series {
                 })
             },
             Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float16Chunked::from_chunks(name, chunks).into_series(),
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
@@ -232,6 +246,9 @@ impl Series {
                 "dtype-i128",
                 Ok(Int128Chunked::from_chunks(name, chunks).into_series())
             ),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => Ok(Float16Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -302,6 +319,16 @@ impl Series {
                     ArrowTimeUnit::Nanosecond => s,
                 })
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(unit) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| interval_to_packed(arr.as_ref(), *unit))
+                    .collect::<Vec<_>>();
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
+            },
             ArrowDataType::Decimal32(precision, scale) => {
                 feature_gated!("dtype-decimal", {
                     polars_ensure!(*scale <= *precision, InvalidOperation: "invalid decimal precision and scale (prec={precision}, scale={scale})");
@@ -540,7 +567,7 @@ impl Series {
                         let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
                         let offsets: &OffsetsBuffer<i32> = arr.offsets();
 
-                        let validity = values.validity().cloned();
+                        let validity = arr.validity().cloned();
 
                         Box::from(ListArray::<i64>::new(
                             ListArray::<i64>::default_datatype(values.dtype().clone()),
@@ -551,13 +578,20 @@ impl Series {
                     })
                     .collect();
 
-                unsafe {
-                    let out = ListChunked::from_chunks_and_dtype_unchecked(
+                let out = unsafe {
+                    ListChunked::from_chunks_and_dtype_unchecked(
                         name,
                         chunks,
                         DataType::List(Box::new(dtype)),
-                    );
+                    )
+                };
 
+                #[cfg(feature = "dtype-map")]
+                {
+                    Ok(out.into_map()?.into_series())
+                }
+                #[cfg(not(feature = "dtype-map"))]
+                {
                     Ok(out.into_series())
                 }
             },
@@ -566,6 +600,39 @@ impl Series {
     }
 }
 
+/// Converts any arrow interval array to packed `i128` intervals.
+#[cfg(feature = "dtype-interval")]
+fn interval_to_packed(arr: &dyn Array, unit: arrow::datatypes::IntervalUnit) -> ArrayRef {
+    use arrow::datatypes::IntervalUnit;
+    use arrow::types::days_ms;
+
+    fn pack<T: arrow::types::NativeType>(
+        arr: &dyn Array,
+        f: impl Fn(T) -> months_days_ns,
+    ) -> ArrayRef {
+        let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        let values = arr
+            .values()
+            .iter()
+            .map(|v| interval_to_i128(f(*v)))
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(
+            ArrowDataType::Int128,
+            values.into(),
+            arr.validity().cloned(),
+        )
+        .to_boxed()
+    }
+
+    match unit {
+        IntervalUnit::YearMonth => pack(arr, |m: i32| months_days_ns::new(m, 0, 0)),
+        IntervalUnit::DayTime => pack(arr, |v: days_ms| {
+            months_days_ns::new(0, v.days(), v.milliseconds() as i64 * 1_000_000)
+        }),
+        IntervalUnit::MonthDayNano => pack(arr, |v: months_days_ns| v),
+    }
+}
+
 fn convert<F: Fn(&dyn Array) -> ArrayRef>(arr: &[ArrayRef], f: F) -> Vec<ArrayRef> {
     arr.iter().map(|arr| f(&**arr)).collect()
 }
@@ -719,6 +786,7 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Timestamp(_, _)
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
+        | ArrowDataType::Interval(_)
         | ArrowDataType::Date64
         | ArrowDataType::Map(_, _)) => {
             let dt = dt.clone();
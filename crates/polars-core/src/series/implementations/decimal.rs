@@ -164,6 +164,22 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         self.agg_helper(|ca| ca.agg_max(groups))
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0
+            .cast(&DataType::Float64)
+            .unwrap()
+            .agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0
+            .cast(&DataType::Float64)
+            .unwrap()
+            .agg_var(groups, ddof)
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
         self.agg_helper(|ca| ca.agg_list(groups))
@@ -386,14 +402,9 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_physical(|ca| {
-            let sum = ca.sum();
-            let DataType::Decimal(_, Some(scale)) = self.dtype() else {
-                unreachable!()
-            };
-            let av = AnyValue::Decimal(sum.unwrap(), *scale);
-            Scalar::new(self.dtype().clone(), av)
-        }))
+        let sum = self.0.checked_sum()?;
+        let av = AnyValue::Decimal(sum, self.0.scale());
+        Ok(Scalar::new(self.dtype().clone(), av))
     }
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         Ok(self.apply_physical(|ca| {
@@ -454,6 +465,11 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
         Ok(self.apply_scale(self.0.physical().std_reduce(ddof)))
     }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        // The variance scales with the square of the scale factor.
+        let var = self.0.physical().var_reduce(ddof);
+        Ok(self.apply_scale(self.apply_scale(var)))
+    }
 
     fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
         self.0
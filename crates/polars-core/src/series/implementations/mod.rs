@@ -15,7 +15,11 @@ mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 mod floats;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
@@ -475,6 +479,8 @@ impl_dyn_series!(Int32Chunked, Int32Type);
 impl_dyn_series!(Int64Chunked, Int64Type);
 #[cfg(feature = "dtype-i128")]
 impl_dyn_series!(Int128Chunked, Int128Type);
+#[cfg(feature = "dtype-u128")]
+impl_dyn_series!(UInt128Chunked, UInt128Type);
 
 impl<T: PolarsNumericType> private::PrivateSeriesNumeric for SeriesWrap<ChunkedArray<T>> {
     fn bit_repr(&self) -> Option<BitRepr> {
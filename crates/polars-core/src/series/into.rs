@@ -1,3 +1,7 @@
+#[cfg(feature = "dtype-map")]
+use arrow::array::MapArray;
+#[cfg(feature = "dtype-map")]
+use arrow::offset::OffsetsBuffer;
 #[cfg(any(
     feature = "dtype-datetime",
     feature = "dtype-date",
@@ -85,6 +89,41 @@ impl Series {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let ca = self.map().unwrap();
+                let arr = ca.physical().chunks()[chunk_idx].clone();
+                // Convert the entries like any other list so the logical key/value types are
+                // applied, then rewrap them as an arrow map.
+                let entries = unsafe {
+                    Series::from_chunks_and_dtype_unchecked(
+                        PlSmallStr::EMPTY,
+                        vec![arr],
+                        &DataType::map_entries(key, value),
+                    )
+                }
+                .to_arrow(0, compat_level);
+                let entries = entries.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let values = entries
+                    .values()
+                    .as_any()
+                    .downcast_ref::<StructArray>()
+                    .unwrap();
+
+                let dtype = self.dtype().to_arrow(compat_level);
+                let ArrowDataType::Map(entries_field, _) = &dtype else {
+                    unreachable!()
+                };
+                let values = StructArray::new(
+                    entries_field.dtype().clone(),
+                    values.len(),
+                    values.values().to_vec(),
+                    values.validity().cloned(),
+                );
+                let offsets = OffsetsBuffer::<i32>::try_from(entries.offsets())
+                    .expect("map offsets should fit in 32 bits");
+                MapArray::new(dtype, offsets, values.boxed(), entries.validity().cloned()).boxed()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, width) => {
                 let ca = self.array().unwrap();
@@ -149,6 +188,24 @@ impl Series {
                 &DataType::Time.to_arrow(compat_level),
             )
             .unwrap(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let arr = self.interval().unwrap().physical().chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i128>>()
+                    .unwrap();
+                let values = arr
+                    .values()
+                    .iter()
+                    .map(|v| i128_to_interval(*v))
+                    .collect::<Vec<_>>();
+                PrimitiveArray::new(
+                    self.dtype().to_arrow(compat_level),
+                    values.into(),
+                    arr.validity().cloned(),
+                )
+                .to_boxed()
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => self.decimal().unwrap().physical().chunks()[chunk_idx]
                 .as_any()
@@ -543,6 +543,8 @@ impl Series {
                 feature_gated!("dtype-duration", Ok(self.clone().into_duration(*tu)))
             },
             (D::Int64, D::Time) => feature_gated!("dtype-time", Ok(self.clone().into_time())),
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => Ok(self.clone().into_interval()),
 
             (D::List(_), D::List(to)) => unsafe {
                 self.list()
@@ -550,6 +552,12 @@ impl Series {
                     .from_physical_unchecked(to.as_ref().clone())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(key, value)) => unsafe {
+                let entries = DataType::map_entry(key, value);
+                let ca = self.list().unwrap().from_physical_unchecked(entries)?;
+                Ok(MapChunked::new_logical(ca, dtype.clone()).into_series())
+            },
             #[cfg(feature = "dtype-array")]
             (D::Array(_, lw), D::Array(to, rw)) if lw == rw => unsafe {
                 self.array()
@@ -572,7 +580,7 @@ impl Series {
     /// Cast numerical types to f64, and keep floats as is.
     pub fn to_float(&self) -> PolarsResult<Series> {
         match self.dtype() {
-            DataType::Float32 | DataType::Float64 => Ok(self.clone()),
+            DataType::Float16 | DataType::Float32 | DataType::Float64 => Ok(self.clone()),
             _ => self.cast_with_options(&DataType::Float64, CastOptions::Overflowing),
         }
     }
@@ -620,6 +628,8 @@ impl Series {
             DataType::List(_) => self.list().unwrap().explode(skip_empty),
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => self.array().unwrap().explode(skip_empty),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => self.map().unwrap().physical().explode(skip_empty),
             _ => Ok(self.clone()),
         }
     }
@@ -627,6 +637,8 @@ impl Series {
     /// Check if numeric value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.f16().unwrap().is_nan()),
             DataType::Float32 => Ok(self.f32().unwrap().is_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_nan()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
@@ -642,6 +654,8 @@ impl Series {
     /// Check if numeric value is NaN (note this is different than missing/null)
     pub fn is_not_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.f16().unwrap().is_not_nan()),
             DataType::Float32 => Ok(self.f32().unwrap().is_not_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_not_nan()),
             dt if dt.is_primitive_numeric() => {
@@ -656,6 +670,8 @@ impl Series {
     /// Check if numeric value is finite
     pub fn is_finite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.f16().unwrap().is_finite()),
             DataType::Float32 => Ok(self.f32().unwrap().is_finite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_finite()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
@@ -671,6 +687,8 @@ impl Series {
     /// Check if numeric value is infinite
     pub fn is_infinite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.f16().unwrap().is_infinite()),
             DataType::Float32 => Ok(self.f32().unwrap().is_infinite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_infinite()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
@@ -700,6 +718,7 @@ impl Series {
     /// * Duration -> Int64
     /// * Decimal -> Int128
     /// * Time -> Int64
+    /// * Interval -> Int128
     /// * Categorical -> U8/U16/U32
     /// * List(inner) -> List(physical of inner)
     /// * Array(inner) -> Array(physical of inner)
@@ -717,6 +736,8 @@ impl Series {
             Duration(_) => Cow::Owned(self.duration().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Cow::Owned(self.time().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-categorical")]
             dt @ (Categorical(_, _) | Enum(_, _)) => {
                 with_match_categorical_physical_type!(dt.cat_physical().unwrap(), |$C| {
@@ -730,6 +751,15 @@ impl Series {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => Cow::Owned(
+                self.map()
+                    .unwrap()
+                    .physical()
+                    .to_physical_repr()
+                    .into_owned()
+                    .into_series(),
+            ),
             #[cfg(feature = "dtype-array")]
             Array(_, _) => match self.array().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
@@ -790,6 +820,10 @@ impl Series {
                 UInt64 => Ok(self.u64().unwrap().prod_reduce()),
                 #[cfg(feature = "dtype-i128")]
                 Int128 => Ok(self.i128().unwrap().prod_reduce()),
+                #[cfg(feature = "dtype-u128")]
+                UInt128 => Ok(self.u128().unwrap().prod_reduce()),
+                #[cfg(feature = "dtype-f16")]
+                Float16 => Ok(self.f16().unwrap().prod_reduce()),
                 Float32 => Ok(self.f32().unwrap().prod_reduce()),
                 Float64 => Ok(self.f64().unwrap().prod_reduce()),
                 dt => {
@@ -846,6 +880,21 @@ impl Series {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn into_interval(self) -> Series {
+        match self.dtype() {
+            DataType::Int128 => self.i128().unwrap().clone().into_interval().into_series(),
+            DataType::Interval => self
+                .interval()
+                .unwrap()
+                .physical()
+                .clone()
+                .into_interval()
+                .into_series(),
+            dt => panic!("into_interval not implemented for {dt:?}"),
+        }
+    }
+
     pub(crate) fn into_date(self) -> Series {
         #[cfg(not(feature = "dtype-date"))]
         {
@@ -934,6 +983,15 @@ impl Series {
     }
 
     pub fn mean_reduce(&self) -> Scalar {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.decimal() {
+            let scale = crate::chunked_array::arithmetic::_get_decimal_scale_mean(ca.scale());
+            let av = match ca.mean_decimal() {
+                Ok(Some(v)) => AnyValue::Decimal(v, scale),
+                _ => AnyValue::Null,
+            };
+            return Scalar::new(ca.mean_dtype(), av);
+        }
         crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
     }
 
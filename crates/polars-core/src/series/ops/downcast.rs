@@ -68,6 +68,18 @@ impl Series {
         try_unpack_chunked!(self, DataType::Int128 => Int128Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn try_u128(&self) -> Option<&UInt128Chunked> {
+        try_unpack_chunked!(self, DataType::UInt128 => UInt128Chunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_f16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         try_unpack_chunked!(self, DataType::Float32 => Float32Chunked)
@@ -142,12 +154,24 @@ impl Series {
         try_unpack_chunked!(self, DataType::Duration(_) => DurationChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
         try_unpack_chunked!(self, DataType::Decimal(_, _) => DecimalChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn try_list(&self) -> Option<&ListChunked> {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
@@ -242,6 +266,20 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Int128"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.try_u128()
+            .ok_or_else(|| unpack_chunked_err!(self => "UInt128"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_f16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.try_f32()
@@ -330,6 +368,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Duration"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
@@ -337,6 +382,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Decimal"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.try_map()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn list(&self) -> PolarsResult<&ListChunked> {
         self.try_list()
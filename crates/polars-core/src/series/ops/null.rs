@@ -13,6 +13,13 @@ impl Series {
             DataType::List(inner_dtype) => {
                 ListChunked::full_null_with_dtype(name, size, inner_dtype).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = DataType::map_entry(key, value);
+                let ca = ListChunked::full_null_with_dtype(name, size, &entries);
+                // SAFETY: the entries are `{key, value}` structs.
+                unsafe { MapChunked::new_logical(ca, dtype.clone()) }.into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
@@ -44,6 +51,10 @@ impl Series {
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, scale.unwrap_or(0))
@@ -376,6 +376,13 @@ macro_rules! match_dtype_to_physical_apply_macro {
             DataType::Int64 => $macro!(i64 $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!(i128 $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!(u128 $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                use $crate::datatypes::f16;
+                $macro!(f16 $(, $opt_args)*)
+            },
             DataType::Float32 => $macro!(f32 $(, $opt_args)*),
             DataType::Float64 => $macro!(f64 $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -405,6 +412,10 @@ macro_rules! match_dtype_to_logical_apply_macro {
             DataType::Int64 => $macro!(Int64Type $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!(Int128Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!(UInt128Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $macro!(Float16Type $(, $opt_args)*),
             DataType::Float32 => $macro!(Float32Type $(, $opt_args)*),
             DataType::Float64 => $macro!(Float64Type $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -433,6 +444,10 @@ macro_rules! match_arrow_dtype_apply_macro_ca {
             DataType::Int64 => $macro!($self.i64().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!($self.i128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!($self.u128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $macro!($self.f16().unwrap() $(, $opt_args)*),
             DataType::Float32 => $macro!($self.f32().unwrap() $(, $opt_args)*),
             DataType::Float64 => $macro!($self.f64().unwrap() $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -455,12 +470,19 @@ macro_rules! with_match_physical_numeric_type {(
         Int64 => __with_ty__! { i64 },
         #[cfg(feature = "dtype-i128")]
         Int128 => __with_ty__! { i128 },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { u128 },
         #[cfg(feature = "dtype-u8")]
         UInt8 => __with_ty__! { u8 },
         #[cfg(feature = "dtype-u16")]
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            use $crate::datatypes::f16;
+            __with_ty__! { f16 }
+        },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -482,6 +504,8 @@ macro_rules! with_match_physical_integer_type {(
         Int64 => __with_ty__! { i64 },
         #[cfg(feature = "dtype-i128")]
         Int128 => __with_ty__! { i128 },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { u128 },
         #[cfg(feature = "dtype-u8")]
         UInt8 => __with_ty__! { u8 },
         #[cfg(feature = "dtype-u16")]
@@ -499,6 +523,11 @@ macro_rules! with_match_physical_float_type {(
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use $crate::datatypes::DataType::*;
     match $dtype {
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            use $crate::datatypes::f16;
+            __with_ty__! { f16 }
+        },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -512,6 +541,8 @@ macro_rules! with_match_physical_float_polars_type {(
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use $crate::datatypes::DataType::*;
     match $key_type {
+        #[cfg(feature = "dtype-f16")]
+        Float16 => __with_ty__! { Float16Type },
         Float32 => __with_ty__! { Float32Type },
         Float64 => __with_ty__! { Float64Type },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -533,12 +564,16 @@ macro_rules! with_match_physical_numeric_polars_type {(
         Int64 => __with_ty__! { Int64Type },
             #[cfg(feature = "dtype-i128")]
         Int128 => __with_ty__! { Int128Type },
+            #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
             #[cfg(feature = "dtype-u8")]
         UInt8 => __with_ty__! { UInt8Type },
             #[cfg(feature = "dtype-u16")]
         UInt16 => __with_ty__! { UInt16Type },
         UInt32 => __with_ty__! { UInt32Type },
         UInt64 => __with_ty__! { UInt64Type },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => __with_ty__! { Float16Type },
         Float32 => __with_ty__! { Float32Type },
         Float64 => __with_ty__! { Float64Type },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -561,6 +596,8 @@ macro_rules! with_match_physical_integer_polars_type {(
         Int64 => __with_ty__! { Int64Type },
         #[cfg(feature = "dtype-i128")]
         Int128 => __with_ty__! { Int128Type },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
         #[cfg(feature = "dtype-u8")]
         UInt8 => __with_ty__! { UInt8Type },
         #[cfg(feature = "dtype-u16")]
@@ -603,6 +640,10 @@ macro_rules! downcast_as_macro_arg_physical {
             DataType::Int64 => $macro!($self.i64().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!($self.i128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!($self.u128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $macro!($self.f16().unwrap() $(, $opt_args)*),
             DataType::Float32 => $macro!($self.f32().unwrap() $(, $opt_args)*),
             DataType::Float64 => $macro!($self.f64().unwrap() $(, $opt_args)*),
             dt => panic!("not implemented for {:?}", dt),
@@ -658,6 +699,16 @@ macro_rules! downcast_as_macro_arg_physical_mut {
                 let ca: &mut Int128Chunked = $self.as_mut();
                 $macro!(Int128Type, ca $(, $opt_args)*)
             },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                let ca: &mut UInt128Chunked = $self.as_mut();
+                $macro!(UInt128Type, ca $(, $opt_args)*)
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let ca: &mut Float16Chunked = $self.as_mut();
+                $macro!(Float16Type, ca $(, $opt_args)*)
+            },
             DataType::Float32 => {
                 let ca: &mut Float32Chunked = $self.as_mut();
                 $macro!(Float32Type, ca $(, $opt_args)*)
@@ -691,6 +742,10 @@ macro_rules! apply_method_all_arrow_series {
             DataType::Int64 => $self.i64().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $self.i128().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $self.u128().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $self.f16().unwrap().$method($($args),*),
             DataType::Float32 => $self.f32().unwrap().$method($($args),*),
             DataType::Float64 => $self.f64().unwrap().$method($($args),*),
             DataType::Time => $self.time().unwrap().$method($($args),*),
@@ -721,6 +776,8 @@ macro_rules! apply_method_physical_integer {
             DataType::Int64 => $self.i64().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $self.i128().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $self.u128().unwrap().$method($($args),*),
             dt => panic!("not implemented for dtype {:?}", dt),
         }
     }
@@ -731,6 +788,8 @@ macro_rules! apply_method_physical_integer {
 macro_rules! apply_method_physical_numeric {
     ($self:expr, $method:ident, $($args:expr),*) => {
         match $self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $self.f16().unwrap().$method($($args),*),
             DataType::Float32 => $self.f32().unwrap().$method($($args),*),
             DataType::Float64 => $self.f64().unwrap().$method($($args),*),
             _ => apply_method_physical_integer!($self, $method, $($args),*),
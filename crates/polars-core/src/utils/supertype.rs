@@ -57,6 +57,7 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
         }
     } else if l.is_unsigned_integer() && r.is_unsigned_integer() {
         match (l, r) {
+            (UInt128, _) | (_, UInt128) => Some(UInt128),
             (UInt64, _) | (_, UInt64) => Some(UInt64),
             (UInt32, _) | (_, UInt32) => Some(UInt32),
             (UInt16, _) | (_, UInt16) => Some(UInt16),
@@ -73,6 +74,8 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
         // One side is signed, the other is unsigned. We just need to upcast the
         // unsigned side to a signed integer with the next-largest bit width.
         match (l, r) {
+            // There is no signed integer wide enough to hold every UInt128.
+            (UInt128, _) | (_, UInt128) => None,
             (UInt64, _) | (_, UInt64) | (Int128, _) | (_, Int128) => Some(Int128),
             (UInt32, _) | (_, UInt32) | (Int64, _) | (_, Int64) => Some(Int64),
             (UInt16, _) | (_, UInt16) | (Int32, _) | (_, Int32) => Some(Int32),
@@ -198,6 +201,10 @@ pub fn get_supertype_with_options(
             (a, Int128) if a.is_integer() | a.is_bool() => Some(Int128),
             #[cfg(feature = "dtype-i128")]
             (a, Int128) if a.is_float() => Some(Float64),
+            #[cfg(feature = "dtype-u128")]
+            (a, UInt128) if a.is_unsigned_integer() | a.is_bool() => Some(UInt128),
+            #[cfg(feature = "dtype-u128")]
+            (a, UInt128) if a.is_signed_integer() | a.is_float() => Some(Float64), // Follow numpy
             #[cfg(feature = "dtype-i128")]
 
 
@@ -269,6 +276,25 @@ pub fn get_supertype_with_options(
 
             (Float32, Float64) => Some(Float64),
 
+            // Half-precision floats can represent 8-bit integers exactly, wider
+            // integers need a wider float.
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Boolean) => Some(Float16),
+            #[cfg(all(feature = "dtype-f16", feature = "dtype-i8"))]
+            (Float16, Int8) => Some(Float16),
+            #[cfg(all(feature = "dtype-f16", feature = "dtype-u8"))]
+            (Float16, UInt8) => Some(Float16),
+            #[cfg(all(feature = "dtype-f16", feature = "dtype-i16"))]
+            (Float16, Int16) => Some(Float32),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, UInt16) => Some(Float32),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Int32 | Int64 | UInt32 | UInt64) => Some(Float64),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Float32) => Some(Float32),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Float64) => Some(Float64),
+
             #[cfg(feature = "dtype-u8")]
             (Float64, UInt8) => Some(Float64),
             #[cfg(feature = "dtype-u16")]
@@ -355,6 +381,8 @@ pub fn get_supertype_with_options(
             (Duration(_), Date) | (Date, Duration(_)) => Some(Date),
             #[cfg(feature = "dtype-duration")]
             (Duration(lu), Duration(ru)) => Some(Duration(get_time_units(lu, ru))),
+            #[cfg(feature = "dtype-interval")]
+            (Duration(_), Interval) | (Interval, Duration(_)) => Some(Interval),
 
             // both None or both Some("<tz>") timezones
             // we cast from more precision to higher precision as that always fits with occasional loss of precision
@@ -381,6 +409,12 @@ pub fn get_supertype_with_options(
                 let st = get_supertype(inner_left, inner_right)?;
                 Some(Array(Box::new(st), *width_left))
             }
+            #[cfg(feature = "dtype-map")]
+            (Map(key_left, value_left), Map(key_right, value_right)) => {
+                let key = get_supertype(key_left, key_right)?;
+                let value = get_supertype(value_left, value_right)?;
+                Some(Map(Box::new(key), Box::new(value)))
+            }
             (List(inner), other) | (other, List(inner)) if options.allow_implode_list() => {
                 let st = get_supertype(inner, other)?;
                 Some(List(Box::new(st)))
@@ -437,6 +471,10 @@ pub fn get_supertype_with_options(
                                     // Ensure we don't cast to float when dealing with dynamic literals
                                     Some(Int64)
                                 },
+                                #[cfg(feature = "dtype-i128")]
+                                UInt128 if smallest_fitting_dtype.is_signed_integer() => {
+                                    Some(Int128)
+                                },
                                 _ => {
                                     get_supertype(dt, &smallest_fitting_dtype)
                                 }
@@ -465,7 +503,7 @@ pub fn get_supertype_with_options(
                 Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
             }
             #[cfg(feature = "dtype-decimal")]
-            (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
+            (Decimal(_, _), f @ (Float16 | Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
             (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
             _ => None,
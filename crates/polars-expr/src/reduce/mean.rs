@@ -1,6 +1,10 @@
 use std::marker::PhantomData;
 
 use num_traits::{AsPrimitive, Zero};
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _decimal_mean, _get_decimal_precision_mean, _get_decimal_scale_mean,
+};
 use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
@@ -16,7 +20,7 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalMeanReducer)),
 
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
@@ -27,6 +31,14 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
 
 fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
     match dtype {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let ca: Float16Chunked = values
+                .into_iter()
+                .map(|(s, c)| (c != 0).then(|| f16::from_f64(s / c as f64)))
+                .collect_ca(PlSmallStr::EMPTY);
+            ca.into_series()
+        },
         DataType::Float32 => {
             let ca: Float32Chunked = values
                 .into_iter()
@@ -41,15 +53,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(scale.unwrap() as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const MS_IN_DAY: i64 = 86_400_000;
@@ -122,6 +125,64 @@ where
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalMeanReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMeanReducer {
+    type Dtype = Int128Type;
+    type Value = (i128, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (0, 0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 = a.0.wrapping_add(b.0);
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        a.0 = a.0.wrapping_add(b.unwrap_or(0));
+        a.1 += b.is_some() as usize;
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        v.0 = v.0.wrapping_add(ca.sum().unwrap_or(0));
+        v.1 += ca.len() - ca.null_count();
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let ca: Int128Chunked = v
+            .into_iter()
+            .map(|(s, c)| _decimal_mean(s, c, *scale))
+            .collect_ca(PlSmallStr::EMPTY);
+        Ok(ca
+            .into_decimal_unchecked(
+                _get_decimal_precision_mean(*precision),
+                _get_decimal_scale_mean(*scale),
+            )
+            .into_series())
+    }
+}
+
 #[derive(Clone)]
 struct BoolMeanReducer;
 
@@ -26,6 +26,8 @@ pub fn new_min_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         Float64 if propagate_nans => {
             Box::new(VMGR::new(dtype, NumReducer::<NanMin<Float64Type>>::new()))
         },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float16Type>>::new())),
         Float32 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float32Type>>::new())),
         Float64 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float64Type>>::new())),
         Null => Box::new(NullGroupedReduction::default()),
@@ -37,6 +39,8 @@ pub fn new_min_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(VMGR::new(dtype, NumReducer::<Min<Int128Type>>::new())),
+        #[cfg(feature = "dtype-interval")]
+        Interval => Box::new(VMGR::new(dtype, NumReducer::<Min<Int128Type>>::new())),
         #[cfg(feature = "dtype-categorical")]
         Categorical(cats, map) => with_match_categorical_physical_type!(cats.physical(), |$C| {
             Box::new(VMGR::new(dtype.clone(), CatMinReducer::<$C>(map.clone(), PhantomData)))
@@ -58,6 +62,8 @@ pub fn new_max_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         Float64 if propagate_nans => {
             Box::new(VMGR::new(dtype, NumReducer::<NanMax<Float64Type>>::new()))
         },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float16Type>>::new())),
         Float32 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float32Type>>::new())),
         Float64 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float64Type>>::new())),
         Null => Box::new(NullGroupedReduction::default()),
@@ -69,6 +75,8 @@ pub fn new_max_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(VMGR::new(dtype, NumReducer::<Max<Int128Type>>::new())),
+        #[cfg(feature = "dtype-interval")]
+        Interval => Box::new(VMGR::new(dtype, NumReducer::<Max<Int128Type>>::new())),
         #[cfg(feature = "dtype-categorical")]
         Categorical(cats, map) => with_match_categorical_physical_type!(cats.physical(), |$C| {
             Box::new(VMGR::new(dtype.clone(), CatMaxReducer::<$C>(map.clone(), PhantomData)))
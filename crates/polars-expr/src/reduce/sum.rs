@@ -30,6 +30,10 @@ impl_sum_cast!(
 impl_sum_cast!(u32, u64, i32, i64, f32, f64);
 #[cfg(feature = "dtype-i128")]
 impl_sum_cast!(i128);
+#[cfg(feature = "dtype-u128")]
+impl_sum_cast!(u128);
+#[cfg(feature = "dtype-f16")]
+impl_sum_cast!(f16);
 
 fn out_dtype(in_dtype: &DataType) -> DataType {
     use DataType::*;
@@ -52,6 +56,8 @@ pub fn new_sum_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
         },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(VGR::new(dtype, NumSumReducer::<Int128Type>(PhantomData))),
+        #[cfg(feature = "dtype-interval")]
+        Interval => Box::new(VGR::new(dtype, NumSumReducer::<Int128Type>(PhantomData))),
         Duration(_) => Box::new(VGR::new(dtype, NumSumReducer::<Int64Type>(PhantomData))),
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
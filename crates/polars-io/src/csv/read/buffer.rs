@@ -91,6 +91,13 @@ impl PrimitiveParser for Int128Type {
         atoi_simd::parse_skipped(bytes).ok()
     }
 }
+#[cfg(feature = "dtype-u128")]
+impl PrimitiveParser for UInt128Type {
+    #[inline]
+    fn parse(bytes: &[u8]) -> Option<u128> {
+        atoi_simd::parse_skipped(bytes).ok()
+    }
+}
 
 trait ParsedBuffer {
     fn parse_bytes(
@@ -510,6 +517,8 @@ pub fn init_buffers(
                 &DataType::Int64 => Buffer::Int64(PrimitiveChunkedBuilder::new(name, capacity)),
                 #[cfg(feature = "dtype-i128")]
                 &DataType::Int128 => Buffer::Int128(PrimitiveChunkedBuilder::new(name, capacity)),
+                #[cfg(feature = "dtype-u128")]
+                &DataType::UInt128 => Buffer::UInt128(PrimitiveChunkedBuilder::new(name, capacity)),
                 #[cfg(feature = "dtype-u8")]
                 &DataType::UInt8 => Buffer::UInt8(PrimitiveChunkedBuilder::new(name, capacity)),
                 #[cfg(feature = "dtype-u16")]
@@ -596,6 +605,8 @@ pub enum Buffer {
     Int64(PrimitiveChunkedBuilder<Int64Type>),
     #[cfg(feature = "dtype-i128")]
     Int128(PrimitiveChunkedBuilder<Int128Type>),
+    #[cfg(feature = "dtype-u128")]
+    UInt128(PrimitiveChunkedBuilder<UInt128Type>),
     #[cfg(feature = "dtype-u8")]
     UInt8(PrimitiveChunkedBuilder<UInt8Type>),
     #[cfg(feature = "dtype-u16")]
@@ -636,6 +647,8 @@ impl Buffer {
             Buffer::Int64(v) => v.finish().into_series(),
             #[cfg(feature = "dtype-i128")]
             Buffer::Int128(v) => v.finish().into_series(),
+            #[cfg(feature = "dtype-u128")]
+            Buffer::UInt128(v) => v.finish().into_series(),
             #[cfg(feature = "dtype-u8")]
             Buffer::UInt8(v) => v.finish().into_series(),
             #[cfg(feature = "dtype-u16")]
@@ -691,6 +704,8 @@ impl Buffer {
             Buffer::Int64(v) => v.append_null(),
             #[cfg(feature = "dtype-i128")]
             Buffer::Int128(v) => v.append_null(),
+            #[cfg(feature = "dtype-u128")]
+            Buffer::UInt128(v) => v.append_null(),
             #[cfg(feature = "dtype-u8")]
             Buffer::UInt8(v) => v.append_null(),
             #[cfg(feature = "dtype-u16")]
@@ -732,6 +747,8 @@ impl Buffer {
             Buffer::Int64(_) => DataType::Int64,
             #[cfg(feature = "dtype-i128")]
             Buffer::Int128(_) => DataType::Int128,
+            #[cfg(feature = "dtype-u128")]
+            Buffer::UInt128(_) => DataType::UInt128,
             #[cfg(feature = "dtype-u8")]
             Buffer::UInt8(_) => DataType::UInt8,
             #[cfg(feature = "dtype-u16")]
@@ -815,6 +832,15 @@ impl Buffer {
                 missing_is_null,
                 None,
             ),
+            #[cfg(feature = "dtype-u128")]
+            UInt128(buf) => <PrimitiveChunkedBuilder<UInt128Type> as ParsedBuffer>::parse_bytes(
+                buf,
+                bytes,
+                ignore_errors,
+                needs_escaping,
+                missing_is_null,
+                None,
+            ),
             #[cfg(feature = "dtype-u8")]
             UInt8(buf) => <PrimitiveChunkedBuilder<UInt8Type> as ParsedBuffer>::parse_bytes(
                 buf,
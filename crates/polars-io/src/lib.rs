@@ -33,6 +33,8 @@ pub mod path_utils;
 pub mod pl_async;
 pub mod predicates;
 pub mod prelude;
+#[cfg(feature = "ipc")]
+pub mod result_cache;
 mod shared;
 pub mod utils;
 
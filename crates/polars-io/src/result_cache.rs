@@ -0,0 +1,201 @@
+//! A persistent cache for query results.
+//!
+//! Results are stored as IPC files in a local directory, keyed by a fingerprint of the (sub)plan
+//! that produced them. This allows reusing them across queries and processes. Once the total size
+//! of the cache exceeds its maximum, the least recently accessed results are evicted.
+
+use std::fs::{File, FileTimes};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_error::feature_gated;
+use polars_utils::plpath::PlPathRef;
+
+use crate::cloud::CloudOptions;
+use crate::ipc::{IpcReader, IpcWriter};
+use crate::path_utils::{POLARS_TEMP_DIR_BASE_PATH, ensure_directory_init};
+use crate::prelude::{SerReader, SerWriter};
+
+const EXTENSION: &str = "ipc";
+
+/// The process-wide result cache. This is `None` (and results are not cached) if the cache
+/// could not be set up, e.g. because its directory cannot be created.
+pub static RESULT_CACHE: LazyLock<Option<ResultCache>> = LazyLock::new(|| {
+    let dir = std::env::var("POLARS_RESULT_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| POLARS_TEMP_DIR_BASE_PATH.join("result-cache/"));
+
+    if config::verbose() {
+        eprintln!("result cache directory: {}", dir.to_string_lossy());
+    }
+
+    let cache = get_env_result_cache_max_size()
+        .and_then(|max_size| ResultCache::new(dir.clone(), max_size));
+    match cache {
+        Ok(cache) => Some(cache),
+        Err(err) => {
+            polars_warn!(
+                "result cache is disabled: path = {}, err = {}",
+                dir.to_string_lossy(),
+                err
+            );
+            None
+        },
+    }
+});
+
+/// Maximum size of the result cache in bytes, 1 GiB by default.
+pub fn get_env_result_cache_max_size() -> PolarsResult<u64> {
+    match std::env::var("POLARS_RESULT_CACHE_MAX_SIZE") {
+        Ok(v) => v.parse::<u64>().map_err(|_| {
+            polars_err!(InvalidOperation: "POLARS_RESULT_CACHE_MAX_SIZE must be a number of bytes; found '{}'", v)
+        }),
+        Err(_) => Ok(1 << 30),
+    }
+}
+
+pub struct ResultCache {
+    dir: Box<Path>,
+    max_size: u64,
+}
+
+impl ResultCache {
+    pub fn new(dir: PathBuf, max_size: u64) -> PolarsResult<Self> {
+        ensure_directory_init(&dir)?;
+        Ok(Self {
+            dir: dir.into_boxed_path(),
+            max_size,
+        })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{EXTENSION}"))
+    }
+
+    /// Get the result stored under `key`, if any.
+    pub fn get(&self, key: &str) -> Option<DataFrame> {
+        let path = self.path(key);
+        let file = File::open(&path).ok()?;
+
+        // The last accessed time determines the eviction order.
+        let _ = file.set_times(FileTimes::new().set_accessed(SystemTime::now()));
+
+        match IpcReader::new(file).finish() {
+            Ok(df) => Some(df),
+            Err(err) => {
+                if config::verbose() {
+                    eprintln!(
+                        "[result_cache] removing unreadable entry: path = {}, err = {}",
+                        path.to_str().unwrap(),
+                        err
+                    );
+                }
+                let _ = std::fs::remove_file(&path);
+                None
+            },
+        }
+    }
+
+    /// Store `df` under `key` and evict old results if the cache has grown too large.
+    pub fn insert(&self, key: &str, df: &mut DataFrame) -> PolarsResult<()> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        // Readers, possibly in other processes, must never see a partially written file.
+        let tmp_path = self
+            .dir
+            .join(format!("{key}.{}-{nanos}.tmp", std::process::id()));
+
+        let result = File::create(&tmp_path)
+            .map_err(PolarsError::from)
+            .and_then(|file| IpcWriter::new(file).finish(df))
+            .and_then(|_| std::fs::rename(&tmp_path, self.path(key)).map_err(PolarsError::from));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+        result?;
+
+        self.evict();
+        Ok(())
+    }
+
+    /// Remove the least recently accessed results until the cache fits in its maximum size.
+    fn evict(&self) {
+        let Ok(read_dir) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+
+        let mut entries = read_dir
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != EXTENSION {
+                    return None;
+                }
+                let metadata = std::fs::metadata(&path).ok()?;
+                let accessed = metadata.accessed().or_else(|_| metadata.modified()).ok()?;
+                Some((accessed, metadata.len(), path))
+            })
+            .collect::<Vec<_>>();
+
+        let mut total_size: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        if total_size <= self.max_size {
+            return;
+        }
+
+        entries.sort_unstable_by_key(|(accessed, _, _)| *accessed);
+        for (_, size, path) in entries {
+            if total_size <= self.max_size {
+                break;
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                if config::verbose() {
+                    eprintln!("[result_cache] evicted {}", path.to_str().unwrap());
+                }
+                total_size -= size;
+            }
+        }
+    }
+}
+
+/// Identifies the current version of a source file, such that cached results computed from it
+/// are not reused once it changes.
+pub fn source_version(
+    path: PlPathRef<'_>,
+    #[cfg_attr(not(feature = "cloud"), allow(unused))] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<String> {
+    match path {
+        PlPathRef::Local(p) => {
+            let metadata = std::fs::metadata(p)?;
+            let last_modified = metadata
+                .modified()?
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos();
+            let p = std::fs::canonicalize(p)?;
+            Ok(format!(
+                "{}:{}:{last_modified}",
+                p.to_str().unwrap(),
+                metadata.len()
+            ))
+        },
+        PlPathRef::Cloud(p) => feature_gated!("cloud", {
+            use crate::cloud::{CloudLocation, build_object_store, object_path_from_str};
+            use crate::pl_async;
+
+            let uri = p.uri();
+            let metadata = pl_async::get_runtime().block_in_place_on(async {
+                let (CloudLocation { prefix, .. }, object_store) =
+                    build_object_store(uri, cloud_options, false).await?;
+                object_store.head(&object_path_from_str(&prefix)?).await
+            })?;
+            let version = metadata
+                .e_tag
+                .unwrap_or_else(|| metadata.last_modified.timestamp_millis().to_string());
+            Ok(format!("{uri}:{}:{version}", metadata.size))
+        }),
+    }
+}
@@ -404,7 +404,11 @@ pub(crate) fn _deserialize<'a, A: Borrow<BorrowedValue<'a>>>(
         ArrowDataType::UInt64 => {
             fill_array_from::<_, _, PrimitiveArray<u64>>(deserialize_primitive_into, dtype, rows)
         },
-        ArrowDataType::Float16 => unreachable!(),
+        ArrowDataType::Float16 => fill_array_from::<_, _, PrimitiveArray<arrow::types::f16>>(
+            deserialize_primitive_into,
+            dtype,
+            rows,
+        ),
         ArrowDataType::Float32 => {
             fill_array_from::<_, _, PrimitiveArray<f32>>(deserialize_primitive_into, dtype, rows)
         },
@@ -14,7 +14,7 @@ use arrow::temporal_conversions::{
     parse_offset, time64ns_to_time, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
     timestamp_to_datetime, timestamp_us_to_datetime,
 };
-use arrow::types::NativeType;
+use arrow::types::{NativeType, f16};
 use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use streaming_iterator::StreamingIterator;
 
@@ -114,6 +114,19 @@ where
     materialize_serializer(f, array.iter(), offset, take)
 }
 
+fn float16_serializer<'a>(
+    array: &'a PrimitiveArray<f16>,
+    offset: usize,
+    take: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let f = |x: Option<&f16>, buf: &mut Vec<u8>| match x {
+        Some(x) if x.is_finite() => write_float(buf, x.to_f32()),
+        _ => buf.extend(b"null"),
+    };
+
+    materialize_serializer(f, array.iter(), offset, take)
+}
+
 #[cfg(feature = "dtype-decimal")]
 fn decimal_serializer<'a>(
     array: &'a PrimitiveArray<i128>,
@@ -465,6 +478,9 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::UInt64 => {
             primitive_serializer::<u64>(array.as_any().downcast_ref().unwrap(), offset, take)
         },
+        ArrowDataType::Float16 => {
+            float16_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
+        },
         ArrowDataType::Float32 => {
             float_serializer::<f32>(array.as_any().downcast_ref().unwrap(), offset, take)
         },
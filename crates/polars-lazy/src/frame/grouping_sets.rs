@@ -0,0 +1,109 @@
+//! Group-by over multiple grouping sets, as used by SQL `GROUPING SETS`, `ROLLUP` and `CUBE`.
+//!
+//! Every grouping set is aggregated by a separate group-by and the results are concatenated.
+//! Keys that are not part of a grouping set are filled with nulls.
+
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::prelude::*;
+
+/// Utility struct for a lazy group-by over multiple grouping sets.
+#[derive(Clone)]
+pub struct LazyGroupingSets {
+    input: LazyFrame,
+    keys: Vec<Expr>,
+    grouping_sets: Vec<Vec<usize>>,
+    indicator_prefix: Option<PlSmallStr>,
+}
+
+impl LazyGroupingSets {
+    pub(super) fn new(input: LazyFrame, keys: Vec<Expr>, grouping_sets: Vec<Vec<usize>>) -> Self {
+        Self {
+            input,
+            keys,
+            grouping_sets,
+            indicator_prefix: None,
+        }
+    }
+
+    /// Add an `Int32` column `{prefix}{key}` for every key, which is `1` if the row aggregates
+    /// over that key and `0` if it is grouped by it. This tells the nulls filled in for keys
+    /// that are not part of a grouping set apart from null keys (cf. SQL `GROUPING()`).
+    pub fn with_grouping_indicators(mut self, prefix: PlSmallStr) -> Self {
+        self.indicator_prefix = Some(prefix);
+        self
+    }
+
+    /// Aggregate every grouping set and concatenate the results.
+    ///
+    /// The output holds the keys, followed by the aggregations and the (optional) grouping
+    /// indicators. The empty grouping set aggregates over all rows, and yields a single row even
+    /// if the input is empty.
+    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> PolarsResult<LazyFrame> {
+        let aggs = aggs.as_ref();
+        let key_names = self
+            .keys
+            .iter()
+            .map(expr_output_name)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let agg_names = aggs
+            .iter()
+            .map(expr_output_name)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        polars_ensure!(
+            !self.grouping_sets.is_empty(),
+            InvalidOperation: "at least one grouping set is required"
+        );
+
+        let inputs = self
+            .grouping_sets
+            .iter()
+            .map(|set| {
+                if let Some(&idx) = set.iter().find(|&&idx| idx >= self.keys.len()) {
+                    polars_bail!(
+                        OutOfBounds: "grouping set index {} is out of bounds for {} keys",
+                        idx, self.keys.len()
+                    );
+                }
+                let lf = if set.is_empty() {
+                    self.input.clone().select(aggs)
+                } else {
+                    let keys = set.iter().map(|&idx| self.keys[idx].clone());
+                    self.input
+                        .clone()
+                        .group_by(keys.collect::<Vec<_>>())
+                        .agg(aggs)
+                };
+
+                let mut projection = key_names
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, name)| {
+                        if set.contains(&idx) {
+                            col(name.clone())
+                        } else {
+                            lit(NULL).alias(name.clone())
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                projection.extend(agg_names.iter().map(|name| col(name.clone())));
+                if let Some(prefix) = &self.indicator_prefix {
+                    projection.extend(key_names.iter().enumerate().map(|(idx, name)| {
+                        lit(i32::from(!set.contains(&idx)))
+                            .alias(format_pl_smallstr!("{prefix}{name}"))
+                    }));
+                }
+                Ok(lf.select(projection))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        concat(
+            inputs,
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )
+    }
+}
@@ -6,8 +6,11 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod grouping_sets;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "result_cache")]
+mod result_cache;
 
 use std::sync::{Arc, Mutex};
 
@@ -17,6 +20,7 @@ pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+pub use grouping_sets::LazyGroupingSets;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
@@ -211,6 +215,33 @@ impl LazyFrame {
         self
     }
 
+    /// Reorder chains of inner joins based on the estimated number of rows of their inputs.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_REORDER, toggle);
+        self
+    }
+
+    /// Push decomposable aggregations (`sum`, `min`, `max`, `count`, `len`) below joins.
+    pub fn with_eager_aggregation(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::EAGER_AGGREGATION, toggle);
+        self
+    }
+
+    /// Persist the results of subplans marked with [`LazyFrame::cache`] across queries and
+    /// processes.
+    ///
+    /// Results are stored as IPC files in `POLARS_RESULT_CACHE_DIR` (by default a directory in the
+    /// Polars temporary directory), keyed by a fingerprint of the subplan and the size and
+    /// modification time (or ETag) of the files it reads. Once the cache exceeds
+    /// `POLARS_RESULT_CACHE_MAX_SIZE` bytes (1 GiB by default) the least recently used results
+    /// are evicted. Subplans that read in-memory data or call user-defined functions are never
+    /// persisted.
+    #[cfg(feature = "result_cache")]
+    pub fn with_result_cache(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::RESULT_CACHE, toggle);
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::EAGER, toggle);
@@ -241,6 +272,14 @@ impl LazyFrame {
         Ok(self.clone().to_alp_optimized()?.describe_tree_format())
     }
 
+    /// Return a String describing the optimized logical plan, where every node is annotated with
+    /// its (estimated) number of rows.
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn describe_optimized_plan_with_statistics(&self) -> PolarsResult<String> {
+        Ok(self.clone().to_alp_optimized()?.describe_with_statistics())
+    }
+
     /// Return a String describing the logical plan.
     ///
     /// If `optimized` is `true`, explains the optimized plan. If `optimized` is `false`,
@@ -685,6 +724,10 @@ impl LazyFrame {
             _ => {},
         }
         let mut alp_plan = self.clone().to_alp_optimized()?;
+        #[cfg(feature = "result_cache")]
+        if self.opt_state.result_cache() {
+            result_cache::apply_result_cache(&mut alp_plan, engine)?;
+        }
 
         match engine {
             Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
@@ -1390,6 +1433,76 @@ impl LazyFrame {
         }
     }
 
+    /// Group by multiple grouping sets at once, as SQL `GROUPING SETS` does.
+    ///
+    /// Every grouping set holds the indices of the keys in `by` that it groups by. The results of
+    /// all grouping sets are concatenated, with the keys that are not part of a grouping set
+    /// filled with nulls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<LazyFrame> {
+    ///     // Totals per (region, product), per region and per product.
+    ///     let grouping_sets = vec![vec![0, 1], vec![0], vec![1]];
+    ///     df.lazy()
+    ///         .group_by_grouping_sets([col("region"), col("product")], grouping_sets)
+    ///         .agg([col("sales").sum()])
+    /// }
+    /// ```
+    pub fn group_by_grouping_sets<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        grouping_sets: Vec<Vec<usize>>,
+    ) -> LazyGroupingSets {
+        let keys = by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<_>>();
+        LazyGroupingSets::new(self, keys, grouping_sets)
+    }
+
+    /// Group by every prefix of `by`, from all keys down to the grand total, as SQL `ROLLUP`
+    /// does. See [`group_by_grouping_sets`][`Self::group_by_grouping_sets`].
+    pub fn group_by_rollup<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+    ) -> LazyGroupingSets {
+        let n_keys = by.as_ref().len();
+        let grouping_sets = (0..=n_keys).rev().map(|n| (0..n).collect()).collect();
+        self.group_by_grouping_sets(by, grouping_sets)
+    }
+
+    /// Group by every subset of `by`, as SQL `CUBE` does. See
+    /// [`group_by_grouping_sets`][`Self::group_by_grouping_sets`].
+    ///
+    /// As the number of grouping sets doubles with every key, at most 12 keys are supported.
+    pub fn group_by_cube<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+    ) -> PolarsResult<LazyGroupingSets> {
+        let n_keys = by.as_ref().len();
+        polars_ensure!(
+            n_keys <= 12,
+            InvalidOperation: "group_by_cube supports at most 12 keys (found {})", n_keys
+        );
+        // Bit `n_keys - 1 - i` of the mask selects key `i`, so that the sets are ordered from
+        // all keys down to the grand total.
+        let grouping_sets = (0..1usize << n_keys)
+            .rev()
+            .map(|mask| {
+                (0..n_keys)
+                    .filter(|i| mask & (1 << (n_keys - 1 - i)) != 0)
+                    .collect()
+            })
+            .collect();
+        Ok(self.group_by_grouping_sets(by, grouping_sets))
+    }
+
     /// Left anti join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -0,0 +1,132 @@
+//! Persist the results of cached subplans across queries and processes.
+//!
+//! Every [`IR::Cache`] whose input can be fingerprinted is looked up in the [`RESULT_CACHE`]. On a
+//! hit the cache node is replaced by the stored result, on a miss the subplan is executed and its
+//! result is stored before replacing the cache node. Missed subplans run on the engine of the
+//! query itself.
+
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use polars_core::config::verbose;
+use polars_io::result_cache::{RESULT_CACHE, source_version};
+use polars_plan::plans::fingerprint_plan;
+use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::unique_id::UniqueId;
+
+use super::*;
+
+/// The key under which the result of the subplan at `input` is stored. Returns `None` if the
+/// subplan cannot be fingerprinted.
+fn result_key(
+    input: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Option<String>> {
+    let Some(fingerprint) = fingerprint_plan(input, lp_arena, expr_arena) else {
+        return Ok(None);
+    };
+
+    let mut lo = PlFixedStateQuality::with_seed(0).build_hasher();
+    let mut hi = PlFixedStateQuality::with_seed(1).build_hasher();
+    fingerprint.hash.hash(&mut lo);
+    fingerprint.hash.hash(&mut hi);
+    for (path, cloud_options) in &fingerprint.sources {
+        let version = source_version(path.as_ref(), cloud_options.as_ref())?;
+        version.hash(&mut lo);
+        version.hash(&mut hi);
+    }
+    Ok(Some(format!("{:016x}{:016x}", hi.finish(), lo.finish())))
+}
+
+/// Execute the subplan below the cache node `node` on `engine`.
+fn execute_subplan(node: Node, plan: &mut IRPlan, engine: Engine) -> PolarsResult<DataFrame> {
+    match engine {
+        Engine::Streaming => feature_gated!("new_streaming", {
+            let sink = plan.lp_arena.add(IR::Sink {
+                input: node,
+                payload: SinkTypeIR::Memory,
+            });
+            polars_stream::run_query(sink, &mut plan.lp_arena, &mut plan.expr_arena)
+                .map(|v| v.unwrap_single())
+        }),
+        _ => {
+            // Execute the cache node rather than its input, so that the subplan is cloned
+            // instead of taken from the arena.
+            let mut physical_plan = create_physical_plan(
+                node,
+                &mut plan.lp_arena,
+                &mut plan.expr_arena,
+                BUILD_STREAMING_EXECUTOR,
+            )?;
+            let mut state = ExecutionState::new();
+            physical_plan.execute(&mut state)
+        },
+    }
+}
+
+pub(super) fn apply_result_cache(plan: &mut IRPlan, engine: Engine) -> PolarsResult<()> {
+    let Some(cache) = RESULT_CACHE.as_ref() else {
+        return Ok(());
+    };
+    let verbose = verbose();
+    let mut results: PlHashMap<UniqueId, DataFrame> = PlHashMap::default();
+    let mut stack = vec![plan.lp_top];
+
+    while let Some(node) = stack.pop() {
+        let IR::Cache { input, id, .. } = plan.lp_arena.get(node) else {
+            plan.lp_arena.get(node).copy_inputs(&mut stack);
+            continue;
+        };
+        let (input, id) = (*input, *id);
+
+        if let Some(df) = results.get(&id) {
+            let schema = df.schema().clone();
+            plan.lp_arena.replace(
+                node,
+                IR::DataFrameScan {
+                    df: Arc::new(df.clone()),
+                    schema,
+                    output_schema: None,
+                },
+            );
+            continue;
+        }
+
+        let Some(key) = result_key(input, &plan.lp_arena, &plan.expr_arena)? else {
+            stack.push(input);
+            continue;
+        };
+        let schema = plan.lp_arena.get(input).schema(&plan.lp_arena).into_owned();
+
+        let df = match cache
+            .get(&key)
+            .filter(|df| df.schema().as_ref() == schema.as_ref())
+        {
+            Some(df) => {
+                if verbose {
+                    eprintln!("result_cache: hit for key {key}");
+                }
+                df
+            },
+            None => {
+                if verbose {
+                    eprintln!("result_cache: miss for key {key}");
+                }
+                let mut df = execute_subplan(node, plan, engine)?;
+                cache.insert(&key, &mut df)?;
+                df
+            },
+        };
+
+        plan.lp_arena.replace(
+            node,
+            IR::DataFrameScan {
+                df: Arc::new(df.clone()),
+                schema,
+                output_schema: None,
+            },
+        );
+        results.insert(id, df);
+    }
+    Ok(())
+}
@@ -566,3 +566,51 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+fn test_group_by_rollup() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "x", "y"],
+        "b" => [1, 2, 1],
+        "c" => [1i64, 2, 3],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_rollup([col("a"), col("b")])
+        .with_grouping_indicators("grouping_".into())
+        .agg([col("c").sum()])?
+        .sort(["grouping_a", "grouping_b", "a", "b"], Default::default())
+        .collect()?;
+
+    let expected = df![
+        "a" => [Some("x"), Some("x"), Some("y"), Some("x"), Some("y"), None],
+        "b" => [Some(1), Some(2), Some(1), None, None, None],
+        "c" => [1i64, 2, 3, 3, 3, 6],
+        "grouping_a" => [0i32, 0, 0, 0, 0, 1],
+        "grouping_b" => [0i32, 0, 0, 1, 1, 1],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_group_by_cube_max_keys() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_cube([col("a")])?
+        .agg([len()])?
+        .collect()?;
+    assert_eq!(out.height(), 3);
+
+    let keys = (0..13).map(|_| col("a")).collect::<Vec<_>>();
+    assert!(df.lazy().group_by_cube(keys).is_err());
+
+    Ok(())
+}
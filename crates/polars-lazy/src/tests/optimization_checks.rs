@@ -184,6 +184,84 @@ pub fn test_slice_pushdown_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+pub fn test_slice_pushdown_left_join_many_to_one() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let categories = scan_foods_parquet(false)
+        .group_by([col("category")])
+        .agg([col("fats_g").mean().alias("mean_fats_g")]);
+
+    let q = scan_foods_parquet(false)
+        .join(
+            categories,
+            [col("category")],
+            [col("category")],
+            JoinArgs {
+                validation: JoinValidation::ManyToOne,
+                ..JoinArgs::new(JoinType::Left)
+            },
+        )
+        .slice(1, 3)
+        .with_comm_subplan_elim(false);
+
+    // Every left row produces exactly one output row, so the slice is applied to the left input.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let IR::Join {
+        input_left,
+        options,
+        ..
+    } = lp_arena.get(lp)
+    else {
+        panic!("expected the slice to be pushed below the join")
+    };
+    assert!(options.args.slice.is_none());
+    assert!(matches!(
+        lp_arena.get(*input_left),
+        IR::Scan { unified_scan_args, .. } if unified_scan_args.pre_slice.is_some()
+    ));
+
+    let out = q.collect()?;
+    assert_eq!(out.shape(), (3, 5));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+pub fn test_slice_pushdown_union_with_offset() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let q = concat(
+        [scan_foods_parquet(false), scan_foods_parquet(false)],
+        UnionArgs::default(),
+    )?
+    .slice(25, 4)
+    .with_comm_subplan_elim(false);
+
+    // Every input only has to produce the rows up to the end of the slice.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let IR::Union { inputs, options } = lp_arena.get(lp) else {
+        panic!("expected a union")
+    };
+    assert_eq!(options.slice, Some((25, 4)));
+    for input in inputs {
+        assert!(matches!(
+            lp_arena.get(*input),
+            IR::Scan { unified_scan_args, .. } if matches!(
+                unified_scan_args.pre_slice,
+                Some(polars_utils::slice_enum::Slice::Positive { offset: 0, len: 29 })
+            )
+        ));
+    }
+
+    let out = q.collect()?;
+    assert_eq!(out.height(), 4);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 pub fn test_slice_pushdown_group_by() -> PolarsResult<()> {
@@ -651,3 +729,240 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_join_reorder() -> PolarsResult<()> {
+    let fact = df![
+        "id" => (0..1000i32).collect::<Vec<_>>(),
+        "a_id" => (0..1000i32).map(|i| i % 100).collect::<Vec<_>>(),
+        "b_id" => (0..1000i32).map(|i| i % 10).collect::<Vec<_>>(),
+    ]?;
+    let a = df![
+        "a_id" => (0..100i32).collect::<Vec<_>>(),
+        "a_val" => (0..100i32).map(|i| i * 2).collect::<Vec<_>>(),
+    ]?;
+    let b = df![
+        "b_id" => (0..10i32).collect::<Vec<_>>(),
+        "b_val" => (0..10i32).map(|i| i % 3).collect::<Vec<_>>(),
+    ]?;
+
+    // The filtered `b` is much more selective, so it should be joined first.
+    let q = fact
+        .lazy()
+        .join(
+            a.lazy(),
+            [col("a_id")],
+            [col("a_id")],
+            JoinType::Inner.into(),
+        )
+        .join(
+            b.lazy().filter(col("b_val").eq(lit(0))),
+            [col("b_id")],
+            [col("b_id")],
+            JoinType::Inner.into(),
+        );
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q
+        .clone()
+        .with_join_reorder(true)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    let IR::SimpleProjection { input, .. } = lp_arena.get(lp) else {
+        panic!("expected the reordered joins to be projected")
+    };
+    let IR::Join { input_right, .. } = lp_arena.get(*input) else {
+        panic!("expected a join")
+    };
+    let IR::DataFrameScan { schema, .. } = lp_arena.get(*input_right) else {
+        panic!("expected the last join to read `a`")
+    };
+    assert!(schema.contains("a_val"));
+
+    let expected = q.clone().sort(["id"], Default::default()).collect()?;
+    let out = q
+        .with_join_reorder(true)
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), expected.get_column_names());
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_describe_plan_with_statistics() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..100i32).collect::<Vec<_>>(),
+        "b" => (0..100i32).map(|i| i % 10).collect::<Vec<_>>(),
+    ]?;
+
+    let plan = df
+        .lazy()
+        .filter(col("b").eq(lit(1)))
+        .describe_optimized_plan_with_statistics()?;
+
+    // The in-memory frame has an exact row count, the filter only an estimate.
+    assert!(plan.contains("[rows: 100]"));
+    assert!(plan.contains("[estimated rows: "));
+
+    Ok(())
+}
+
+#[test]
+fn test_eager_aggregation() -> PolarsResult<()> {
+    let fact = df![
+        "dim_id" => (0..1000i32).map(|i| i % 10).collect::<Vec<_>>(),
+        "x" => (0..1000i32).map(|i| if i % 7 == 0 { None } else { Some(i) }).collect::<Vec<_>>(),
+    ]?;
+    let dim = df![
+        "dim_id" => (0..10i32).collect::<Vec<_>>(),
+        "category" => (0..10i32).map(|i| i % 3).collect::<Vec<_>>(),
+    ]?;
+
+    let q = fact
+        .lazy()
+        .join(
+            dim.lazy(),
+            [col("dim_id")],
+            [col("dim_id")],
+            JoinType::Inner.into(),
+        )
+        .group_by([col("category")])
+        .agg([
+            col("x").sum().alias("sum"),
+            col("x").min().alias("min"),
+            col("x").max().alias("max"),
+            col("x").count().alias("count"),
+            len(),
+        ]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q
+        .clone()
+        .with_eager_aggregation(true)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    // The fact table should be aggregated before it is joined.
+    assert!(lp_arena.iter(lp).any(|(_, ir)| {
+        matches!(ir, IR::GroupBy { input, .. } if matches!(lp_arena.get(*input), IR::DataFrameScan { .. }))
+    }));
+
+    let expected = q.clone().sort(["category"], Default::default()).collect()?;
+    let out = q
+        .with_eager_aggregation(true)
+        .sort(["category"], Default::default())
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+#[cfg(feature = "result_cache")]
+fn test_result_cache() -> PolarsResult<()> {
+    // Don't share (and pollute) the default cache directory. This test is the only one to use the
+    // result cache, so the directory is set before the cache is initialized.
+    let cache_dir =
+        std::env::temp_dir().join(format!("polars-test-result-cache-{}", std::process::id()));
+    unsafe { std::env::set_var("POLARS_RESULT_CACHE_DIR", &cache_dir) };
+
+    let q = scan_foods_parquet(false)
+        .group_by([col("category")])
+        .agg([col("calories").sum()])
+        .cache()
+        .sort(["category"], Default::default());
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(fingerprint_plan(lp, &lp_arena, &expr_arena).is_some());
+
+    // In-memory data cannot be identified across processes.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = get_df()
+        .lazy()
+        .cache()
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(fingerprint_plan(lp, &lp_arena, &expr_arena).is_none());
+
+    // Neither can the results of unseeded random expressions, but seeded ones are deterministic.
+    #[cfg(feature = "random")]
+    for (seed, cacheable) in [(None, false), (Some(0), true)] {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = scan_foods_parquet(false)
+            .select([col("calories").shuffle(seed)])
+            .optimize(&mut lp_arena, &mut expr_arena)?;
+        assert_eq!(
+            fingerprint_plan(lp, &lp_arena, &expr_arena).is_some(),
+            cacheable
+        );
+    }
+
+    let cache_entries = || -> PolarsResult<Vec<std::path::PathBuf>> {
+        Ok(std::fs::read_dir(&cache_dir)?
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "ipc"))
+            .collect())
+    };
+
+    let expected = q.clone().collect()?;
+    // The first query populates the cache.
+    #[cfg(feature = "new_streaming")]
+    let out = q
+        .clone()
+        .with_result_cache(true)
+        .collect_with_engine(Engine::Streaming)?;
+    #[cfg(not(feature = "new_streaming"))]
+    let out = q.clone().with_result_cache(true).collect()?;
+    assert!(out.equals_missing(&expected));
+    let entries = cache_entries()?;
+    assert_eq!(entries.len(), 1);
+
+    // Tamper with the stored result, so that a query reading from the cache can be told apart
+    // from one that recomputes its result.
+    let mut stored = IpcReader::new(std::fs::File::open(&entries[0])?)
+        .finish()?
+        .head(Some(1));
+    IpcWriter::new(std::fs::File::create(&entries[0])?).finish(&mut stored)?;
+    for _ in 0..2 {
+        let out = q.clone().with_result_cache(true).collect()?;
+        assert!(out.equals_missing(&stored));
+    }
+    assert_eq!(cache_entries()?, entries);
+
+    let _ = std::fs::remove_dir_all(cache_dir);
+    Ok(())
+}
+
+#[test]
+fn test_eager_aggregation_suffixed_key() -> PolarsResult<()> {
+    let fact = df![
+        "dim_id" => (0..1000i32).map(|i| i % 10).collect::<Vec<_>>(),
+        "x" => (0..1000i32).collect::<Vec<_>>(),
+        "v" => (0..1000i32).map(|i| i % 4).collect::<Vec<_>>(),
+    ]?;
+    let dim = df![
+        "dim_id" => (0..10i32).collect::<Vec<_>>(),
+        "v" => (0..10i32).map(|i| i % 3).collect::<Vec<_>>(),
+    ]?;
+
+    // `v_right` only exists because `v` of `dim` collides with `v` of `fact`.
+    let q = fact
+        .lazy()
+        .join(
+            dim.lazy(),
+            [col("dim_id")],
+            [col("dim_id")],
+            JoinType::Inner.into(),
+        )
+        .group_by([col("v_right")])
+        .agg([col("x").sum()]);
+
+    let expected = q.clone().sort(["v_right"], Default::default()).collect()?;
+    let out = q
+        .with_eager_aggregation(true)
+        .sort(["v_right"], Default::default())
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
@@ -47,6 +47,7 @@ pub(super) fn sum_array_numerical(ca: &ArrayChunked, inner_type: &DataType) -> S
                 UInt16 => dispatch_sum::<u16, i64>(values, width, arr.validity()),
                 UInt32 => dispatch_sum::<u32, u32>(values, width, arr.validity()),
                 UInt64 => dispatch_sum::<u64, u64>(values, width, arr.validity()),
+                UInt128 => dispatch_sum::<u128, u128>(values, width, arr.validity()),
                 Float32 => dispatch_sum::<f32, f32>(values, width, arr.validity()),
                 Float64 => dispatch_sum::<f64, f64>(values, width, arr.validity()),
                 _ => unimplemented!(),
@@ -105,6 +106,22 @@ pub(super) fn sum_with_nulls(ca: &ArrayChunked, inner_dtype: &DataType) -> Polar
                     .collect();
                 out.into_series()
             },
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => {
+                let out: UInt128Chunked = ca
+                    .amortized_iter()
+                    .map(|s| s.and_then(|s| s.as_ref().sum().ok()))
+                    .collect();
+                out.into_series()
+            },
+            #[cfg(feature = "dtype-f16")]
+            Float16 => {
+                let out: Float16Chunked = ca
+                    .amortized_iter()
+                    .map(|s| s.and_then(|s| s.as_ref().sum().ok()))
+                    .collect();
+                out.into_series()
+            },
             Float32 => {
                 let out: Float32Chunked = ca
                     .amortized_iter()
@@ -172,6 +172,14 @@ impl TakeChunked for Series {
                 ca.take_chunked_unchecked(by, sorted, avoid_sharing)
                     .into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                let ca = self.map().unwrap();
+                let out = ca
+                    .physical()
+                    .take_chunked_unchecked(by, sorted, avoid_sharing);
+                MapChunked::new_logical(out, self.dtype().clone()).into_series()
+            },
             #[cfg(feature = "dtype-struct")]
             Struct(_) => {
                 let ca = self.struct_().unwrap();
@@ -218,6 +226,14 @@ impl TakeChunked for Series {
                     .into_time()
                     .into_series()
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ca = self.interval().unwrap();
+                ca.physical()
+                    .take_chunked_unchecked(by, sorted, avoid_sharing)
+                    .into_interval()
+                    .into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 with_match_categorical_physical_type!(self.dtype().cat_physical().unwrap(), |$C| {
@@ -272,6 +288,12 @@ impl TakeChunked for Series {
                 ca.take_opt_chunked_unchecked(by, avoid_sharing)
                     .into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                let ca = self.map().unwrap();
+                let out = ca.physical().take_opt_chunked_unchecked(by, avoid_sharing);
+                MapChunked::new_logical(out, self.dtype().clone()).into_series()
+            },
             #[cfg(feature = "dtype-struct")]
             Struct(_) => {
                 let ca = self.struct_().unwrap();
@@ -318,6 +340,14 @@ impl TakeChunked for Series {
                     .into_time()
                     .into_series()
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ca = self.interval().unwrap();
+                ca.physical()
+                    .take_opt_chunked_unchecked(by, avoid_sharing)
+                    .into_interval()
+                    .into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 with_match_categorical_physical_type!(self.dtype().cat_physical().unwrap(), |$C| {
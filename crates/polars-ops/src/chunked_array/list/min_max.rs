@@ -60,6 +60,7 @@ fn min_list_numerical(ca: &ListChunked, inner_type: &DataType) -> Series {
                 UInt16 => dispatch_min::<u16>(values, offsets, arr.validity()),
                 UInt32 => dispatch_min::<u32>(values, offsets, arr.validity()),
                 UInt64 => dispatch_min::<u64>(values, offsets, arr.validity()),
+                UInt128 => dispatch_min::<u128>(values, offsets, arr.validity()),
                 Float32 => dispatch_min::<f32>(values, offsets, arr.validity()),
                 Float64 => dispatch_min::<f64>(values, offsets, arr.validity()),
                 _ => unimplemented!(),
@@ -171,6 +172,7 @@ fn max_list_numerical(ca: &ListChunked, inner_type: &DataType) -> Series {
                 UInt16 => dispatch_max::<u16>(values, offsets, arr.validity()),
                 UInt32 => dispatch_max::<u32>(values, offsets, arr.validity()),
                 UInt64 => dispatch_max::<u64>(values, offsets, arr.validity()),
+                UInt128 => dispatch_max::<u128>(values, offsets, arr.validity()),
                 Float32 => dispatch_max::<f32>(values, offsets, arr.validity()),
                 Float64 => dispatch_max::<f64>(values, offsets, arr.validity()),
                 _ => unimplemented!(),
@@ -56,6 +56,7 @@ pub(super) fn sum_list_numerical(ca: &ListChunked, inner_type: &DataType) -> Ser
                 UInt16 => dispatch_sum::<u16, i64>(values, offsets, arr.validity()),
                 UInt32 => dispatch_sum::<u32, u32>(values, offsets, arr.validity()),
                 UInt64 => dispatch_sum::<u64, u64>(values, offsets, arr.validity()),
+                UInt128 => dispatch_sum::<u128, u128>(values, offsets, arr.validity()),
                 Float32 => dispatch_sum::<f32, f32>(values, offsets, arr.validity()),
                 Float64 => dispatch_sum::<f64, f64>(values, offsets, arr.validity()),
                 _ => unimplemented!(),
@@ -167,6 +168,7 @@ pub(super) fn mean_list_numerical(ca: &ListChunked, inner_type: &DataType) -> Se
                 UInt16 => dispatch_mean::<u16, f64>(values, offsets, arr.validity()),
                 UInt32 => dispatch_mean::<u32, f64>(values, offsets, arr.validity()),
                 UInt64 => dispatch_mean::<u64, f64>(values, offsets, arr.validity()),
+                UInt128 => dispatch_mean::<u128, f64>(values, offsets, arr.validity()),
                 Float32 => dispatch_mean::<f32, f32>(values, offsets, arr.validity()),
                 Float64 => dispatch_mean::<f64, f64>(values, offsets, arr.validity()),
                 _ => unimplemented!(),
@@ -0,0 +1,186 @@
+use arrow::array::Array;
+use arrow::bitmap::BitmapBuilder;
+use arrow::legacy::prelude::LargeListArray;
+use arrow::offset::Offsets;
+
+use super::*;
+
+/// Find the position of `key` in the flattened entries of every map.
+///
+/// `entries` must consist of a single chunk. The positions index into its inner values, which
+/// may start before the first entry if `entries` is sliced.
+fn key_positions(entries: &ListChunked, key: &Series) -> PolarsResult<Vec<Option<IdxSize>>> {
+    let arr = entries.downcast_as_array();
+    let offsets = arr.offsets();
+    let (first, last) = (*offsets.first() as usize, *offsets.last() as usize);
+    let entry_keys = entries
+        .get_inner()
+        .struct_()?
+        .fields_as_series()
+        .swap_remove(0)
+        .slice(first as i64, last - first);
+
+    let eq = if key.len() == 1 {
+        entry_keys.equal(key)?
+    } else {
+        let idx = offsets
+            .lengths()
+            .enumerate()
+            .flat_map(|(i, len)| std::iter::repeat_n(i as IdxSize, len))
+            .collect::<Vec<_>>();
+        entry_keys.equal(&key.take_slice(&idx)?)?
+    };
+    let eq = eq.rechunk();
+    let eq = eq.downcast_as_array();
+
+    Ok(offsets
+        .offset_and_length_iter()
+        .enumerate()
+        .map(|(i, (start, len))| {
+            if !arr.is_valid(i) {
+                return None;
+            }
+            (start..start + len)
+                .find(|&j| eq.is_valid(j - first) && eq.value(j - first))
+                .map(|j| j as IdxSize)
+        })
+        .collect())
+}
+
+fn prepare_key(ca: &MapChunked, key: &Series, op: &str) -> PolarsResult<Series> {
+    polars_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        length_mismatch = op,
+        ca.len(),
+        key.len()
+    );
+    key.strict_cast(ca.key_dtype())
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the value stored under `key` in every map, or null if the key is missing.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let key = prepare_key(ca, key, "map.get")?;
+
+        let entries = ca.physical().rechunk();
+        let positions = key_positions(&entries, &key)?;
+        let positions = IdxCa::from_iter_options(PlSmallStr::EMPTY, positions.into_iter());
+
+        let entry_values = entries
+            .get_inner()
+            .struct_()?
+            .fields_as_series()
+            .swap_remove(1);
+        Ok(entry_values.take(&positions)?.with_name(ca.name().clone()))
+    }
+
+    /// Check if every map contains `key`.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let key = prepare_key(ca, key, "map.contains_key")?;
+
+        let entries = ca.physical().rechunk();
+        let positions = key_positions(&entries, &key)?;
+        let arr = entries.downcast_as_array();
+
+        Ok(BooleanChunked::from_iter_options(
+            ca.name().clone(),
+            positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| arr.is_valid(i).then_some(pos.is_some())),
+        ))
+    }
+
+    /// Get the keys of every map as a list.
+    fn map_keys(&self) -> ListChunked {
+        self.as_map().keys()
+    }
+
+    /// Get the values of every map as a list.
+    fn map_values(&self) -> ListChunked {
+        self.as_map().values()
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+/// Combine a list of keys and a list of values into a map.
+///
+/// A row is null if either its keys or its values are null. Valid rows must have as many
+/// keys as values.
+pub fn map_from_lists(keys: &ListChunked, values: &ListChunked) -> PolarsResult<MapChunked> {
+    polars_ensure!(
+        keys.len() == values.len(),
+        length_mismatch = "map.from_lists",
+        keys.len(),
+        values.len()
+    );
+
+    let keys = keys.rechunk();
+    let values = values.rechunk();
+    let key_arr = keys.downcast_as_array();
+    let value_arr = values.downcast_as_array();
+
+    let mut key_idx = Vec::new();
+    let mut value_idx = Vec::new();
+    let mut offsets = Offsets::<i64>::with_capacity(keys.len());
+    let mut validity = BitmapBuilder::with_capacity(keys.len());
+    for (i, ((key_start, key_len), (value_start, value_len))) in key_arr
+        .offsets()
+        .offset_and_length_iter()
+        .zip(value_arr.offsets().offset_and_length_iter())
+        .enumerate()
+    {
+        let is_valid = key_arr.is_valid(i) && value_arr.is_valid(i);
+        validity.push(is_valid);
+        if !is_valid {
+            offsets.try_push(0)?;
+            continue;
+        }
+
+        polars_ensure!(
+            key_len == value_len,
+            ShapeMismatch: "map keys and values should have the same length, got {} keys and {} values at index {}",
+            key_len, value_len, i
+        );
+        key_idx.extend((key_start..key_start + key_len).map(|j| j as IdxSize));
+        value_idx.extend((value_start..value_start + value_len).map(|j| j as IdxSize));
+        offsets.try_push(key_len)?;
+    }
+
+    let entry_keys = keys
+        .get_inner()
+        .take_slice(&key_idx)?
+        .with_name(PlSmallStr::from_static("key"));
+    let entry_values = values
+        .get_inner()
+        .take_slice(&value_idx)?
+        .with_name(PlSmallStr::from_static("value"));
+    let entries = StructChunked::from_series(
+        PlSmallStr::EMPTY,
+        entry_keys.len(),
+        [entry_keys, entry_values].iter(),
+    )?
+    .into_series()
+    .rechunk();
+
+    let entries_arr = entries.chunks()[0].clone();
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(entries_arr.dtype().clone()),
+        offsets.into(),
+        entries_arr,
+        validity.into_opt_validity(),
+    );
+
+    // SAFETY: the list dtype is derived from the entries dtype.
+    let ca = unsafe {
+        ListChunked::from_chunks_and_dtype_unchecked(
+            keys.name().clone(),
+            vec![Box::new(arr)],
+            DataType::List(Box::new(entries.dtype().clone())),
+        )
+    };
+    ca.into_map()
+}
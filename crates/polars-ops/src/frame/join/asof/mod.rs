@@ -294,6 +294,11 @@ pub trait AsofJoin: IntoDf {
                 let ca = left_key.i128().unwrap();
                 join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                let ca = left_key.u128().unwrap();
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
+            },
             DataType::UInt64 => {
                 let ca = left_key.u64().unwrap();
                 join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
@@ -74,6 +74,10 @@ pub(super) fn par_sorted_merge_left(
         DataType::Int128 => {
             par_sorted_merge_left_impl(s_left.i128().unwrap(), s_right.i128().unwrap())
         },
+        #[cfg(feature = "dtype-u128")]
+        DataType::UInt128 => {
+            par_sorted_merge_left_impl(s_left.u128().unwrap(), s_right.u128().unwrap())
+        },
         DataType::Float32 => {
             par_sorted_merge_left_impl(s_left.f32().unwrap(), s_right.f32().unwrap())
         },
@@ -150,6 +154,10 @@ pub(super) fn par_sorted_merge_inner_no_nulls(
         DataType::Int128 => {
             par_sorted_merge_inner_impl(s_left.i128().unwrap(), s_right.i128().unwrap())
         },
+        #[cfg(feature = "dtype-u128")]
+        DataType::UInt128 => {
+            par_sorted_merge_inner_impl(s_left.u128().unwrap(), s_right.u128().unwrap())
+        },
         DataType::Float32 => {
             par_sorted_merge_inner_impl(s_left.f32().unwrap(), s_right.f32().unwrap())
         },
@@ -198,6 +198,10 @@ pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
         UInt64 => cum_prod_numeric(s.u64()?, reverse).into_series(),
         #[cfg(feature = "dtype-i128")]
         Int128 => cum_prod_numeric(s.i128()?, reverse).into_series(),
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => cum_prod_numeric(s.u128()?, reverse).into_series(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => cum_prod_numeric(s.f16()?, reverse).into_series(),
         Float32 => cum_prod_numeric(s.f32()?, reverse).into_series(),
         Float64 => cum_prod_numeric(s.f64()?, reverse).into_series(),
         dt => polars_bail!(opq = cum_prod, dt),
@@ -226,6 +230,10 @@ pub fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
         UInt64 => cum_sum_numeric(s.u64()?, reverse).into_series(),
         #[cfg(feature = "dtype-i128")]
         Int128 => cum_sum_numeric(s.i128()?, reverse).into_series(),
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => cum_sum_numeric(s.u128()?, reverse).into_series(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => cum_sum_numeric(s.f16()?, reverse).into_series(),
         Float32 => cum_sum_numeric(s.f32()?, reverse).into_series(),
         Float64 => cum_sum_numeric(s.f64()?, reverse).into_series(),
         #[cfg(feature = "dtype-decimal")]
@@ -140,11 +140,13 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         | DT::UInt16
         | DT::UInt32
         | DT::UInt64
+        | DT::UInt128
         | DT::Int8
         | DT::Int16
         | DT::Int32
         | DT::Int64
         | DT::Int128
+        | DT::Float16
         | DT::Float32
         | DT::Float64 => unreachable!("primitive numeric"),
 
@@ -153,6 +155,10 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         DT::Decimal(..) => unreachable!(),
         #[cfg(feature = "dtype-categorical")]
         DT::Categorical(..) | DT::Enum(..) => unreachable!(),
+        #[cfg(feature = "dtype-map")]
+        DT::Map(..) => unreachable!(),
+        #[cfg(feature = "dtype-interval")]
+        DT::Interval => unreachable!(),
         DT::Date | DT::Datetime(..) | DT::Duration(..) | DT::Time => unreachable!(),
 
         DT::Object(_) | DT::Unknown(_) => polars_bail!(op = "index_of", series.dtype()),
@@ -27,6 +27,8 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => s.interval().unwrap().negate().into_series(),
         dt => polars_bail!(opq = neg, dt),
     };
     Ok(out)
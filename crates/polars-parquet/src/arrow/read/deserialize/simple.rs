@@ -4,7 +4,7 @@ use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 
@@ -182,6 +182,33 @@ pub fn page_iter_to_array(
                 ptm,
             )
         },
+        (PhysicalType::FixedLenByteArray(12), Interval(IntervalUnit::MonthDayNano)) => {
+            // @TODO: Make a separate decoder for this
+
+            let n = 12;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(n)
+                .map(super::super::convert_months_days_ns)
+                .collect::<Vec<_>>();
+            let validity = array.validity().cloned();
+
+            (
+                nested,
+                PrimitiveArray::<months_days_ns>::try_new(dtype.clone(), values.into(), validity)?
+                    .to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::FixedLenByteArray(16), Int128) => {
             let n = 16;
             let (nested, array, ptm) = PageDecoder::new(
@@ -204,6 +231,28 @@ pub fn page_iter_to_array(
                 ptm,
             )
         },
+        (PhysicalType::FixedLenByteArray(16), UInt128) => {
+            let n = 16;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let (_, values, validity) = array.into_inner();
+            let values = values
+                .try_transmute()
+                .expect("this should work since the parquet decoder has alignment constraints");
+
+            (
+                nested,
+                PrimitiveArray::<u128>::try_new(dtype.clone(), values, validity)?.to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::Int32, Decimal(_, _)) => PageDecoder::new(
             &field.name,
             pages,
@@ -362,7 +411,7 @@ pub fn page_iter_to_array(
         .collect_boxed(filter)?,
 
         // Float16
-        (PhysicalType::FixedLenByteArray(2), Float32) => {
+        (PhysicalType::FixedLenByteArray(2), Float16 | Float32) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
 
             let (nested, mut fsb_array, ptm) = PageDecoder::new(
@@ -378,20 +427,21 @@ pub fn page_iter_to_array(
             let values = fsb_array.values().as_slice();
             assert_eq!(values.len() % 2, 0);
             let values = values.chunks_exact(2);
-            let values = values
-                .map(|v| {
-                    // SAFETY: We know that `v` is always of size two.
-                    let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
-                    let v = arrow::types::f16::from_le_bytes(le_bytes);
-                    v.to_f32()
-                })
-                .collect();
+            let values = values.map(|v| {
+                // SAFETY: We know that `v` is always of size two.
+                let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
+                arrow::types::f16::from_le_bytes(le_bytes)
+            });
+
+            let array = if matches!(dtype, Float16) {
+                PrimitiveArray::<arrow::types::f16>::new(dtype, values.collect(), validity)
+                    .to_boxed()
+            } else {
+                let values = values.map(|v| v.to_f32()).collect();
+                PrimitiveArray::<f32>::new(dtype, values, validity).to_boxed()
+            };
 
-            (
-                nested,
-                PrimitiveArray::<f32>::new(dtype, values, validity).to_boxed(),
-                ptm,
-            )
+            (nested, array, ptm)
         },
 
         (PhysicalType::Float, Float32) => PageDecoder::new(
@@ -176,11 +176,12 @@ fn from_fixed_len_byte_array(
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
             ArrowDataType::Decimal(precision, scale)
         },
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float16,
         (None, Some(PrimitiveConvertedType::Interval)) => {
-            // There is currently no reliable way of determining which IntervalUnit
-            // to return. Thus without the original Arrow schema, the results
-            // would be incorrect if all 12 bytes of the interval are populated
-            ArrowDataType::Interval(IntervalUnit::DayTime)
+            // The parquet interval holds months, days and milliseconds, which fit losslessly
+            // into a `MonthDayNano` interval. The original Arrow schema, if present, can
+            // still narrow this down to a different `IntervalUnit`.
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano)
         },
         _ => ArrowDataType::FixedSizeBinary(length),
     }
@@ -244,9 +245,9 @@ fn non_repeated_group(
     match (logical_type, converted_type) {
         (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
-        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options),
+        (Some(GroupLogicalType::Map), _) => to_map(fields, parent_name, options),
         (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
-            to_map(fields, options)
+            to_map(fields, parent_name, options)
         },
         _ => to_struct(fields, options),
     }
@@ -266,11 +267,31 @@ fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option
     }
 }
 
-/// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
-/// Returns [`None`] if all its fields are empty
-fn to_map(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
-    let inner = to_field(&fields[0], options)?;
-    Some(ArrowDataType::Map(Box::new(inner), false))
+/// Converts a parquet map to an arrow [`ArrowDataType::Map`].
+///
+/// Falls back to a list if the repeated group does not hold a key and a value.
+fn to_map(
+    fields: &[ParquetType],
+    parent_name: &str,
+    options: &SchemaInferenceOptions,
+) -> Option<ArrowDataType> {
+    let ParquetType::GroupType {
+        field_info,
+        fields: key_value,
+        ..
+    } = &fields[0]
+    else {
+        return to_list(fields, parent_name, options);
+    };
+    if key_value.len() != 2 {
+        return to_list(fields, parent_name, options);
+    }
+
+    let entries = to_struct(key_value, options)?;
+    Some(ArrowDataType::Map(
+        Box::new(Field::new(field_info.name.clone(), entries, false)),
+        false,
+    ))
 }
 
 /// Entry point for converting parquet group type.
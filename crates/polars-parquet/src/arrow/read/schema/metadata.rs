@@ -69,7 +69,6 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
                 convert_field(field);
             }
         },
-        Float16 => dtype = Float32,
         Binary | LargeBinary => dtype = BinaryView,
         Utf8 | LargeUtf8 => dtype = Utf8View,
         Dictionary(_, ref mut dtype, _) => {
@@ -79,13 +78,7 @@ fn convert_dtype(mut dtype: ArrowDataType) -> ArrowDataType {
         Extension(ref mut ext) => {
             ext.inner = convert_dtype(std::mem::take(&mut ext.inner));
         },
-        Map(mut field, _ordered) => {
-            // Polars doesn't support Map.
-            // A map is physically a `List<Struct<K, V>>`
-            // So we read as list.
-            convert_field(field.as_mut());
-            dtype = LargeList(field);
-        },
+        Map(ref mut field, _ordered) => convert_field(field.as_mut()),
         _ => {},
     }
 
@@ -5,7 +5,7 @@ use arrow::array::{
     PrimitiveArray, Utf8ViewArray,
 };
 use arrow::datatypes::{ArrowDataType, Field, IntegerType, IntervalUnit, TimeUnit};
-use arrow::types::{NativeType, days_ms, f16, i256};
+use arrow::types::{NativeType, days_ms, f16, i256, months_days_ns};
 use ethnum::I256;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
@@ -16,7 +16,7 @@ use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
-    convert_year_month, int96_to_i64_ns,
+    convert_months_days_ns, convert_year_month, int96_to_i64_ns,
 };
 
 /// Parquet statistics for a nesting level
@@ -197,6 +197,11 @@ impl ColumnStatistics {
                 @prim Vec<u8>,
                 |x| convert_days_ms(&x)
             ),
+            (D::Interval(IntervalUnit::MonthDayNano), _) => rmap!(
+                expect_binary,
+                @prim Vec<u8>,
+                |x| convert_months_days_ns(&x)
+            ),
 
             (D::UInt8, _) => rmap!(expect_int32, @prim i32 as u8),
             (D::UInt16, _) => rmap!(expect_int32, @prim i32 as u16),
@@ -217,7 +222,10 @@ impl ColumnStatistics {
                 })
             },
 
-            // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+            (D::Float16, PPT::FixedLenByteArray(2)) => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+            },
+            // Float16 read into a Float32 column.
             (_, PPT::FixedLenByteArray(2))
                 if matches!(
                     self.logical_type.as_ref(),
@@ -440,6 +448,12 @@ pub fn deserialize_all(
                     @prim Vec<u8>,
                     |x| convert_days_ms(&x)
                 ),
+                (D::Interval(IntervalUnit::MonthDayNano), _) => rmap!(
+                    expect_binary,
+                    MutablePrimitiveArray::<months_days_ns>,
+                    @prim Vec<u8>,
+                    |x| convert_months_days_ns(&x)
+                ),
 
                 (D::UInt8, _) => rmap!(expect_int32, MutablePrimitiveArray::<u8>, @prim i32 as u8),
                 (D::UInt16, _) => {
@@ -468,7 +482,10 @@ pub fn deserialize_all(
                     })
                 },
 
-                // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+                (D::Float16, PPT::FixedLenByteArray(2)) => {
+                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f16>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+                },
+                // Float16 read into a Float32 column.
                 (_, PPT::FixedLenByteArray(2))
                     if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
                 {
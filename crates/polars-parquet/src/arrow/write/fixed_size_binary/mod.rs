@@ -2,7 +2,7 @@ mod basic;
 mod nested;
 
 use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
-use arrow::types::i256;
+use arrow::types::{NativeType, f16, i256};
 pub use basic::array_to_page;
 pub use nested::array_to_page as nested_array_to_page;
 
@@ -60,6 +60,36 @@ pub(super) fn build_statistics(
     }
 }
 
+pub(super) fn build_statistics_float16(
+    array: &PrimitiveArray<f16>,
+    primitive_type: PrimitiveType,
+    options: &StatisticsOptions,
+) -> FixedLenStatistics {
+    // NaNs are not ordered and must not be written as min or max.
+    let non_nan_values = || array.non_null_values_iter().filter(|x| !x.is_nan());
+    FixedLenStatistics {
+        primitive_type,
+        null_count: options.null_count.then_some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: options
+            .max_value
+            .then(|| {
+                non_nan_values()
+                    .max_by(|x, y| x.to_f32().total_cmp(&y.to_f32()))
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+        min_value: options
+            .min_value
+            .then(|| {
+                non_nan_values()
+                    .min_by(|x, y| x.to_f32().total_cmp(&y.to_f32()))
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+    }
+}
+
 pub(super) fn build_statistics_decimal(
     array: &PrimitiveArray<i128>,
     primitive_type: PrimitiveType,
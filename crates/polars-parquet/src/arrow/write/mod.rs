@@ -27,7 +27,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, f16, i256, months_days_ns};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -531,6 +531,24 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
+        ArrowDataType::Float16 => {
+            let array: &PrimitiveArray<f16> = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_float16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                array.values().clone().try_transmute().unwrap(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
@@ -637,6 +655,43 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(12 * array.len());
+            for (i, x) in array.values().iter().enumerate() {
+                if !array.is_valid(i) {
+                    values.extend_from_slice(&[0; 12]);
+                    continue;
+                }
+                // Parquet intervals are unsigned and only have millisecond precision.
+                let months = u32::try_from(x.months()).ok();
+                let days = u32::try_from(x.days()).ok();
+                let millis = (x.ns() % 1_000_000 == 0)
+                    .then(|| u32::try_from(x.ns() / 1_000_000).ok())
+                    .flatten();
+                let (Some(months), Some(days), Some(millis)) = (months, days, millis) else {
+                    polars_bail!(
+                        InvalidOperation:
+                        "interval of {} months, {} days and {}ns cannot be written to parquet, which only supports non-negative intervals with millisecond precision",
+                        x.months(), x.days(), x.ns()
+                    );
+                };
+                values.extend_from_slice(&months.to_le_bytes());
+                values.extend_from_slice(&days.to_le_bytes());
+                values.extend_from_slice(&millis.to_le_bytes());
+            }
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(12),
+                values.into(),
+                array.validity().cloned(),
+            );
+            // The sort order of parquet intervals is undefined, so we don't write min/max
+            // statistics.
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -831,6 +886,17 @@ pub fn array_to_page_simple(
             );
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::UInt128 => {
+            // The raw bytes carry no parquet logical type, so min/max statistics could not be
+            // interpreted by readers and are not written.
+            let array: &PrimitiveArray<u128> = array.as_any().downcast_ref().unwrap();
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(16),
+                array.values().clone().try_transmute().unwrap(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         other => polars_bail!(nyi = "Writing parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
@@ -910,6 +976,24 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<i64, i64>(array, options, type_, nested)
         },
+        Float16 => {
+            let array: &PrimitiveArray<f16> = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_float16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                array.values().clone().try_transmute().unwrap(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
@@ -1090,6 +1174,15 @@ fn array_to_page_nested(
             );
             fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
         },
+        UInt128 => {
+            let array: &PrimitiveArray<u128> = array.as_any().downcast_ref().unwrap();
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(16),
+                array.values().clone().try_transmute().unwrap(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, None)
+        },
         other => polars_bail!(nyi = "Writing nested parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
@@ -239,9 +239,9 @@ fn to_nested_recursive(
         },
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let type_ = if let ParquetType::GroupType { fields, .. } = type_ {
+            let key_value_fields = if let ParquetType::GroupType { fields, .. } = type_ {
                 if let ParquetType::GroupType { fields, .. } = &fields[0] {
-                    &fields[0]
+                    fields
                 } else {
                     polars_bail!(InvalidOperation:
                         "Parquet type must be a group for a map array",
@@ -258,7 +258,30 @@ fn to_nested_recursive(
                 array.validity().cloned(),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+
+            match key_value_fields.as_slice() {
+                // The entries are wrapped in their own group.
+                [type_ @ ParquetType::GroupType { .. }] => {
+                    to_nested_recursive(array.field().as_ref(), type_, nested, parents)?
+                },
+                // The repeated `key_value` group holds the key and value directly.
+                _ => {
+                    let entries = array
+                        .field()
+                        .as_any()
+                        .downcast_ref::<StructArray>()
+                        .unwrap();
+                    parents.push(Nested::Struct(StructNested {
+                        is_optional: false,
+                        validity: None,
+                        length: entries.len(),
+                    }));
+
+                    for (type_, array) in key_value_fields.iter().zip(entries.values()) {
+                        to_nested_recursive(array.as_ref(), type_, nested, parents.clone())?;
+                    }
+                },
+            }
         },
         _ => {
             parents.push(Nested::Primitive(PrimitiveNested {
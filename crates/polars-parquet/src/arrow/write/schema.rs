@@ -32,6 +32,7 @@ fn convert_dtype(dtype: ArrowDataType) -> ArrowDataType {
     use ArrowDataType as D;
     match dtype {
         D::LargeList(field) => D::LargeList(Box::new(convert_field(*field))),
+        D::Map(field, sorted) => D::Map(Box::new(convert_field(*field)), sorted),
         D::Struct(mut fields) => {
             for field in &mut fields {
                 *field = convert_field(std::mem::take(field))
@@ -212,6 +213,11 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
         // no natural representation in parquet; leave it as is.
         // arrow consumers MAY use the arrow schema in the metadata to parse them.
         ArrowDataType::Date64 => (PhysicalType::Int64, None, None),
+        ArrowDataType::Float16 => (
+            PhysicalType::FixedLenByteArray(2),
+            None,
+            Some(PrimitiveLogicalType::Float16),
+        ),
         ArrowDataType::Float32 => (PhysicalType::Float, None, None),
         ArrowDataType::Float64 => (PhysicalType::Double, None, None),
         ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView => {
@@ -383,7 +389,9 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
             Some(PrimitiveConvertedType::Interval),
             None,
         ),
-        ArrowDataType::Int128 => (PhysicalType::FixedLenByteArray(16), None, None),
+        ArrowDataType::Int128 | ArrowDataType::UInt128 => {
+            (PhysicalType::FixedLenByteArray(16), None, None)
+        },
         ArrowDataType::List(f)
         | ArrowDataType::FixedSizeList(f, _)
         | ArrowDataType::LargeList(f) => {
@@ -410,6 +418,44 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 field_id,
             ));
         },
+        ArrowDataType::Map(f, _) => {
+            let ArrowDataType::Struct(entries) = f.dtype() else {
+                polars_bail!(InvalidOperation: "map entries must be a struct")
+            };
+
+            let ChildWriteOptions::ListLike(map_write_options) = &options.children else {
+                unreachable!();
+            };
+            let ChildWriteOptions::Struct(entries_write_options) =
+                &map_write_options.child.children
+            else {
+                unreachable!();
+            };
+
+            assert_eq!(entries.len(), entries_write_options.children.len());
+
+            // The repeated `key_value` group holds the key and value directly.
+            let fields = entries
+                .iter()
+                .zip(entries_write_options.children.as_slice())
+                .map(|(f, c)| to_parquet_type(f, c))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            return Ok(ParquetType::from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(GroupLogicalType::Map),
+                vec![ParquetType::from_group(
+                    PlSmallStr::from_static("key_value"),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    fields,
+                    None,
+                )],
+                field_id,
+            ));
+        },
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     };
 
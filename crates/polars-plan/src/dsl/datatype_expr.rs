@@ -129,9 +129,7 @@ fn into_datatype_impl(
                     DT::UInt16 | DT::Int16 => DT::UInt16,
                     DT::UInt32 | DT::Int32 => DT::UInt32,
                     DT::UInt64 | DT::Int64 => DT::UInt64,
-                    DT::Int128 => {
-                        polars_bail!(InvalidOperation: "`int128` has no unsigned equivalent")
-                    },
+                    DT::UInt128 | DT::Int128 => DT::UInt128,
                     _ => unreachable!(),
                 },
                 IntDataTypeExpr::ToSigned => {
@@ -141,7 +139,7 @@ fn into_datatype_impl(
                         DT::UInt16 | DT::Int16 => DT::Int16,
                         DT::UInt32 | DT::Int32 => DT::Int32,
                         DT::UInt64 | DT::Int64 => DT::Int64,
-                        DT::Int128 => DT::Int128,
+                        DT::UInt128 | DT::Int128 => DT::Int128,
                         _ => unreachable!(),
                     }
                 },
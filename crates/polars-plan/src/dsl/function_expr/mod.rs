@@ -13,6 +13,8 @@ mod correlation;
 #[cfg(feature = "temporal")]
 mod datetime;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod pow;
 #[cfg(feature = "random")]
 mod random;
@@ -39,6 +41,8 @@ pub use correlation::CorrelationMethod;
 pub use list::ListFunction;
 #[cfg(feature = "list_to_struct")]
 pub use list::ListToStruct;
+#[cfg(feature = "dtype-map")]
+pub use map::MapFunction;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
@@ -82,6 +86,8 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -377,6 +383,8 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -665,6 +673,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -10,3 +10,9 @@ pub fn as_struct(exprs: Vec<Expr>) -> Expr {
     );
     Expr::n_ary(FunctionExpr::AsStruct, exprs)
 }
+
+/// Combine a list column of keys and a list column of values into a map column.
+#[cfg(feature = "dtype-map")]
+pub fn map_from_lists(keys: Expr, values: Expr) -> Expr {
+    keys.map_binary(FunctionExpr::MapExpr(MapFunction::FromLists), values)
+}
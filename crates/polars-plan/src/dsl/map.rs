@@ -0,0 +1,31 @@
+use polars_core::prelude::*;
+
+use crate::dsl::function_expr::MapFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub Expr);
+
+impl MapNameSpace {
+    /// Get the value stored under `key` in every map, or null if the key is missing.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::Get), key)
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+
+    /// Check if every map contains `key`.
+    pub fn contains_key(self, key: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::ContainsKey), key)
+    }
+}
@@ -39,6 +39,12 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 16;
+        /// Reorder chains of inner joins based on the estimated number of rows of their inputs.
+        const JOIN_REORDER = 1 << 17;
+        /// Push decomposable aggregations below the joins they aggregate.
+        const EAGER_AGGREGATION = 1 << 18;
+        /// Persist the results of cached subplans across queries and processes.
+        const RESULT_CACHE = 1 << 19;
     }
 }
 
@@ -59,6 +65,18 @@ impl OptFlags {
         self.contains(OptFlags::COLLAPSE_JOINS)
     }
 
+    pub fn join_reorder(&self) -> bool {
+        self.contains(OptFlags::JOIN_REORDER)
+    }
+
+    pub fn eager_aggregation(&self) -> bool {
+        self.contains(OptFlags::EAGER_AGGREGATION)
+    }
+
+    pub fn result_cache(&self) -> bool {
+        self.contains(OptFlags::RESULT_CACHE)
+    }
+
     pub fn predicate_pushdown(&self) -> bool {
         self.contains(OptFlags::PREDICATE_PUSHDOWN)
     }
@@ -82,7 +100,12 @@ impl OptFlags {
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::JOIN_REORDER
+            & !Self::EAGER_AGGREGATION
+            & !Self::RESULT_CACHE
     }
 }
 
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+#[cfg(feature = "dtype-decimal")]
+use polars_core::prelude::DataType;
 use polars_core::schema::Schema;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
@@ -27,11 +29,31 @@ pub fn constant_evaluate<'a>(
 pub fn into_column<'a>(
     e: Node,
     expr_arena: &'a Arena<AExpr>,
-    _schema: &Schema,
-    _depth: usize,
+    schema: &Schema,
+    depth: usize,
 ) -> Option<&'a PlSmallStr> {
     match expr_arena.get(e) {
         AExpr::Column(c) => Some(c),
+        // Type coercion casts decimal columns to a supertype of unknown precision when compared
+        // against decimal literals. If the scale stays the same the physical values are
+        // unchanged, so the column statistics still apply.
+        #[cfg(feature = "dtype-decimal")]
+        AExpr::Cast {
+            expr,
+            dtype: DataType::Decimal(precision_to, Some(scale_to)),
+            ..
+        } => {
+            let c = into_column(*expr, expr_arena, schema, depth + 1)?;
+            let DataType::Decimal(precision_from, Some(scale_from)) = schema.get(c)? else {
+                return None;
+            };
+            let widens = match (precision_from, precision_to) {
+                (_, None) => true,
+                (Some(from), Some(to)) => from <= to,
+                (None, Some(_)) => false,
+            };
+            (scale_from == scale_to && widens).then_some(c)
+        },
         _ => None,
     }
 }
@@ -0,0 +1,110 @@
+use polars_ops::chunked_array::map::*;
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRMapFunction {
+    Get,
+    Keys,
+    Values,
+    ContainsKey,
+    FromLists,
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(_, value) => Ok(value.as_ref().clone()),
+                _ => polars_bail!(op = "map.get", got = dt, expected = "Map"),
+            }),
+            Keys => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(key, _) => Ok(DataType::List(key.clone())),
+                _ => polars_bail!(op = "map.keys", got = dt, expected = "Map"),
+            }),
+            Values => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(_, value) => Ok(DataType::List(value.clone())),
+                _ => polars_bail!(op = "map.values", got = dt, expected = "Map"),
+            }),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            FromLists => mapper.try_map_dtypes(|dtypes| match dtypes {
+                [DataType::List(key), DataType::List(value)] => {
+                    Ok(DataType::Map(key.clone(), value.clone()))
+                },
+                _ => polars_bail!(
+                    InvalidOperation: "`map.from_lists` expects a list of keys and a list of values, got {:?}",
+                    dtypes
+                ),
+            }),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        let name = match self {
+            Get => "get",
+            Keys => "keys",
+            Values => "values",
+            ContainsKey => "contains_key",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<IRMapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRMapFunction) -> Self {
+        use IRMapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            Keys => map!(keys),
+            Values => map!(values),
+            ContainsKey => map_as_slice!(contains_key),
+            FromLists => map_as_slice!(from_lists),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_get(key).map(Column::from)
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    Ok(s.map()?.map_keys().into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    Ok(s.map()?.map_values().into_column())
+}
+
+pub(super) fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    Ok(ca.map_contains_key(key)?.into_column())
+}
+
+pub(super) fn from_lists(s: &[Column]) -> PolarsResult<Column> {
+    let keys = s[0].list()?;
+    let values = s[1].list()?;
+    Ok(map_from_lists(keys, values)?
+        .into_series()
+        .with_name(s[0].name().clone())
+        .into_column())
+}
+
+impl From<IRMapFunction> for IRFunctionExpr {
+    fn from(func: IRMapFunction) -> Self {
+        IRFunctionExpr::MapExpr(func)
+    }
+}
@@ -40,6 +40,8 @@ mod index_of;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -86,6 +88,8 @@ pub use correlation::IRCorrelationMethod;
 #[cfg(feature = "fused")]
 pub use fused::FusedOperator;
 pub use list::IRListFunction;
+#[cfg(feature = "dtype-map")]
+pub use map::IRMapFunction;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -130,6 +134,8 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(IRCategoricalFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(IRMapFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -427,6 +433,8 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -720,6 +728,8 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -1005,6 +1015,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             ListExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
             #[cfg(feature = "dtype-struct")]
@@ -1395,6 +1407,8 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             F::Categorical(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-map")]
+            F::MapExpr(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-struct")]
@@ -2,6 +2,12 @@ use super::*;
 
 pub(super) fn drop_nans(s: Column) -> PolarsResult<Column> {
     match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let ca = s.f16()?;
+            let mask = ca.is_not_nan() | ca.is_null();
+            ca.filter(&mask).map(|ca| ca.into_column())
+        },
         DataType::Float32 => {
             let ca = s.f32()?;
             let mask = ca.is_not_nan() | ca.is_null();
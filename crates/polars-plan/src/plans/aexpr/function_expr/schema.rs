@@ -20,6 +20,8 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -64,7 +66,8 @@ impl IRFunctionExpr {
                 use IRRollingFunction::*;
                 match function {
                     Min | Max => mapper.with_same_dtype(),
-                    Mean | Quantile | Std => mapper.moment_dtype(),
+                    Mean => mapper.mean_dtype(),
+                    Quantile | Std => mapper.moment_dtype(),
                     Var => mapper.var_dtype(),
                     Sum => mapper.sum_dtype(),
                     #[cfg(feature = "cov")]
@@ -78,7 +81,8 @@ impl IRFunctionExpr {
                 use IRRollingFunctionBy::*;
                 match function_by {
                     MinBy | MaxBy => mapper.with_same_dtype(),
-                    MeanBy | QuantileBy | StdBy=> mapper.moment_dtype(),
+                    MeanBy => mapper.mean_dtype(),
+                    QuantileBy | StdBy=> mapper.moment_dtype(),
                     VarBy => mapper.var_dtype(),
                     SumBy => mapper.sum_dtype(),
                 }
@@ -103,11 +107,14 @@ impl IRFunctionExpr {
             Product => mapper.map_dtype(|dtype| {
                 use DataType as T;
                 match dtype {
+                    T::Float16 => T::Float16,
                     T::Float32 => T::Float32,
                     T::Float64 => T::Float64,
                     T::UInt64 => T::UInt64,
                     #[cfg(feature = "dtype-i128")]
                     T::Int128 => T::Int128,
+                    #[cfg(feature = "dtype-u128")]
+                    T::UInt128 => T::UInt128,
                     _ => T::Int64,
                 }
             }),
@@ -493,6 +500,24 @@ impl<'a> FieldsMapper<'a> {
         }
     }
 
+    /// Like [`FieldsMapper::moment_dtype`], but the mean of a decimal stays a decimal.
+    pub fn mean_dtype(&self) -> PolarsResult<Field> {
+        match self.fields[0].dtype() {
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(precision, Some(scale)) => {
+                use polars_core::chunked_array::arithmetic::{
+                    _get_decimal_precision_mean, _get_decimal_scale_mean,
+                };
+                let dtype = DataType::Decimal(
+                    _get_decimal_precision_mean(*precision),
+                    Some(_get_decimal_scale_mean(*scale)),
+                );
+                self.map_dtype(|_| dtype)
+            },
+            _ => self.moment_dtype(),
+        }
+    }
+
     pub fn moment_dtype(&self) -> PolarsResult<Field> {
         let map_inner = |dt: &DataType| match dt {
             #[cfg(feature = "dtype-datetime")]
@@ -501,6 +526,7 @@ impl<'a> FieldsMapper<'a> {
             dt @ DataType::Duration(_) => dt.clone(),
             #[cfg(feature = "dtype-time")]
             dt @ DataType::Time => dt.clone(),
+            DataType::Float16 => DataType::Float16,
             DataType::Float32 => DataType::Float32,
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(..) => DataType::Float64,
@@ -69,7 +69,7 @@ pub fn aexpr_to_column_predicates(
                 is_sumwise_complete = false;
                 continue;
             },
-            D::Float32 | D::Float64 => {
+            D::Float16 | D::Float32 | D::Float64 => {
                 is_sumwise_complete = false;
                 continue;
             },
@@ -301,7 +301,7 @@ fn is_between(
 
     use AnyValue as AV;
     ints!(
-        Int8, Int16, Int32, Int64, Int128, UInt8, UInt16, UInt32, UInt64
+        Int8, Int16, Int32, Int64, Int128, UInt8, UInt16, UInt32, UInt64, UInt128
     );
 
     Some(SpecializedColumnPredicate::Between(low, high))
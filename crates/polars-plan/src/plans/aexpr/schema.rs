@@ -1,5 +1,6 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_core::chunked_array::arithmetic::{
+    _get_decimal_precision_add_sub, _get_decimal_precision_div, _get_decimal_precision_mul,
     _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
 };
 use polars_utils::format_pl_smallstr;
@@ -133,6 +134,10 @@ impl AExpr {
                     List(inner) => Field::new(field.name().clone(), *inner.clone()),
                     #[cfg(feature = "dtype-array")]
                     Array(inner, ..) => Field::new(field.name().clone(), *inner.clone()),
+                    #[cfg(feature = "dtype-map")]
+                    Map(key, value) => {
+                        Field::new(field.name().clone(), DataType::map_entry(key, value))
+                    },
                     _ => field,
                 };
 
@@ -225,7 +230,7 @@ impl AExpr {
                             _ => {
                                 let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
                                 let mapper = FieldsMapper::new(&field);
-                                return mapper.moment_dtype();
+                                return mapper.mean_dtype();
                             },
                         }
                         Ok(field)
@@ -458,6 +463,8 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
                 (Datetime(tu, _), Date) | (Date, Datetime(tu, _)) => Duration(*tu),
                 // T - T != T if T is a datetime / date
                 (Datetime(tul, _), Datetime(tur, _)) => Duration(get_time_units(tul, tur)),
@@ -506,9 +513,15 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (Decimal(p_left, Some(scale_left)), Decimal(p_right, Some(scale_right))) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *p_left,
+                        *scale_left,
+                        *p_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -522,6 +535,10 @@ fn get_arithmetic_field(
                 | (Date, Duration(_))
                 | (Duration(_), Time)
                 | (Time, Duration(_)) => try_get_supertype(left_field.dtype(), &right_type)?,
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Datetime(_, _) | Date) => right_type.clone(),
                 (_, Datetime(_, _))
                 | (Datetime(_, _), _)
                 | (_, Date)
@@ -560,9 +577,15 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (Decimal(p_left, Some(scale_left)), Decimal(p_right, Some(scale_right))) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *p_left,
+                        *scale_left,
+                        *p_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -605,18 +628,27 @@ fn get_arithmetic_field(
                     },
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
-                    let scale = match op {
-                        Operator::Multiply => _get_decimal_scale_mul(*scale_left, *scale_right),
-                        Operator::Divide | Operator::TrueDivide => {
-                            _get_decimal_scale_div(*scale_left)
-                        },
+                (Decimal(p_left, Some(scale_left)), Decimal(p_right, Some(scale_right))) => {
+                    let (precision, scale) = match op {
+                        Operator::Multiply => (
+                            _get_decimal_precision_mul(*p_left, *p_right),
+                            _get_decimal_scale_mul(*scale_left, *scale_right),
+                        ),
+                        Operator::Divide | Operator::TrueDivide => (
+                            _get_decimal_precision_div(
+                                *p_left,
+                                *scale_left,
+                                *p_right,
+                                *scale_right,
+                            ),
+                            _get_decimal_scale_div(*scale_left),
+                        ),
                         _ => {
                             debug_assert!(false);
-                            *scale_left
+                            (None, *scale_left)
                         },
                     };
-                    let dtype = Decimal(None, Some(scale));
+                    let dtype = Decimal(precision, Some(scale));
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
@@ -719,9 +751,15 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
         (Float32, Float64) => Float64,
         (Float32, _) => Float32,
         #[cfg(feature = "dtype-decimal")]
-        (Decimal(_, Some(scale_left)), Decimal(_, _)) => {
+        (Decimal(p_left, Some(scale_left)), Decimal(p_right, scale_right)) => {
             let scale = _get_decimal_scale_div(*scale_left);
-            Decimal(None, Some(scale))
+            let precision = _get_decimal_precision_div(
+                *p_left,
+                *scale_left,
+                *p_right,
+                scale_right.unwrap_or(0),
+            );
+            Decimal(precision, Some(scale))
         },
         (dt, _) if dt.is_primitive_numeric() => Float64,
         #[cfg(feature = "dtype-duration")]
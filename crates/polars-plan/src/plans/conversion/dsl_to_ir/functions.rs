@@ -194,6 +194,17 @@ pub(super) fn convert_functions(
                 L::ToStruct(list_to_struct_args) => IL::ToStruct(list_to_struct_args),
             })
         },
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::ContainsKey => IM::ContainsKey,
+                M::FromLists => IM::FromLists,
+            })
+        },
         #[cfg(feature = "strings")]
         F::StringExpr(string_function) => {
             use {IRStringFunction as IS, StringFunction as S};
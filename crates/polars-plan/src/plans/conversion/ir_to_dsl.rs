@@ -374,6 +374,17 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::ToStruct(list_to_struct_args) => L::ToStruct(list_to_struct_args),
             })
         },
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::ContainsKey => M::ContainsKey,
+                IM::FromLists => M::FromLists,
+            })
+        },
         #[cfg(feature = "strings")]
         IF::StringExpr(f) => {
             use {IRStringFunction as IB, StringFunction as B};
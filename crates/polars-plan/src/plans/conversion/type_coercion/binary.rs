@@ -115,6 +115,38 @@ fn err_date_str_compare() -> PolarsResult<()> {
     }
 }
 
+/// Rewrite `ts + interval` and `ts - interval` into `ts.dt.offset_by(±interval)`, as calendar
+/// intervals cannot be added to the physical timestamp.
+#[cfg(feature = "dtype-interval")]
+fn offset_by_interval(
+    node_ts: Node,
+    node_interval: Node,
+    negate: bool,
+    expr_arena: &mut Arena<AExpr>,
+) -> AExpr {
+    let mut node_interval = node_interval;
+    if negate {
+        let function = IRFunctionExpr::Negate;
+        let options = function.function_options();
+        let input = vec![ExprIR::from_node(node_interval, expr_arena)];
+        node_interval = expr_arena.add(AExpr::Function {
+            input,
+            function,
+            options,
+        });
+    }
+    let function = IRFunctionExpr::TemporalExpr(IRTemporalFunction::OffsetBy);
+    let options = function.function_options();
+    AExpr::Function {
+        input: vec![
+            ExprIR::from_node(node_ts, expr_arena),
+            ExprIR::from_node(node_interval, expr_arena),
+        ],
+        function,
+        options,
+    }
+}
+
 pub(super) fn process_binary(
     expr_arena: &mut Arena<AExpr>,
     input_schema: &Schema,
@@ -212,6 +244,19 @@ pub(super) fn process_binary(
 
     if op.is_arithmetic() {
         match (&type_left, &type_right) {
+            #[cfg(feature = "dtype-interval")]
+            (Date | Datetime(_, _), Interval) if matches!(op, Operator::Plus | Operator::Minus) => {
+                let negate = op == Operator::Minus;
+                return Ok(Some(offset_by_interval(
+                    node_left, node_right, negate, expr_arena,
+                )));
+            },
+            #[cfg(feature = "dtype-interval")]
+            (Interval, Date | Datetime(_, _)) if op == Operator::Plus => {
+                return Ok(Some(offset_by_interval(
+                    node_right, node_left, false, expr_arena,
+                )));
+            },
             (Duration(_), Duration(_)) => return Ok(None),
             (Duration(_), r) if r.is_primitive_numeric() => return Ok(None),
             (String, a) | (a, String) if a.is_primitive_numeric() => {
@@ -58,6 +58,14 @@ fn check_namespace(function: &IRFunctionExpr, first_dtype: &DataType) -> PolarsR
         IRFunctionExpr::ArrayExpr(_) => {
             polars_ensure!(matches!(first_dtype, DataType::Array(_, _)), InvalidOperation: "expected Array type, got: {}", first_dtype)
         },
+        #[cfg(feature = "dtype-map")]
+        IRFunctionExpr::MapExpr(IRMapFunction::FromLists) => {
+            polars_ensure!(matches!(first_dtype, DataType::List(_)), InvalidOperation: "expected List type, got: {}", first_dtype)
+        },
+        #[cfg(feature = "dtype-map")]
+        IRFunctionExpr::MapExpr(_) => {
+            polars_ensure!(matches!(first_dtype, DataType::Map(_, _)), InvalidOperation: "expected Map type, got: {}", first_dtype)
+        },
         #[cfg(feature = "dtype-struct")]
         IRFunctionExpr::StructExpr(_) => {
             polars_ensure!(matches!(first_dtype, DataType::Struct(_)), InvalidOperation: "expected Struct type, got: {}", first_dtype)
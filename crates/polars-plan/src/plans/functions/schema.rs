@@ -131,6 +131,10 @@ fn explode_schema<'a>(
             DataType::Array(inner, _) => {
                 schema.with_column(name.clone(), inner.as_ref().clone());
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                schema.with_column(name.clone(), DataType::map_entry(key, value));
+            },
             _ => {},
         }
 
@@ -0,0 +1,158 @@
+//! Fingerprints of subplans that are stable across processes.
+//!
+//! These are used to persist the results of subplans between queries. A fingerprint must never
+//! be equal for plans that can produce different results, so everything we cannot reliably
+//! identify (in-memory data, user-defined functions) and unseeded random expressions make a plan
+//! un-fingerprintable.
+
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use polars_io::cloud::CloudOptions;
+use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::arena::{Arena, Node};
+use polars_utils::plpath::PlPath;
+
+use super::format::write_ir_non_recursive;
+use crate::dsl::ScanSources;
+#[cfg(feature = "random")]
+use crate::plans::IRFunctionExpr;
+use crate::plans::visitor::IRNode;
+use crate::plans::{AExpr, FileScanIR, FunctionIR, IR, LiteralValue};
+
+/// The fingerprint of a subplan.
+#[derive(Debug, Clone)]
+pub struct PlanFingerprint {
+    /// Hash of the operations in the plan.
+    pub hash: u128,
+    /// The files read by the plan. Their contents are not part of `hash`, so the caller has to
+    /// take their identity (e.g. size and modification time) into account.
+    pub sources: Vec<(PlPath, Option<CloudOptions>)>,
+}
+
+type FixedHasher = <PlFixedStateQuality as BuildHasher>::Hasher;
+
+/// Two independently seeded hashers, giving a 128-bit hash.
+struct Fingerprinter {
+    lo: FixedHasher,
+    hi: FixedHasher,
+}
+
+impl Fingerprinter {
+    fn new() -> Self {
+        Self {
+            lo: PlFixedStateQuality::with_seed(0x9e37_79b9_7f4a_7c15).build_hasher(),
+            hi: PlFixedStateQuality::with_seed(0xc2b2_ae3d_27d4_eb4f).build_hasher(),
+        }
+    }
+
+    fn finish_u128(&self) -> u128 {
+        ((self.hi.finish() as u128) << 64) | self.lo.finish() as u128
+    }
+}
+
+impl Hasher for Fingerprinter {
+    fn write(&mut self, bytes: &[u8]) {
+        self.lo.write(bytes);
+        self.hi.write(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.lo.finish()
+    }
+}
+
+/// Fingerprint the subplan at `root`. Returns `None` if the plan depends on something that
+/// cannot be identified across processes, e.g. in-memory data or user-defined functions, or if
+/// its result is not deterministic.
+pub fn fingerprint_plan(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<PlanFingerprint> {
+    let mut state = Fingerprinter::new();
+    let mut sources = Vec::new();
+    let mut display = String::new();
+
+    // Results of different versions are not comparable.
+    env!("CARGO_PKG_VERSION").hash(&mut state);
+
+    for (node, ir) in lp_arena.iter(root) {
+        match ir {
+            // Cache ids are random, the cached input is part of the fingerprint anyway.
+            IR::Cache { .. } => continue,
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => return None,
+            IR::DataFrameScan { .. } | IR::Sink { .. } | IR::SinkMultiple { .. } => return None,
+            IR::GroupBy { apply: Some(_), .. } => return None,
+            IR::Scan {
+                sources: scan_sources,
+                scan_type,
+                unified_scan_args,
+                ..
+            } => {
+                match scan_type.as_ref() {
+                    FileScanIR::Anonymous { .. } => return None,
+                    #[cfg(feature = "python")]
+                    FileScanIR::PythonDataset { .. } => return None,
+                    _ => {},
+                }
+                let ScanSources::Paths(paths) = scan_sources else {
+                    return None;
+                };
+                let cloud_options = &unified_scan_args.cloud_options;
+                sources.extend(paths.iter().map(|p| (p.clone(), cloud_options.clone())));
+            },
+            IR::MapFunction { function, .. } => match function {
+                FunctionIR::Opaque { .. } => return None,
+                #[cfg(feature = "python")]
+                FunctionIR::OpaquePython(_) => return None,
+                FunctionIR::FastCount {
+                    sources: ScanSources::Paths(paths),
+                    cloud_options,
+                    ..
+                } => sources.extend(paths.iter().map(|p| (p.clone(), cloud_options.clone()))),
+                FunctionIR::FastCount { .. } => return None,
+                _ => {},
+            },
+            _ => {},
+        }
+
+        // The shape of the plan.
+        ir.inputs().count().hash(&mut state);
+        IRNode::new(node)
+            .hashable_and_cmp(lp_arena, expr_arena)
+            .hash(&mut state);
+
+        // The node hash skips parameters that are not needed to tell plans within one query
+        // apart, the formatted node includes most of them.
+        display.clear();
+        write_ir_non_recursive(&mut display, ir, expr_arena, &ir.schema(lp_arena), 0).ok()?;
+        display.hash(&mut state);
+
+        for e in ir.exprs() {
+            for (_, ae) in expr_arena.iter(e.node()) {
+                match ae {
+                    AExpr::AnonymousFunction { .. } => return None,
+                    // Shuffles and samples without a seed differ between runs.
+                    #[cfg(feature = "random")]
+                    AExpr::Function {
+                        function: IRFunctionExpr::Random { seed: None, .. },
+                        ..
+                    } => return None,
+                    // Literal series are only hashed partially.
+                    AExpr::Literal(LiteralValue::Series(s)) => {
+                        for i in 0..s.len() {
+                            s.get(i).ok()?.hash(&mut state);
+                        }
+                    },
+                    _ => {},
+                }
+            }
+        }
+    }
+
+    Some(PlanFingerprint {
+        hash: state.finish_u128(),
+        sources,
+    })
+}
@@ -14,6 +14,7 @@ const INDENT_INCREMENT: usize = 2;
 
 pub struct IRDisplay<'a> {
     lp: IRPlanRef<'a>,
+    statistics: Option<&'a IRStatisticsEstimator>,
 }
 
 #[derive(Clone, Copy)]
@@ -112,7 +113,16 @@ fn write_scan(
 
 impl<'a> IRDisplay<'a> {
     pub fn new(lp: IRPlanRef<'a>) -> Self {
-        Self { lp }
+        Self {
+            lp,
+            statistics: None,
+        }
+    }
+
+    /// Annotate every node with its estimated number of rows.
+    pub fn with_statistics(mut self, statistics: &'a IRStatisticsEstimator) -> Self {
+        self.statistics = Some(statistics);
+        self
     }
 
     fn root(&self) -> &IR {
@@ -122,6 +132,7 @@ impl<'a> IRDisplay<'a> {
     fn with_root(&self, root: Node) -> Self {
         Self {
             lp: self.lp.with_root(root),
+            statistics: self.statistics,
         }
     }
 
@@ -146,6 +157,21 @@ impl<'a> IRDisplay<'a> {
             writeln!(f)?;
         }
 
+        if let Some(statistics) = self.statistics {
+            match statistics.get(self.lp.lp_top) {
+                Some(stats) if stats.exact => {
+                    writeln!(f, "{:indent$}[rows: {}]", "", stats.num_rows as usize)?
+                },
+                Some(stats) => writeln!(
+                    f,
+                    "{:indent$}[estimated rows: {}]",
+                    "",
+                    stats.num_rows.round() as usize
+                )?,
+                None => writeln!(f, "{:indent$}[estimated rows: unknown]", "")?,
+            }
+        }
+
         let sub_indent = indent + INDENT_INCREMENT;
         use IR::*;
 
@@ -1,19 +1,25 @@
 mod dot;
+#[cfg(feature = "cse")]
+mod fingerprint;
 mod format;
 mod inputs;
 mod schema;
+mod statistics;
 pub(crate) mod tree_format;
 
 use std::borrow::Cow;
 use std::fmt;
 
 pub use dot::{EscapeLabel, IRDotDisplay, PathsDisplay, ScanSourcesDisplay};
+#[cfg(feature = "cse")]
+pub use fingerprint::{PlanFingerprint, fingerprint_plan};
 pub use format::{ExprIRDisplay, IRDisplay, write_group_by, write_ir_non_recursive};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::unique_id::UniqueId;
 #[cfg(feature = "ir_serde")]
 use serde::{Deserialize, Serialize};
+pub use statistics::{ColumnStatistics, IRStatistics, IRStatisticsEstimator};
 use strum_macros::IntoStaticStr;
 
 use self::hive::HivePartitionsDf;
@@ -192,6 +198,10 @@ impl IRPlan {
         self.as_ref().describe_tree_format()
     }
 
+    pub fn describe_with_statistics(&self) -> String {
+        self.as_ref().describe_with_statistics()
+    }
+
     pub fn display(&self) -> format::IRDisplay<'_> {
         self.as_ref().display()
     }
@@ -226,6 +236,15 @@ impl<'a> IRPlanRef<'a> {
         self.display().to_string()
     }
 
+    /// Describe the plan, annotating every node with its estimated number of rows.
+    pub fn describe_with_statistics(self) -> String {
+        let mut estimator = IRStatisticsEstimator::new();
+        estimator.estimate(self.lp_top, self.lp_arena, self.expr_arena);
+        format::IRDisplay::new(self)
+            .with_statistics(&estimator)
+            .to_string()
+    }
+
     pub fn describe_tree_format(self) -> String {
         let mut visitor = tree_format::TreeFmtVisitor::default();
         tree_format::TreeFmtNode::root_logical_plan(self).traverse(&mut visitor);
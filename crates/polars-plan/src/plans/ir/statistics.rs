@@ -0,0 +1,685 @@
+//! Estimation of the statistics (number of rows, number of distinct values and null counts) of
+//! the output of [`IR`] nodes.
+//!
+//! Statistics start at the sources, i.e. the row counts and column statistics found in the
+//! metadata of the scanned files and the heights of in-memory frames. They are then propagated
+//! upwards through filters, joins, group-bys, etc. These are estimates and may only be used to
+//! guide optimizations, never to determine the result of a query.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_ops::frame::JoinArgs;
+use recursive::recursive;
+
+use crate::prelude::*;
+
+/// Selectivity we assume for a predicate we know nothing about.
+const DEFAULT_SELECTIVITY: f64 = 0.25;
+/// Selectivity of `col == value` if the number of distinct values of `col` is unknown.
+const DEFAULT_EQ_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a range predicate such as `col < value`.
+const DEFAULT_RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// Estimated statistics of a single column.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ColumnStatistics {
+    /// Estimated number of distinct values.
+    pub n_distinct: Option<f64>,
+    /// Estimated number of null values.
+    pub null_count: Option<f64>,
+}
+
+/// Estimated statistics of the output of an [`IR`] node.
+#[derive(Debug, Clone)]
+pub struct IRStatistics {
+    /// Estimated number of rows.
+    pub num_rows: f64,
+    /// Whether `num_rows` is exact.
+    pub exact: bool,
+    /// Estimated number of rows if no filters had been applied since the sources. This is used to
+    /// propagate the selectivity of filters through joins.
+    pub unfiltered_rows: f64,
+    /// Statistics of the output columns. Columns with unknown statistics are absent.
+    pub columns: PlHashMap<PlSmallStr, ColumnStatistics>,
+}
+
+impl IRStatistics {
+    pub fn new(num_rows: usize, exact: bool) -> Self {
+        Self {
+            num_rows: num_rows as f64,
+            exact,
+            unfiltered_rows: num_rows as f64,
+            columns: PlHashMap::new(),
+        }
+    }
+
+    /// Fraction of the rows that remain after the filters applied since the sources.
+    pub fn selectivity(&self) -> f64 {
+        if self.unfiltered_rows > 0.0 {
+            (self.num_rows / self.unfiltered_rows).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    pub fn n_distinct(&self, name: &str) -> Option<f64> {
+        self.columns.get(name).and_then(|c| c.n_distinct)
+    }
+
+    /// Returns `(Option<known_size>, estimated_size)`, as used by the row estimations in the
+    /// plan options.
+    pub fn row_estimation(&self) -> (Option<usize>, usize) {
+        let rows = self.num_rows.min(usize::MAX as f64) as usize;
+        (self.exact.then_some(rows), rows)
+    }
+
+    /// Keep a fraction of the rows.
+    pub fn filtered(mut self, selectivity: f64) -> Self {
+        let selectivity = selectivity.clamp(0.0, 1.0);
+        self.num_rows *= selectivity;
+        self.exact &= selectivity == 1.0;
+        for c in self.columns.values_mut() {
+            c.null_count = c.null_count.map(|n| n * selectivity);
+        }
+        self.clamp_columns();
+        self
+    }
+
+    /// Keep at most `len` rows.
+    pub fn limited(mut self, len: usize) -> Self {
+        self.num_rows = self.num_rows.min(len as f64);
+        self.clamp_columns();
+        self
+    }
+
+    fn sliced(self, slice: Option<(i64, usize)>) -> Self {
+        match slice {
+            None => self,
+            Some((offset, len)) => {
+                let available = if offset >= 0 {
+                    (self.num_rows - offset as f64).max(0.0)
+                } else {
+                    (offset.unsigned_abs() as f64).min(self.num_rows)
+                };
+                let len = available.min(len as f64) as usize;
+                self.limited(len)
+            },
+        }
+    }
+
+    /// Distinct values and null counts can never exceed the number of rows.
+    fn clamp_columns(&mut self) {
+        let num_rows = self.num_rows;
+        for c in self.columns.values_mut() {
+            c.n_distinct = c.n_distinct.map(|n| n.min(num_rows));
+            c.null_count = c.null_count.map(|n| n.min(num_rows));
+        }
+    }
+
+    fn project<'a>(mut self, names: impl IntoIterator<Item = &'a PlSmallStr>) -> Self {
+        let mut columns = PlHashMap::new();
+        for name in names {
+            if let Some(stats) = self.columns.remove(name) {
+                columns.insert(name.clone(), stats);
+            }
+        }
+        self.columns = columns;
+        self
+    }
+
+    /// Estimate the result of an inner equi-join on the given `(left, right)` key columns.
+    ///
+    /// If the number of distinct values of a key is known on both sides, we use the textbook
+    /// estimate `|L| * |R| / max(ndv(L.key), ndv(R.key))`. Otherwise we assume a foreign-key join
+    /// in which the key is unique in the (unfiltered) smaller relation. Every row of the larger
+    /// relation then finds a match, unless that match was removed by a filter.
+    pub fn inner_join(&self, other: &Self, keys: &[(&str, &str)]) -> Self {
+        let (large, small) = if self.unfiltered_rows >= other.unfiltered_rows {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let denominator = keys
+            .iter()
+            .filter_map(|(l, r)| Some(self.n_distinct(l)?.max(other.n_distinct(r)?)))
+            .reduce(f64::max);
+
+        let num_rows = match denominator {
+            Some(d) if d >= 1.0 => self.num_rows * other.num_rows / d,
+            _ => large.num_rows * small.selectivity(),
+        };
+
+        let mut columns = self.columns.clone();
+        for (name, stats) in &other.columns {
+            columns.entry(name.clone()).or_insert(*stats);
+        }
+        for (l, r) in keys {
+            let n_distinct = match (self.n_distinct(l), other.n_distinct(r)) {
+                (Some(l), Some(r)) => Some(l.min(r)),
+                (l, r) => l.or(r),
+            };
+            for name in [l, r] {
+                if let Some(c) = columns.get_mut(*name) {
+                    c.n_distinct = n_distinct;
+                }
+            }
+        }
+
+        let mut out = Self {
+            num_rows,
+            exact: false,
+            unfiltered_rows: large.unfiltered_rows,
+            columns,
+        };
+        out.clamp_columns();
+        out
+    }
+
+    fn join(&self, other: &Self, keys: &[(&str, &str)], args: &JoinArgs) -> Self {
+        use polars_ops::frame::JoinType::*;
+
+        let inner = self.inner_join(other, keys);
+        let mut out = match &args.how {
+            Inner => inner,
+            Left => Self {
+                num_rows: inner.num_rows.max(self.num_rows),
+                unfiltered_rows: self.unfiltered_rows,
+                ..inner
+            },
+            Right => Self {
+                num_rows: inner.num_rows.max(other.num_rows),
+                unfiltered_rows: other.unfiltered_rows,
+                ..inner
+            },
+            Full => Self {
+                num_rows: inner.num_rows.max(self.num_rows).max(other.num_rows),
+                unfiltered_rows: self.unfiltered_rows.max(other.unfiltered_rows),
+                ..inner
+            },
+            #[cfg(feature = "semi_anti_join")]
+            Semi | Anti => {
+                let matched = inner.num_rows.min(self.num_rows);
+                let num_rows = if args.how.is_semi() {
+                    matched
+                } else {
+                    self.num_rows - matched
+                };
+                let mut out = self.clone();
+                out.num_rows = num_rows;
+                out.exact = false;
+                out
+            },
+            #[cfg(feature = "asof_join")]
+            AsOf(_) => Self {
+                num_rows: self.num_rows,
+                exact: self.exact,
+                unfiltered_rows: self.unfiltered_rows,
+                ..inner
+            },
+            #[cfg(feature = "iejoin")]
+            IEJoin => Self {
+                num_rows: self.num_rows * other.num_rows * DEFAULT_RANGE_SELECTIVITY,
+                unfiltered_rows: self.unfiltered_rows * other.unfiltered_rows,
+                ..inner
+            },
+            Cross => Self {
+                num_rows: self.num_rows * other.num_rows,
+                exact: self.exact && other.exact,
+                unfiltered_rows: self.unfiltered_rows * other.unfiltered_rows,
+                ..inner
+            },
+        };
+        out.clamp_columns();
+        out.sliced(args.slice)
+    }
+
+    fn union<'a>(inputs: impl IntoIterator<Item = &'a Self>) -> Option<Self> {
+        let mut inputs = inputs.into_iter();
+        let mut out = inputs.next()?.clone();
+        for other in inputs {
+            out.num_rows += other.num_rows;
+            out.exact &= other.exact;
+            out.unfiltered_rows += other.unfiltered_rows;
+            out.columns.retain(|name, stats| {
+                let Some(other) = other.columns.get(name) else {
+                    return false;
+                };
+                // The distinct values may overlap, so the maximum is a lower bound.
+                stats.n_distinct = stats
+                    .n_distinct
+                    .zip(other.n_distinct)
+                    .map(|(l, r)| l.max(r));
+                stats.null_count = stats.null_count.zip(other.null_count).map(|(l, r)| l + r);
+                true
+            });
+        }
+        Some(out)
+    }
+
+    /// Estimated number of distinct combinations of `keys`.
+    fn n_groups<'a>(&self, mut keys: impl Iterator<Item = Option<&'a str>>) -> f64 {
+        keys.try_fold(1.0, |acc, key| Some(acc * self.n_distinct(key?)?))
+            .map_or(self.num_rows, |n| n.min(self.num_rows))
+    }
+}
+
+/// Estimates and caches the [`IRStatistics`] of the nodes in a plan.
+#[derive(Default)]
+pub struct IRStatisticsEstimator {
+    cache: PlHashMap<Node, Option<Arc<IRStatistics>>>,
+}
+
+impl IRStatisticsEstimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the statistics of a node that was previously estimated.
+    pub fn get(&self, node: Node) -> Option<&IRStatistics> {
+        self.cache.get(&node)?.as_deref()
+    }
+
+    /// Estimate the statistics of `node`. Returns `None` if they cannot be estimated, e.g. because
+    /// the size of one of the sources is unknown.
+    #[recursive]
+    pub fn estimate(
+        &mut self,
+        node: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<Arc<IRStatistics>> {
+        if let Some(stats) = self.cache.get(&node) {
+            return stats.clone();
+        }
+
+        // Estimate all inputs so that every node in the plan ends up in the cache.
+        let inputs = lp_arena
+            .get(node)
+            .inputs()
+            .map(|input| self.estimate(input, lp_arena, expr_arena))
+            .collect::<Vec<_>>();
+
+        let stats = self
+            .estimate_node(node, &inputs, lp_arena, expr_arena)
+            .map(Arc::new);
+        self.cache.insert(node, stats.clone());
+        stats
+    }
+
+    fn estimate_node(
+        &self,
+        node: Node,
+        inputs: &[Option<Arc<IRStatistics>>],
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<IRStatistics> {
+        use IR::*;
+
+        let input = || inputs.first().cloned().flatten().map(Arc::unwrap_or_clone);
+
+        Some(match lp_arena.get(node) {
+            #[cfg(feature = "python")]
+            PythonScan { .. } => return None,
+            DataFrameScan {
+                df, output_schema, ..
+            } => {
+                let mut stats = IRStatistics::new(df.height(), true);
+                let schema = output_schema.as_ref().unwrap_or_else(|| df.schema());
+                for name in schema.iter_names() {
+                    if let Ok(column) = df.column(name) {
+                        let stats_col = ColumnStatistics {
+                            n_distinct: None,
+                            null_count: Some(column.null_count() as f64),
+                        };
+                        stats.columns.insert(name.clone(), stats_col);
+                    }
+                }
+                stats
+            },
+            Scan {
+                sources,
+                file_info,
+                predicate,
+                unified_scan_args,
+                scan_type,
+                ..
+            } => {
+                // The row estimation is determined from the first source only.
+                let n_sources = sources.len();
+                let mut stats = match file_info.row_estimation {
+                    (Some(n), _) => IRStatistics::new(n.saturating_mul(n_sources), n_sources == 1),
+                    (None, n) if n != 0 && n != usize::MAX => {
+                        IRStatistics::new(n.saturating_mul(n_sources), false)
+                    },
+                    _ => return None,
+                };
+
+                #[cfg(feature = "parquet")]
+                if let FileScanIR::Parquet {
+                    metadata: Some(metadata),
+                    ..
+                } = scan_type.as_ref()
+                {
+                    stats.columns = parquet_column_statistics(metadata, n_sources);
+                }
+                #[cfg(not(feature = "parquet"))]
+                let _ = scan_type;
+
+                if let Some(ri) = &unified_scan_args.row_index {
+                    stats.columns.insert(
+                        ri.name.clone(),
+                        ColumnStatistics {
+                            n_distinct: Some(stats.num_rows),
+                            null_count: Some(0.0),
+                        },
+                    );
+                }
+                if let Some(pre_slice) = &unified_scan_args.pre_slice {
+                    stats = stats.limited(pre_slice.len());
+                }
+                if let Some(predicate) = predicate {
+                    let selectivity = predicate_selectivity(predicate.node(), expr_arena, &stats);
+                    stats = stats.filtered(selectivity);
+                }
+                stats
+            },
+            Filter { predicate, .. } => {
+                let stats = input()?;
+                let selectivity = predicate_selectivity(predicate.node(), expr_arena, &stats);
+                stats.filtered(selectivity)
+            },
+            Slice { offset, len, .. } => input()?.sliced(Some((*offset, *len as usize))),
+            SimpleProjection { columns, .. } => input()?.project(columns.iter_names()),
+            Select { expr, .. } => {
+                let stats = input()?;
+                let mut out = projected_statistics(&stats, expr, expr_arena);
+                if !expr.is_empty() && expr.iter().all(|e| is_scalar_ae(e.node(), expr_arena)) {
+                    out = IRStatistics::new(1, stats.exact);
+                }
+                out
+            },
+            HStack { exprs, .. } => {
+                let stats = input()?;
+                let mut out = stats.clone();
+                let projected = projected_statistics(&stats, exprs, expr_arena);
+                for e in exprs {
+                    out.columns.remove(e.output_name());
+                }
+                out.columns.extend(projected.columns);
+                out
+            },
+            Sort { slice, .. } => input()?.sliced(*slice),
+            Cache { .. } | ExtContext { .. } | Sink { .. } => input()?,
+            GroupBy { keys, .. } => {
+                let stats = input()?;
+                let key_names = || {
+                    keys.iter().map(|k| match expr_arena.get(k.node()) {
+                        AExpr::Column(name) => Some(name.as_str()),
+                        _ => None,
+                    })
+                };
+                let n_groups = stats.n_groups(key_names());
+                let mut out = IRStatistics {
+                    num_rows: n_groups,
+                    exact: false,
+                    unfiltered_rows: n_groups,
+                    columns: PlHashMap::new(),
+                };
+                if keys.len() == 1 {
+                    out.columns.insert(
+                        keys[0].output_name().clone(),
+                        ColumnStatistics {
+                            n_distinct: Some(n_groups),
+                            null_count: None,
+                        },
+                    );
+                } else {
+                    out.columns = projected_statistics(&stats, keys, expr_arena).columns;
+                }
+                out.clamp_columns();
+                out
+            },
+            Distinct { options, .. } => {
+                let stats = input()?;
+                let schema = lp_arena.get(node).schema(lp_arena);
+                let n_groups = match &options.subset {
+                    Some(subset) => stats.n_groups(subset.iter().map(|s| Some(s.as_str()))),
+                    None => stats.n_groups(schema.iter_names().map(|s| Some(s.as_str()))),
+                };
+                let mut out = stats;
+                out.num_rows = n_groups;
+                out.exact = false;
+                out.unfiltered_rows = n_groups;
+                out.clamp_columns();
+                out.sliced(options.slice)
+            },
+            Join {
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                let left = inputs[0].as_deref()?;
+                let right = inputs[1].as_deref()?;
+                let keys = left_on
+                    .iter()
+                    .zip(right_on)
+                    .filter_map(|(l, r)| {
+                        match (expr_arena.get(l.node()), expr_arena.get(r.node())) {
+                            (AExpr::Column(l), AExpr::Column(r)) => Some((l.as_str(), r.as_str())),
+                            _ => None,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                left.join(right, &keys, &options.args)
+            },
+            Union { options, .. } => {
+                let inputs = inputs
+                    .iter()
+                    .map(|i| i.as_deref())
+                    .collect::<Option<Vec<_>>>()?;
+                IRStatistics::union(inputs)?.sliced(options.slice)
+            },
+            HConcat { .. } => {
+                let inputs = inputs
+                    .iter()
+                    .map(|i| i.as_deref())
+                    .collect::<Option<Vec<_>>>()?;
+                let num_rows = inputs.iter().map(|s| s.num_rows).fold(0.0, f64::max);
+                let mut out = IRStatistics {
+                    num_rows,
+                    exact: inputs.iter().all(|s| s.exact),
+                    unfiltered_rows: num_rows,
+                    columns: PlHashMap::new(),
+                };
+                for s in inputs {
+                    out.columns
+                        .extend(s.columns.iter().map(|(k, v)| (k.clone(), *v)));
+                }
+                out
+            },
+            MapFunction { function, .. } => {
+                let mut stats = input()?;
+                match function {
+                    FunctionIR::RowIndex { name, .. } => {
+                        stats.columns.insert(
+                            name.clone(),
+                            ColumnStatistics {
+                                n_distinct: Some(stats.num_rows),
+                                null_count: Some(0.0),
+                            },
+                        );
+                    },
+                    FunctionIR::Rechunk => {},
+                    FunctionIR::FastCount { .. } => stats = IRStatistics::new(1, true),
+                    _ => {
+                        // The function may change the number of rows and the contents of the
+                        // columns.
+                        stats.exact = false;
+                        stats.columns.clear();
+                    },
+                }
+                stats
+            },
+            SinkMultiple { .. } => return None,
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted { .. } => {
+                let inputs = inputs
+                    .iter()
+                    .map(|i| i.as_deref())
+                    .collect::<Option<Vec<_>>>()?;
+                IRStatistics::union(inputs)?
+            },
+            Invalid => unreachable!(),
+        })
+    }
+}
+
+/// Statistics of the output columns of `exprs` that directly refer to an input column.
+fn projected_statistics(
+    input: &IRStatistics,
+    exprs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> IRStatistics {
+    let mut out = IRStatistics {
+        columns: PlHashMap::new(),
+        ..input.clone()
+    };
+    for e in exprs {
+        if let AExpr::Column(name) = expr_arena.get(e.node()) {
+            if let Some(stats) = input.columns.get(name) {
+                out.columns.insert(e.output_name().clone(), *stats);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_column_statistics(
+    metadata: &polars_io::parquet::metadata::FileMetadata,
+    n_sources: usize,
+) -> PlHashMap<PlSmallStr, ColumnStatistics> {
+    let mut columns: PlHashMap<PlSmallStr, ColumnStatistics> = PlHashMap::new();
+    let Some(first) = metadata.row_groups.first() else {
+        return columns;
+    };
+
+    for chunk in first.parquet_columns() {
+        // Only consider non-nested columns.
+        let [name] = chunk.descriptor().path_in_schema.as_slice() else {
+            continue;
+        };
+
+        let mut stats = ColumnStatistics {
+            n_distinct: Some(0.0),
+            null_count: Some(0.0),
+        };
+        for rg in &metadata.row_groups {
+            let Some(mut chunks) = rg.columns_under_root_iter(name) else {
+                stats = ColumnStatistics::default();
+                break;
+            };
+            let rg_stats = chunks.next().and_then(|c| c.metadata().statistics.as_ref());
+            // The distinct values of row groups may overlap, so the maximum is a lower bound.
+            stats.n_distinct = stats
+                .n_distinct
+                .zip(rg_stats.and_then(|s| s.distinct_count))
+                .map(|(acc, n)| acc.max(n as f64));
+            stats.null_count = stats
+                .null_count
+                .zip(rg_stats.and_then(|s| s.null_count))
+                .map(|(acc, n)| acc + n as f64);
+        }
+        stats.null_count = stats.null_count.map(|n| n * n_sources as f64);
+
+        if stats != ColumnStatistics::default() {
+            columns.insert(name.clone(), stats);
+        }
+    }
+    columns
+}
+
+/// If `node` is `col == <literal>` or `<literal> == col`, returns the name of the column.
+fn column_compared_to_literal<'a>(
+    left: Node,
+    right: Node,
+    expr_arena: &'a Arena<AExpr>,
+) -> Option<&'a PlSmallStr> {
+    match (expr_arena.get(left), expr_arena.get(right)) {
+        (AExpr::Column(name), AExpr::Literal(_)) | (AExpr::Literal(_), AExpr::Column(name)) => {
+            Some(name)
+        },
+        _ => None,
+    }
+}
+
+/// Estimate the fraction of rows for which `predicate` holds.
+#[recursive]
+pub(crate) fn predicate_selectivity(
+    predicate: Node,
+    expr_arena: &Arena<AExpr>,
+    input: &IRStatistics,
+) -> f64 {
+    let null_fraction = |node: Node| -> Option<f64> {
+        let AExpr::Column(name) = expr_arena.get(node) else {
+            return None;
+        };
+        let null_count = input.columns.get(name)?.null_count?;
+        (input.num_rows > 0.0).then(|| null_count / input.num_rows)
+    };
+
+    let selectivity = match expr_arena.get(predicate) {
+        AExpr::BinaryExpr { left, op, right } => {
+            let eq_selectivity = || {
+                column_compared_to_literal(*left, *right, expr_arena)
+                    .and_then(|name| input.n_distinct(name))
+                    .map_or(DEFAULT_EQ_SELECTIVITY, |n| 1.0 / n.max(1.0))
+            };
+
+            match op {
+                Operator::And | Operator::LogicalAnd => {
+                    predicate_selectivity(*left, expr_arena, input)
+                        * predicate_selectivity(*right, expr_arena, input)
+                },
+                Operator::Or | Operator::LogicalOr => {
+                    let l = predicate_selectivity(*left, expr_arena, input);
+                    let r = predicate_selectivity(*right, expr_arena, input);
+                    l + r - l * r
+                },
+                Operator::Eq | Operator::EqValidity => eq_selectivity(),
+                Operator::NotEq | Operator::NotEqValidity => 1.0 - eq_selectivity(),
+                Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+                    DEFAULT_RANGE_SELECTIVITY
+                },
+                _ => DEFAULT_SELECTIVITY,
+            }
+        },
+        AExpr::Function {
+            input: args,
+            function: IRFunctionExpr::Boolean(function),
+            ..
+        } => match function {
+            IRBooleanFunction::Not => {
+                1.0 - predicate_selectivity(args[0].node(), expr_arena, input)
+            },
+            IRBooleanFunction::IsNull => {
+                null_fraction(args[0].node()).unwrap_or(DEFAULT_EQ_SELECTIVITY)
+            },
+            IRBooleanFunction::IsNotNull => {
+                1.0 - null_fraction(args[0].node()).unwrap_or(DEFAULT_EQ_SELECTIVITY)
+            },
+            _ => DEFAULT_SELECTIVITY,
+        },
+        AExpr::Literal(lv) => match lv.bool() {
+            Some(true) => 1.0,
+            Some(false) => 0.0,
+            None => DEFAULT_SELECTIVITY,
+        },
+        _ => DEFAULT_SELECTIVITY,
+    };
+
+    selectivity.clamp(0.0, 1.0)
+}
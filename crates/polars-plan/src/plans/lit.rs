@@ -237,6 +237,7 @@ impl LiteralValue {
                 AnyValue::UInt16(v) => Ok(v as usize),
                 AnyValue::UInt32(v) => cast_usize!(v),
                 AnyValue::UInt64(v) => cast_usize!(v),
+                AnyValue::UInt128(v) => cast_usize!(v),
                 AnyValue::Int8(v) => cast_usize!(v),
                 AnyValue::Int16(v) => cast_usize!(v),
                 AnyValue::Int32(v) => cast_usize!(v),
@@ -267,6 +268,7 @@ impl LiteralValue {
                 AnyValue::UInt16(v) => Ok(v as i64),
                 AnyValue::UInt32(v) => cast_i64!(v),
                 AnyValue::UInt64(v) => cast_i64!(v),
+                AnyValue::UInt128(v) => cast_i64!(v),
                 AnyValue::Int8(v) => cast_i64!(v),
                 AnyValue::Int16(v) => cast_i64!(v),
                 AnyValue::Int32(v) => cast_i64!(v),
@@ -491,6 +493,7 @@ macro_rules! make_dyn_lit {
 }
 
 make_literal!(bool, Boolean);
+make_literal_typed!(f16, Float16);
 make_literal_typed!(f32, Float32);
 make_literal_typed!(f64, Float64);
 make_literal_typed!(i8, Int8);
@@ -502,6 +505,7 @@ make_literal_typed!(u8, UInt8);
 make_literal_typed!(u16, UInt16);
 make_literal_typed!(u32, UInt32);
 make_literal_typed!(u64, UInt64);
+make_literal_typed!(u128, UInt128);
 
 make_dyn_lit!(f32, Float);
 make_dyn_lit!(f64, Float);
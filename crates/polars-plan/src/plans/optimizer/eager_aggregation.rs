@@ -0,0 +1,281 @@
+//! Optimization that pushes decomposable aggregations below joins.
+//!
+//! A query like `fact.join(dim, on="id").group_by("dim_attr").agg(pl.col.x.sum())` joins every
+//! row of `fact` before aggregating. Because the sum is decomposable, we can instead first
+//! aggregate `fact` by its join keys, join the (much smaller) partial result and re-aggregate:
+//!
+//! ```text
+//! group_by([dim_attr]).agg([sum(x)])           group_by([dim_attr]).agg([sum(partial)])
+//!   join(id == id)                      =>       join(id == id)
+//!     fact                                         group_by([id]).agg([sum(x).alias(partial)])
+//!     dim                                            fact
+//!                                                  dim
+//! ```
+//!
+//! This is valid as every row of `fact` with the same join keys joins with the same rows of
+//! `dim`. Only `sum`, `min`, `max`, `count` and `len` are decomposed.
+
+use std::sync::Arc;
+
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinType, JoinValidation};
+use polars_utils::arena::{Arena, Node};
+use polars_utils::format_pl_smallstr;
+
+use super::{AExpr, IR, aexpr_to_leaf_names_iter};
+use crate::constants::POLARS_TMP_PREFIX;
+use crate::dsl::GroupbyOptions;
+use crate::plans::{ExprIR, IRAggExpr, IRBuilder, IRStatisticsEstimator, OutputName};
+
+/// Don't pre-aggregate if we expect it to less than halve the number of rows that are joined.
+const MIN_REDUCTION: f64 = 0.5;
+
+/// An aggregation that is computed in two phases.
+struct SplitAgg {
+    /// Computed per join key below the join.
+    partial: ExprIR,
+    /// Combines the partial results above the join.
+    fin: ExprIR,
+}
+
+fn column_name(node: Node, expr_arena: &Arena<AExpr>) -> Option<&PlSmallStr> {
+    match expr_arena.get(node) {
+        AExpr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Split `agg` into a partial and a final aggregation. Returns `None` if it is not decomposable
+/// or if it doesn't only reference columns of `input_schema`.
+fn split_agg(
+    agg: &ExprIR,
+    idx: usize,
+    input_schema: &Schema,
+    other_schema: &Schema,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<SplitAgg> {
+    let partial_name = format_pl_smallstr!("{POLARS_TMP_PREFIX}eager_agg_{idx}");
+    let is_input_column = |node: Node, expr_arena: &Arena<AExpr>| {
+        column_name(node, expr_arena)
+            .is_some_and(|name| input_schema.contains(name) && !other_schema.contains(name))
+    };
+
+    let partial_col = expr_arena.add(AExpr::Column(partial_name.clone()));
+    let fin = match expr_arena.get(agg.node()) {
+        AExpr::Len => IRAggExpr::Sum(partial_col),
+        AExpr::Agg(agg) => match agg {
+            IRAggExpr::Sum(input) if is_input_column(*input, expr_arena) => {
+                IRAggExpr::Sum(partial_col)
+            },
+            IRAggExpr::Count(input, _) if is_input_column(*input, expr_arena) => {
+                IRAggExpr::Sum(partial_col)
+            },
+            IRAggExpr::Min {
+                input,
+                propagate_nans,
+            } if is_input_column(*input, expr_arena) => IRAggExpr::Min {
+                input: partial_col,
+                propagate_nans: *propagate_nans,
+            },
+            IRAggExpr::Max {
+                input,
+                propagate_nans,
+            } if is_input_column(*input, expr_arena) => IRAggExpr::Max {
+                input: partial_col,
+                propagate_nans: *propagate_nans,
+            },
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let fin = expr_arena.add(AExpr::Agg(fin));
+    Some(SplitAgg {
+        partial: ExprIR::new(agg.node(), OutputName::Alias(partial_name)),
+        fin: ExprIR::new(fin, OutputName::Alias(agg.output_name().clone())),
+    })
+}
+
+/// Whether the input at `side` (0 for left, 1 for right) of the join may be pre-aggregated.
+fn can_pre_aggregate_side(side: usize, how: &JoinType, validation: JoinValidation) -> bool {
+    // Pre-aggregation makes the join keys unique, so uniqueness checks on that side would
+    // trivially succeed.
+    let validation_ok = match validation {
+        JoinValidation::ManyToMany => true,
+        JoinValidation::ManyToOne => side == 0,
+        JoinValidation::OneToMany => side == 1,
+        JoinValidation::OneToOne => false,
+    };
+    // Only the unmatched rows of the side we don't pre-aggregate may be null-extended.
+    let how_ok = match how {
+        JoinType::Inner => true,
+        JoinType::Left => side == 0,
+        JoinType::Right => side == 1,
+        _ => false,
+    };
+    validation_ok && how_ok
+}
+
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let verbose = verbose();
+    let mut estimator = IRStatisticsEstimator::new();
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        if let Some(ir) = try_pre_aggregate(current, lp_arena, expr_arena, &mut estimator) {
+            if verbose {
+                eprintln!("eager_aggregation: pushed aggregation below join");
+            }
+            lp_arena.replace(current, ir);
+        }
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+}
+
+/// Returns the new group-by to replace `node` with, if its aggregations can be pushed below
+/// its input join.
+fn try_pre_aggregate(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    estimator: &mut IRStatisticsEstimator,
+) -> Option<IR> {
+    let IR::GroupBy {
+        input,
+        keys,
+        aggs,
+        schema: output_schema,
+        maintain_order: false,
+        options,
+        apply: None,
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+    if options.is_rolling() || options.is_dynamic() || aggs.is_empty() {
+        return None;
+    }
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options: join_options,
+        ..
+    } = lp_arena.get(*input)
+    else {
+        return None;
+    };
+    if join_options.options.is_some() || join_options.args.slice.is_some() {
+        return None;
+    }
+
+    let keys = keys.clone();
+    let aggs = aggs.clone();
+    let output_schema = output_schema.clone();
+    let options = options.clone();
+    let inputs = [*input_left, *input_right];
+    let join_keys = [left_on.clone(), right_on.clone()];
+    let join_options = join_options.clone();
+    let schemas = inputs.map(|node| lp_arena.get(node).schema(lp_arena).into_owned());
+
+    // The aggregations determine which side we pre-aggregate. If they only count rows, either
+    // side works and we take the left.
+    let side = (0..2)
+        .filter(|&side| {
+            can_pre_aggregate_side(side, &join_options.args.how, join_options.args.validation)
+        })
+        .find(|&side| {
+            aggs.iter().all(|agg| {
+                aexpr_to_leaf_names_iter(agg.node(), expr_arena)
+                    .all(|name| schemas[side].contains(&name) && !schemas[1 - side].contains(&name))
+            })
+        })?;
+    let (agg_schema, other_schema) = (&schemas[side], &schemas[1 - side]);
+
+    // Group by the join keys and by the group keys that originate from the pre-aggregated side.
+    let mut pre_keys = Vec::with_capacity(keys.len() + join_keys[side].len());
+    for key in &join_keys[side] {
+        let name = column_name(key.node(), expr_arena)?;
+        if !pre_keys.iter().any(|k: &ExprIR| k.output_name() == name) {
+            pre_keys.push(key.clone());
+        }
+    }
+    for key in &keys {
+        let name = column_name(key.node(), expr_arena)?;
+        if key.output_name() != name {
+            return None;
+        }
+        if pre_keys.iter().any(|k| k.output_name() == name) || !agg_schema.contains(name) {
+            continue;
+        }
+        // It would be suffixed in the join output.
+        if other_schema.contains(name) {
+            return None;
+        }
+        pre_keys.push(key.clone());
+    }
+
+    // Columns of the other side are suffixed in the join output if they collide with a column
+    // of the pre-aggregated side. That side loses all columns but its keys, so only collisions
+    // on those keys would be preserved.
+    if other_schema
+        .iter_names()
+        .any(|name| agg_schema.contains(name) && !pre_keys.iter().any(|k| k.output_name() == name))
+    {
+        return None;
+    }
+
+    // If the number of distinct keys is unknown, we assume pre-aggregating pays off.
+    if let Some(stats) = estimator.estimate(inputs[side], lp_arena, expr_arena) {
+        let n_groups = pre_keys.iter().try_fold(1.0, |acc, k| {
+            Some(acc * stats.n_distinct(column_name(k.node(), expr_arena)?)?)
+        });
+        if n_groups.is_some_and(|n| n > stats.num_rows * MIN_REDUCTION) {
+            return None;
+        }
+    }
+
+    let (partial_aggs, final_aggs): (Vec<_>, Vec<_>) = aggs
+        .iter()
+        .enumerate()
+        .map(|(i, agg)| {
+            split_agg(agg, i, agg_schema, other_schema, expr_arena).map(|s| (s.partial, s.fin))
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    let pre_aggregated = IRBuilder::new(inputs[side], expr_arena, lp_arena)
+        .group_by(
+            pre_keys,
+            partial_aggs,
+            None,
+            false,
+            Arc::new(GroupbyOptions::default()),
+        )
+        .node();
+
+    let (left, right) = if side == 0 {
+        (pre_aggregated, inputs[1])
+    } else {
+        (inputs[0], pre_aggregated)
+    };
+    let [left_on, right_on] = join_keys;
+    let join = IRBuilder::new(left, expr_arena, lp_arena)
+        .join(right, left_on, right_on, join_options)
+        .node();
+    let join_schema = lp_arena.get(join).schema(lp_arena).into_owned();
+    if !keys.iter().all(|k| join_schema.contains(k.output_name())) {
+        return None;
+    }
+
+    let ir = IRBuilder::new(join, expr_arena, lp_arena)
+        .group_by(keys, final_aggs, None, false, options)
+        .build();
+
+    // E.g. summing an already summed column might upcast differently.
+    (ir.schema(lp_arena).as_ref() == &output_schema).then_some(ir)
+}
@@ -0,0 +1,405 @@
+//! Optimization that reorders chains of inner equi-joins based on estimated cardinalities.
+//!
+//! A chain like `a.join(b, ..).join(c, ..).join(d, ..)` is flattened into its relations and join
+//! conditions. The relations are then greedily joined again, starting with the connected pair
+//! that yields the smallest estimated result and repeatedly adding the connected relation that
+//! keeps the intermediate result smallest. The plan is only rewritten if the estimated cost (the
+//! sum of all intermediate join sizes) improves on the order the user wrote.
+//!
+//! A projection is placed on top of the reordered chain so that the output schema is unchanged.
+
+use std::sync::Arc;
+
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinCoalesce, JoinType, JoinValidation, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, IR, JoinOptionsIR};
+use crate::plans::{ExprIR, IRStatistics, IRStatisticsEstimator, OutputName, det_join_schema};
+
+/// An equality condition between a column of two relations in the chain.
+#[derive(Debug, Clone)]
+struct JoinCondition {
+    left: usize,
+    left_name: PlSmallStr,
+    right: usize,
+    right_name: PlSmallStr,
+}
+
+struct JoinChain {
+    relations: Vec<Node>,
+    schemas: Vec<SchemaRef>,
+    statistics: Vec<Arc<IRStatistics>>,
+    conditions: Vec<JoinCondition>,
+    /// Estimated cost of the plan as written by the user.
+    cost: f64,
+}
+
+fn is_reorderable_join(options: &JoinOptionsIR, root: &JoinOptionsIR) -> bool {
+    let args = &options.args;
+    matches!(args.how, JoinType::Inner)
+        && options.options.is_none()
+        && args.slice.is_none()
+        && args.validation == JoinValidation::ManyToMany
+        && args.maintain_order == MaintainOrderJoin::None
+        && args.nulls_equal == root.args.nulls_equal
+        && args.suffix() == root.args.suffix()
+}
+
+fn column_expr(name: PlSmallStr, expr_arena: &mut Arena<AExpr>) -> ExprIR {
+    let node = expr_arena.add(AExpr::Column(name.clone()));
+    ExprIR::new(node, OutputName::ColumnLhs(name))
+}
+
+fn key_names<'a>(keys: &'a [ExprIR], expr_arena: &'a Arena<AExpr>) -> Option<Vec<&'a PlSmallStr>> {
+    keys.iter()
+        .map(|e| match expr_arena.get(e.node()) {
+            AExpr::Column(name) => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+impl JoinChain {
+    fn collect(
+        root: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+        estimator: &mut IRStatisticsEstimator,
+    ) -> Option<Self> {
+        let IR::Join { options, .. } = lp_arena.get(root) else {
+            unreachable!()
+        };
+
+        let mut chain = JoinChain {
+            relations: Vec::new(),
+            schemas: Vec::new(),
+            statistics: Vec::new(),
+            conditions: Vec::new(),
+            cost: 0.0,
+        };
+        chain.flatten(root, options, lp_arena, expr_arena, estimator)?;
+        Some(chain)
+    }
+
+    /// Flatten the subtree at `node` into the chain, returning its estimated size.
+    fn flatten(
+        &mut self,
+        node: Node,
+        root_options: &JoinOptionsIR,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+        estimator: &mut IRStatisticsEstimator,
+    ) -> Option<IRStatistics> {
+        match lp_arena.get(node) {
+            IR::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } if is_reorderable_join(options, root_options) => {
+                let left_on = key_names(left_on, expr_arena)?;
+                let right_on = key_names(right_on, expr_arena)?;
+
+                let left_start = self.relations.len();
+                let left_stats =
+                    self.flatten(*input_left, root_options, lp_arena, expr_arena, estimator)?;
+                let right_start = self.relations.len();
+                let right_stats =
+                    self.flatten(*input_right, root_options, lp_arena, expr_arena, estimator)?;
+                let right_end = self.relations.len();
+
+                let keys = left_on
+                    .iter()
+                    .zip(&right_on)
+                    .map(|(l, r)| (l.as_str(), r.as_str()))
+                    .collect::<Vec<_>>();
+                let stats = left_stats.inner_join(&right_stats, &keys);
+                self.cost += stats.num_rows;
+
+                for (left_name, right_name) in left_on.into_iter().zip(right_on) {
+                    let left = self.resolve(left_name, left_start..right_start)?;
+                    let right = self.resolve(right_name, right_start..right_end)?;
+                    self.conditions.push(JoinCondition {
+                        left,
+                        left_name: left_name.clone(),
+                        right,
+                        right_name: right_name.clone(),
+                    });
+                }
+
+                Some(stats)
+            },
+            _ => {
+                let stats = estimator.estimate(node, lp_arena, expr_arena)?;
+                self.relations.push(node);
+                self.schemas
+                    .push(lp_arena.get(node).schema(lp_arena).into_owned());
+                self.statistics.push(stats.clone());
+                Some(Arc::unwrap_or_clone(stats))
+            },
+        }
+    }
+
+    /// Find the relation a column in the output of a (sub)chain originates from.
+    fn resolve(&self, name: &str, range: std::ops::Range<usize>) -> Option<usize> {
+        range.into_iter().find(|&i| self.schemas[i].contains(name))
+    }
+
+    /// Columns that exist in multiple relations must be equated by the join conditions, otherwise
+    /// we cannot tell which one survives in the output of the reordered chain.
+    fn add_implied_conditions(&mut self) -> Option<()> {
+        fn find(component: &mut [usize], mut i: usize) -> usize {
+            while component[i] != i {
+                component[i] = component[component[i]];
+                i = component[i];
+            }
+            i
+        }
+
+        let mut name_to_relations: PlIndexMap<&PlSmallStr, Vec<usize>> = PlIndexMap::new();
+        for (i, schema) in self.schemas.iter().enumerate() {
+            for name in schema.iter_names() {
+                name_to_relations.entry(name).or_default().push(i);
+            }
+        }
+
+        let mut implied = Vec::new();
+        for (&name, relations) in name_to_relations.iter().filter(|(_, r)| r.len() > 1) {
+            // Union-find over the `name == name` conditions.
+            let mut component: Vec<usize> = (0..self.relations.len()).collect();
+            for c in &self.conditions {
+                if &c.left_name == name && &c.right_name == name {
+                    let (a, b) = (find(&mut component, c.left), find(&mut component, c.right));
+                    component[a] = b;
+                }
+            }
+
+            let first = find(&mut component, relations[0]);
+            for &other in &relations[1..] {
+                if find(&mut component, other) != first {
+                    return None;
+                }
+                implied.push(JoinCondition {
+                    left: relations[0],
+                    left_name: name.clone(),
+                    right: other,
+                    right_name: name.clone(),
+                });
+            }
+        }
+
+        self.conditions.extend(implied);
+        Some(())
+    }
+
+    /// Conditions between the relations in `joined` and relation `next`, oriented such that the
+    /// left side references `joined`.
+    fn conditions_between(&self, joined: &[bool], next: usize) -> Vec<(PlSmallStr, PlSmallStr)> {
+        let mut out: Vec<(PlSmallStr, PlSmallStr)> = Vec::new();
+        for c in &self.conditions {
+            let pair = if joined[c.left] && c.right == next {
+                (c.left_name.clone(), c.right_name.clone())
+            } else if joined[c.right] && c.left == next {
+                (c.right_name.clone(), c.left_name.clone())
+            } else {
+                continue;
+            };
+            if !out.contains(&pair) {
+                out.push(pair);
+            }
+        }
+        out
+    }
+
+    /// Estimate the result of joining relation `next` to the relations in `joined`.
+    fn join_statistics(
+        &self,
+        current: &IRStatistics,
+        joined: &[bool],
+        next: usize,
+    ) -> IRStatistics {
+        let keys = self.conditions_between(joined, next);
+        let keys = keys
+            .iter()
+            .map(|(l, r)| (l.as_str(), r.as_str()))
+            .collect::<Vec<_>>();
+        current.inner_join(&self.statistics[next], &keys)
+    }
+
+    /// Greedily determine a join order. Returns `None` if the join graph is not connected.
+    fn greedy_order(&self) -> Option<(Vec<usize>, f64)> {
+        let n = self.relations.len();
+        let mut joined = vec![false; n];
+
+        // Start with the connected pair that has the smallest result.
+        let (mut current, (first, second)) = self
+            .conditions
+            .iter()
+            .map(|c| {
+                let (a, b) = (c.left.min(c.right), c.left.max(c.right));
+                let mut only_a = vec![false; n];
+                only_a[a] = true;
+                (
+                    self.join_statistics(&self.statistics[a], &only_a, b),
+                    (a, b),
+                )
+            })
+            .min_by(|(l, _), (r, _)| l.num_rows.total_cmp(&r.num_rows))?;
+
+        joined[first] = true;
+        joined[second] = true;
+        let mut order = vec![first, second];
+        let mut cost = current.num_rows;
+
+        while order.len() < n {
+            let (stats, next) = (0..n)
+                .filter(|&i| !joined[i] && !self.conditions_between(&joined, i).is_empty())
+                .map(|i| (self.join_statistics(&current, &joined, i), i))
+                .min_by(|(l, _), (r, _)| l.num_rows.total_cmp(&r.num_rows))?;
+
+            joined[next] = true;
+            order.push(next);
+            current = stats;
+            cost += current.num_rows;
+        }
+
+        Some((order, cost))
+    }
+}
+
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let verbose = verbose();
+    let mut estimator = IRStatisticsEstimator::new();
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        let IR::Join { options, .. } = lp_arena.get(current) else {
+            lp_arena.get(current).copy_inputs(&mut ir_stack);
+            continue;
+        };
+
+        if !is_reorderable_join(options, options) {
+            lp_arena.get(current).copy_inputs(&mut ir_stack);
+            continue;
+        }
+
+        let Some(chain) = JoinChain::collect(current, lp_arena, expr_arena, &mut estimator) else {
+            lp_arena.get(current).copy_inputs(&mut ir_stack);
+            continue;
+        };
+
+        // Continue below the chain.
+        ir_stack.extend_from_slice(&chain.relations);
+
+        if chain.relations.len() < 3 {
+            continue;
+        }
+
+        if let Some(new_root) = reorder_chain(current, chain, lp_arena, expr_arena) {
+            if verbose {
+                eprintln!("join_order: reordered chain of inner joins");
+            }
+            lp_arena.swap(current, new_root);
+        }
+    }
+}
+
+/// Builds the reordered chain and returns the node of its (schema-restoring) root.
+fn reorder_chain(
+    root: Node,
+    mut chain: JoinChain,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<Node> {
+    let IR::Join {
+        schema: output_schema,
+        options: root_options,
+        ..
+    } = lp_arena.get(root)
+    else {
+        unreachable!()
+    };
+    let output_schema = output_schema.clone();
+    let root_options = root_options.clone();
+
+    // If columns were suffixed, we cannot restore the output schema after reordering.
+    if output_schema
+        .iter_names()
+        .any(|name| !chain.schemas.iter().any(|s| s.contains(name)))
+    {
+        return None;
+    }
+
+    chain.add_implied_conditions()?;
+
+    let (order, cost) = chain.greedy_order()?;
+
+    if cost >= chain.cost {
+        return None;
+    }
+
+    let mut joined = vec![false; chain.relations.len()];
+    joined[order[0]] = true;
+    let mut current = chain.relations[order[0]];
+    let mut current_stats = chain.statistics[order[0]].as_ref().clone();
+
+    for &next in &order[1..] {
+        let keys = chain.conditions_between(&joined, next);
+        let next_stats = chain.join_statistics(&current_stats, &joined, next);
+        joined[next] = true;
+
+        let mut options = root_options.as_ref().clone();
+        // Differently named keys must be kept, as the user might select either of them.
+        options.args.coalesce = if keys.iter().all(|(l, r)| l == r) {
+            JoinCoalesce::CoalesceColumns
+        } else {
+            JoinCoalesce::KeepColumns
+        };
+        options.rows_left = current_stats.row_estimation();
+        options.rows_right = chain.statistics[next].row_estimation();
+
+        let (left_on, right_on): (Vec<_>, Vec<_>) = keys
+            .into_iter()
+            .map(|(l, r)| (column_expr(l, expr_arena), column_expr(r, expr_arena)))
+            .unzip();
+
+        let input_right = chain.relations[next];
+        let schema = det_join_schema(
+            &lp_arena.get(current).schema(lp_arena),
+            &lp_arena.get(input_right).schema(lp_arena),
+            &left_on,
+            &right_on,
+            &options,
+            expr_arena,
+        )
+        .ok()?;
+
+        current = lp_arena.add(IR::Join {
+            input_left: current,
+            input_right,
+            schema,
+            left_on,
+            right_on,
+            options: Arc::new(options),
+        });
+        current_stats = next_stats;
+    }
+
+    let schema = lp_arena.get(current).schema(lp_arena);
+    if !output_schema
+        .iter()
+        .all(|(name, dtype)| schema.get(name) == Some(dtype))
+    {
+        return None;
+    }
+
+    Some(lp_arena.add(IR::SimpleProjection {
+        input: current,
+        columns: output_schema,
+    }))
+}
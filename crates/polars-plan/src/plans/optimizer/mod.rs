@@ -4,6 +4,7 @@ use crate::prelude::*;
 
 mod cache_states;
 mod delay_rechunk;
+mod eager_aggregation;
 
 mod cluster_with_columns;
 mod collapse_and_project;
@@ -18,6 +19,7 @@ mod fused;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
+mod join_order;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod set_order;
@@ -192,6 +194,20 @@ pub fn optimize(
         collapse_joins::optimize(lp_top, lp_arena, expr_arena, opt_flags.new_streaming());
     }
 
+    // Make sure it is after predicate pushdown and collapse joins, so that filters are pushed to
+    // the relations we estimate and cross joins are turned into inner joins.
+    if opt_flags.join_reorder() && get_or_init_members!().has_joins_or_unions {
+        join_order::optimize(lp_top, lp_arena, expr_arena);
+    }
+
+    // Make sure it is after join reordering, so that we pre-aggregate the final join inputs.
+    if opt_flags.eager_aggregation() {
+        let members = get_or_init_members!();
+        if members.has_group_by && members.has_joins_or_unions {
+            eager_aggregation::optimize(lp_top, lp_arena, expr_arena);
+        }
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
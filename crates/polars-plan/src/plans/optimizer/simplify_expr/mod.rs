@@ -56,6 +56,9 @@ macro_rules! eval_binary_same_type {
                         (AnyValue::UInt64($l), AnyValue::UInt64($r)) => {
                             Some(AExpr::Literal(<Scalar as From<u64>>::from($ret).into()))
                         },
+                        (AnyValue::UInt128($l), AnyValue::UInt128($r)) => {
+                            Some(AExpr::Literal(<Scalar as From<u128>>::from($ret).into()))
+                        },
 
                         _ => None,
                     }
@@ -109,6 +112,7 @@ macro_rules! eval_binary_cmp_same_type {
                 (AnyValue::UInt16(l), AnyValue::UInt16(r)) => Some(AExpr::Literal({ let x: bool = l $operand r; Scalar::from(x) }.into())),
                 (AnyValue::UInt32(l), AnyValue::UInt32(r)) => Some(AExpr::Literal({ let x: bool = l $operand r; Scalar::from(x) }.into())),
                 (AnyValue::UInt64(l), AnyValue::UInt64(r)) => Some(AExpr::Literal({ let x: bool = l $operand r; Scalar::from(x) }.into())),
+                (AnyValue::UInt128(l), AnyValue::UInt128(r)) => Some(AExpr::Literal({ let x: bool = l $operand r; Scalar::from(x) }.into())),
 
                 _ => None,
             }.into(),
@@ -534,6 +538,11 @@ impl OptimizationRule for SimplifyExprRule {
                                                 <Scalar as From<u64>>::from(x / y).into(),
                                             ))
                                         },
+                                        (AnyValue::UInt128(x), AnyValue::UInt128(y)) => {
+                                            Some(AExpr::Literal(
+                                                <Scalar as From<u128>>::from(x / y).into(),
+                                            ))
+                                        },
 
                                         _ => None,
                                     }
@@ -617,6 +626,11 @@ impl OptimizationRule for SimplifyExprRule {
                                                 Scalar::from(x as f64 / y as f64).into(),
                                             ))
                                         },
+                                        (AnyValue::UInt128(x), AnyValue::UInt128(y)) => {
+                                            Some(AExpr::Literal(
+                                                Scalar::from(x as f64 / y as f64).into(),
+                                            ))
+                                        },
 
                                         _ => None,
                                     }
@@ -115,6 +115,22 @@ fn can_pushdown_slice_past_projections(
     (true, can_pushdown_and_any_expr_has_column)
 }
 
+/// A left join whose right keys are validated to be unique produces exactly one row for every
+/// row of the left input, so a slice of the join output is a slice of the left input.
+fn can_pushdown_slice_past_join(options: &JoinOptionsIR) -> bool {
+    let args = &options.args;
+    options.options.is_none()
+        && args.slice.is_none()
+        && matches!(args.how, JoinType::Left)
+        // A `OneToOne` validation also checks the left keys, which we would then only partially
+        // validate.
+        && matches!(args.validation, JoinValidation::ManyToOne)
+        && matches!(
+            args.maintain_order,
+            MaintainOrderJoin::None | MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight
+        )
+}
+
 impl SlicePushDown {
     // slice will be done at this node if we found any
     // we also stop optimization
@@ -264,17 +280,46 @@ impl SlicePushDown {
                 Ok(lp)
             }
             (Union {mut inputs, mut options }, Some(state)) => {
-                if state.offset == 0 {
-                    for input in &mut inputs {
-                        let input_lp = lp_arena.take(*input);
-                        let input_lp = self.pushdown(input_lp, Some(state), lp_arena, expr_arena)?;
-                        lp_arena.replace(*input, input_lp);
-                    }
+                // No input has to produce more than the rows up to the end of the slice. The
+                // union itself applies the residual slice.
+                let input_state = (state.offset >= 0).then(|| State {
+                    offset: 0,
+                    len: (state.offset as IdxSize).saturating_add(state.len),
+                });
+                for input in &mut inputs {
+                    let input_lp = lp_arena.take(*input);
+                    let input_lp = self.pushdown(input_lp, input_state, lp_arena, expr_arena)?;
+                    lp_arena.replace(*input, input_lp);
                 }
                 options.slice = Some((state.offset, state.len as usize));
                 let lp = Union {inputs, options};
                 Ok(lp)
             },
+            (Join {
+                input_left,
+                input_right,
+                schema,
+                left_on,
+                right_on,
+                options
+            }, Some(state)) if can_pushdown_slice_past_join(&options) => {
+                let lp_left = lp_arena.take(input_left);
+                let lp_left = self.pushdown(lp_left, Some(state), lp_arena, expr_arena)?;
+                lp_arena.replace(input_left, lp_left);
+
+                let lp_right = lp_arena.take(input_right);
+                let lp_right = self.pushdown(lp_right, None, lp_arena, expr_arena)?;
+                lp_arena.replace(input_right, lp_right);
+
+                Ok(Join {
+                    input_left,
+                    input_right,
+                    schema,
+                    left_on,
+                    right_on,
+                    options
+                })
+            }
             (Join {
                 input_left,
                 input_right,
@@ -233,6 +233,14 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Int128"))?;
                 class.call0()
             },
+            DataType::UInt128 => {
+                let class = pl.getattr(intern!(py, "UInt128"))?;
+                class.call0()
+            },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16"))?;
+                class.call0()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32"))?;
                 class.call0()
@@ -269,6 +277,12 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let inner = Wrap(*inner.clone());
                 class.call1((&inner,))
             },
+            DataType::Map(key, value) => {
+                let class = pl.getattr(intern!(py, "Map"))?;
+                let key = Wrap(*key.clone());
+                let value = Wrap(*value.clone());
+                class.call1((&key, &value))
+            },
             DataType::Date => {
                 let class = pl.getattr(intern!(py, "Date"))?;
                 class.call0()
@@ -281,6 +295,10 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let duration_class = pl.getattr(intern!(py, "Duration"))?;
                 duration_class.call1((tu.to_ascii(),))
             },
+            DataType::Interval => {
+                let class = pl.getattr(intern!(py, "Interval"))?;
+                class.call0()
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 let class = pl.getattr(intern!(py, "Object"))?;
@@ -371,6 +389,8 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "UInt128" => DataType::UInt128,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -382,9 +402,11 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Time" => DataType::Time,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
+                    "Interval" => DataType::Interval,
                     "Decimal" => DataType::Decimal(None, None), // "none" scale => "infer"
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
+                    "Map" => DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null)),
                     "Struct" => DataType::Struct(vec![]),
                     "Null" => DataType::Null,
                     #[cfg(feature = "object")]
@@ -406,6 +428,8 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "UInt128" => DataType::UInt128,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
@@ -444,6 +468,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let time_unit = time_unit.extract::<Wrap<TimeUnit>>()?.0;
                 DataType::Duration(time_unit)
             },
+            "Interval" => DataType::Interval,
             "Decimal" => {
                 let precision = ob.getattr(intern!(py, "precision"))?.extract()?;
                 let scale = ob.getattr(intern!(py, "scale"))?.extract()?;
@@ -461,6 +486,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let size = size.extract::<usize>()?;
                 DataType::Array(Box::new(inner.0), size)
             },
+            "Map" => {
+                let key = ob.getattr(intern!(py, "key")).unwrap();
+                let value = ob.getattr(intern!(py, "value")).unwrap();
+                let key = key.extract::<Wrap<DataType>>()?;
+                let value = value.extract::<Wrap<DataType>>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0))
+            },
             "Struct" => {
                 let fields = ob.getattr(intern!(py, "fields"))?;
                 let fields = fields
@@ -217,6 +217,11 @@ pub fn concat_arr(s: Vec<PyExpr>) -> PyResult<PyExpr> {
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn map_from_lists(keys: PyExpr, values: PyExpr) -> PyExpr {
+    dsl::map_from_lists(keys.inner, values.inner).into()
+}
+
 #[pyfunction]
 pub fn concat_str(s: Vec<PyExpr>, separator: &str, ignore_nulls: bool) -> PyExpr {
     let s = s.into_iter().map(|e| e.inner).collect::<Vec<_>>();
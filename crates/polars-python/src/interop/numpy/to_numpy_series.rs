@@ -191,7 +191,7 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
         Int16 => numeric_series_to_numpy::<Int16Type, f32>(py, s),
         Int32 => numeric_series_to_numpy::<Int32Type, f64>(py, s),
         Int64 => numeric_series_to_numpy::<Int64Type, f64>(py, s),
-        Int128 => {
+        Int128 | UInt128 => {
             let s = s.cast(&DataType::Float64).unwrap();
             series_to_numpy(py, &s, writable, true).unwrap()
         },
@@ -199,6 +199,10 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
         UInt16 => numeric_series_to_numpy::<UInt16Type, f32>(py, s),
         UInt32 => numeric_series_to_numpy::<UInt32Type, f64>(py, s),
         UInt64 => numeric_series_to_numpy::<UInt64Type, f64>(py, s),
+        Float16 => {
+            let s = s.cast(&DataType::Float32).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
         Boolean => boolean_series_to_numpy(py, s),
@@ -253,6 +257,16 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
                 PyArray1::from_iter(py, values).into_py_any(py).unwrap()
             })
         },
+        Interval => {
+            let ca = s.interval().unwrap();
+            let values = ca.physical().iter().map(|v| {
+                v.map(i128_to_interval)
+                    .map(|v| (v.months(), v.days(), v.ns()))
+                    .into_py_any(py)
+                    .unwrap()
+            });
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         Decimal(_, _) => {
             let ca = s.decimal().unwrap();
             let values = decimal_to_pyobject_iter(py, ca)
@@ -261,6 +275,7 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
         List(_) => list_series_to_numpy(py, s, writable),
+        Map(_, _) => list_series_to_numpy(py, &s.to_physical_repr(), writable),
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
             let ca = s.struct_().unwrap();
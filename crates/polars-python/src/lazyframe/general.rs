@@ -495,6 +495,10 @@ impl PyLazyFrame {
         py.enter_polars(|| self.ldf.describe_optimized_plan())
     }
 
+    fn describe_optimized_plan_with_statistics(&self, py: Python) -> PyResult<String> {
+        py.enter_polars(|| self.ldf.describe_optimized_plan_with_statistics())
+    }
+
     fn describe_plan_tree(&self, py: Python) -> PyResult<String> {
         py.enter_polars(|| self.ldf.describe_plan_tree())
     }
@@ -759,6 +759,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
+                IRFunctionExpr::MapExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("map expr"));
+                },
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
@@ -27,6 +27,7 @@ impl PyPolarsNumericType for UInt8Type {}
 impl PyPolarsNumericType for UInt16Type {}
 impl PyPolarsNumericType for UInt32Type {}
 impl PyPolarsNumericType for UInt64Type {}
+impl PyPolarsNumericType for UInt128Type {}
 impl PyPolarsNumericType for Int8Type {}
 impl PyPolarsNumericType for Int16Type {}
 impl PyPolarsNumericType for Int32Type {}
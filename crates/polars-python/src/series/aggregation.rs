@@ -57,7 +57,7 @@ impl PySeries {
                 py,
             ),
             // For non-numeric output types we require mean_reduce.
-            dt if dt.is_temporal() => {
+            dt if dt.is_temporal() || dt.is_decimal() => {
                 scalar_to_py(py.enter_polars_ok(|| self.series.mean_reduce()), py)
             },
             _ => Ok(self.series.mean().into_pyobject(py)?),
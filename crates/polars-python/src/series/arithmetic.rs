@@ -38,54 +38,64 @@ impl_arithmetic!(add_u8, u8, +);
 impl_arithmetic!(add_u16, u16, +);
 impl_arithmetic!(add_u32, u32, +);
 impl_arithmetic!(add_u64, u64, +);
+impl_arithmetic!(add_u128, u128, +);
 impl_arithmetic!(add_i8, i8, +);
 impl_arithmetic!(add_i16, i16, +);
 impl_arithmetic!(add_i32, i32, +);
 impl_arithmetic!(add_i64, i64, +);
 impl_arithmetic!(add_datetime, i64, +);
 impl_arithmetic!(add_duration, i64, +);
+impl_arithmetic!(add_f16, f32, +);
 impl_arithmetic!(add_f32, f32, +);
 impl_arithmetic!(add_f64, f64, +);
 impl_arithmetic!(sub_u8, u8, -);
 impl_arithmetic!(sub_u16, u16, -);
 impl_arithmetic!(sub_u32, u32, -);
 impl_arithmetic!(sub_u64, u64, -);
+impl_arithmetic!(sub_u128, u128, -);
 impl_arithmetic!(sub_i8, i8, -);
 impl_arithmetic!(sub_i16, i16, -);
 impl_arithmetic!(sub_i32, i32, -);
 impl_arithmetic!(sub_i64, i64, -);
 impl_arithmetic!(sub_datetime, i64, -);
 impl_arithmetic!(sub_duration, i64, -);
+impl_arithmetic!(sub_f16, f32, -);
 impl_arithmetic!(sub_f32, f32, -);
 impl_arithmetic!(sub_f64, f64, -);
 impl_arithmetic!(div_u8, u8, /);
 impl_arithmetic!(div_u16, u16, /);
 impl_arithmetic!(div_u32, u32, /);
 impl_arithmetic!(div_u64, u64, /);
+impl_arithmetic!(div_u128, u128, /);
 impl_arithmetic!(div_i8, i8, /);
 impl_arithmetic!(div_i16, i16, /);
 impl_arithmetic!(div_i32, i32, /);
 impl_arithmetic!(div_i64, i64, /);
+impl_arithmetic!(div_f16, f32, /);
 impl_arithmetic!(div_f32, f32, /);
 impl_arithmetic!(div_f64, f64, /);
 impl_arithmetic!(mul_u8, u8, *);
 impl_arithmetic!(mul_u16, u16, *);
 impl_arithmetic!(mul_u32, u32, *);
 impl_arithmetic!(mul_u64, u64, *);
+impl_arithmetic!(mul_u128, u128, *);
 impl_arithmetic!(mul_i8, i8, *);
 impl_arithmetic!(mul_i16, i16, *);
 impl_arithmetic!(mul_i32, i32, *);
 impl_arithmetic!(mul_i64, i64, *);
+impl_arithmetic!(mul_f16, f32, *);
 impl_arithmetic!(mul_f32, f32, *);
 impl_arithmetic!(mul_f64, f64, *);
 impl_arithmetic!(rem_u8, u8, %);
 impl_arithmetic!(rem_u16, u16, %);
 impl_arithmetic!(rem_u32, u32, %);
 impl_arithmetic!(rem_u64, u64, %);
+impl_arithmetic!(rem_u128, u128, %);
 impl_arithmetic!(rem_i8, i8, %);
 impl_arithmetic!(rem_i16, i16, %);
 impl_arithmetic!(rem_i32, i32, %);
 impl_arithmetic!(rem_i64, i64, %);
+impl_arithmetic!(rem_f16, f32, %);
 impl_arithmetic!(rem_f32, f32, %);
 impl_arithmetic!(rem_f64, f64, %);
 
@@ -104,49 +114,59 @@ impl_rhs_arithmetic!(add_u8_rhs, u8, add);
 impl_rhs_arithmetic!(add_u16_rhs, u16, add);
 impl_rhs_arithmetic!(add_u32_rhs, u32, add);
 impl_rhs_arithmetic!(add_u64_rhs, u64, add);
+impl_rhs_arithmetic!(add_u128_rhs, u128, add);
 impl_rhs_arithmetic!(add_i8_rhs, i8, add);
 impl_rhs_arithmetic!(add_i16_rhs, i16, add);
 impl_rhs_arithmetic!(add_i32_rhs, i32, add);
 impl_rhs_arithmetic!(add_i64_rhs, i64, add);
+impl_rhs_arithmetic!(add_f16_rhs, f32, add);
 impl_rhs_arithmetic!(add_f32_rhs, f32, add);
 impl_rhs_arithmetic!(add_f64_rhs, f64, add);
 impl_rhs_arithmetic!(sub_u8_rhs, u8, sub);
 impl_rhs_arithmetic!(sub_u16_rhs, u16, sub);
 impl_rhs_arithmetic!(sub_u32_rhs, u32, sub);
 impl_rhs_arithmetic!(sub_u64_rhs, u64, sub);
+impl_rhs_arithmetic!(sub_u128_rhs, u128, sub);
 impl_rhs_arithmetic!(sub_i8_rhs, i8, sub);
 impl_rhs_arithmetic!(sub_i16_rhs, i16, sub);
 impl_rhs_arithmetic!(sub_i32_rhs, i32, sub);
 impl_rhs_arithmetic!(sub_i64_rhs, i64, sub);
+impl_rhs_arithmetic!(sub_f16_rhs, f32, sub);
 impl_rhs_arithmetic!(sub_f32_rhs, f32, sub);
 impl_rhs_arithmetic!(sub_f64_rhs, f64, sub);
 impl_rhs_arithmetic!(div_u8_rhs, u8, div);
 impl_rhs_arithmetic!(div_u16_rhs, u16, div);
 impl_rhs_arithmetic!(div_u32_rhs, u32, div);
 impl_rhs_arithmetic!(div_u64_rhs, u64, div);
+impl_rhs_arithmetic!(div_u128_rhs, u128, div);
 impl_rhs_arithmetic!(div_i8_rhs, i8, div);
 impl_rhs_arithmetic!(div_i16_rhs, i16, div);
 impl_rhs_arithmetic!(div_i32_rhs, i32, div);
 impl_rhs_arithmetic!(div_i64_rhs, i64, div);
+impl_rhs_arithmetic!(div_f16_rhs, f32, div);
 impl_rhs_arithmetic!(div_f32_rhs, f32, div);
 impl_rhs_arithmetic!(div_f64_rhs, f64, div);
 impl_rhs_arithmetic!(mul_u8_rhs, u8, mul);
 impl_rhs_arithmetic!(mul_u16_rhs, u16, mul);
 impl_rhs_arithmetic!(mul_u32_rhs, u32, mul);
 impl_rhs_arithmetic!(mul_u64_rhs, u64, mul);
+impl_rhs_arithmetic!(mul_u128_rhs, u128, mul);
 impl_rhs_arithmetic!(mul_i8_rhs, i8, mul);
 impl_rhs_arithmetic!(mul_i16_rhs, i16, mul);
 impl_rhs_arithmetic!(mul_i32_rhs, i32, mul);
 impl_rhs_arithmetic!(mul_i64_rhs, i64, mul);
+impl_rhs_arithmetic!(mul_f16_rhs, f32, mul);
 impl_rhs_arithmetic!(mul_f32_rhs, f32, mul);
 impl_rhs_arithmetic!(mul_f64_rhs, f64, mul);
 impl_rhs_arithmetic!(rem_u8_rhs, u8, rem);
 impl_rhs_arithmetic!(rem_u16_rhs, u16, rem);
 impl_rhs_arithmetic!(rem_u32_rhs, u32, rem);
 impl_rhs_arithmetic!(rem_u64_rhs, u64, rem);
+impl_rhs_arithmetic!(rem_u128_rhs, u128, rem);
 impl_rhs_arithmetic!(rem_i8_rhs, i8, rem);
 impl_rhs_arithmetic!(rem_i16_rhs, i16, rem);
 impl_rhs_arithmetic!(rem_i32_rhs, i32, rem);
 impl_rhs_arithmetic!(rem_i64_rhs, i64, rem);
+impl_rhs_arithmetic!(rem_f16_rhs, f32, rem);
 impl_rhs_arithmetic!(rem_f32_rhs, f32, rem);
 impl_rhs_arithmetic!(rem_f64_rhs, f64, rem);
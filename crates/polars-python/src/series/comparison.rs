@@ -47,11 +47,13 @@ impl_op!(equal, eq_u8, u8);
 impl_op!(equal, eq_u16, u16);
 impl_op!(equal, eq_u32, u32);
 impl_op!(equal, eq_u64, u64);
+impl_op!(equal, eq_u128, u128);
 impl_op!(equal, eq_i8, i8);
 impl_op!(equal, eq_i16, i16);
 impl_op!(equal, eq_i32, i32);
 impl_op!(equal, eq_i64, i64);
 impl_op!(equal, eq_i128, i128);
+impl_op!(equal, eq_f16, f32);
 impl_op!(equal, eq_f32, f32);
 impl_op!(equal, eq_f64, f64);
 impl_op!(equal, eq_str, &str);
@@ -60,11 +62,13 @@ impl_op!(not_equal, neq_u8, u8);
 impl_op!(not_equal, neq_u16, u16);
 impl_op!(not_equal, neq_u32, u32);
 impl_op!(not_equal, neq_u64, u64);
+impl_op!(not_equal, neq_u128, u128);
 impl_op!(not_equal, neq_i8, i8);
 impl_op!(not_equal, neq_i16, i16);
 impl_op!(not_equal, neq_i32, i32);
 impl_op!(not_equal, neq_i64, i64);
 impl_op!(not_equal, neq_i128, i128);
+impl_op!(not_equal, neq_f16, f32);
 impl_op!(not_equal, neq_f32, f32);
 impl_op!(not_equal, neq_f64, f64);
 impl_op!(not_equal, neq_str, &str);
@@ -73,11 +77,13 @@ impl_op!(gt, gt_u8, u8);
 impl_op!(gt, gt_u16, u16);
 impl_op!(gt, gt_u32, u32);
 impl_op!(gt, gt_u64, u64);
+impl_op!(gt, gt_u128, u128);
 impl_op!(gt, gt_i8, i8);
 impl_op!(gt, gt_i16, i16);
 impl_op!(gt, gt_i32, i32);
 impl_op!(gt, gt_i64, i64);
 impl_op!(gt, gt_i128, i128);
+impl_op!(gt, gt_f16, f32);
 impl_op!(gt, gt_f32, f32);
 impl_op!(gt, gt_f64, f64);
 impl_op!(gt, gt_str, &str);
@@ -86,11 +92,13 @@ impl_op!(gt_eq, gt_eq_u8, u8);
 impl_op!(gt_eq, gt_eq_u16, u16);
 impl_op!(gt_eq, gt_eq_u32, u32);
 impl_op!(gt_eq, gt_eq_u64, u64);
+impl_op!(gt_eq, gt_eq_u128, u128);
 impl_op!(gt_eq, gt_eq_i8, i8);
 impl_op!(gt_eq, gt_eq_i16, i16);
 impl_op!(gt_eq, gt_eq_i32, i32);
 impl_op!(gt_eq, gt_eq_i64, i64);
 impl_op!(gt_eq, gt_eq_i128, i128);
+impl_op!(gt_eq, gt_eq_f16, f32);
 impl_op!(gt_eq, gt_eq_f32, f32);
 impl_op!(gt_eq, gt_eq_f64, f64);
 impl_op!(gt_eq, gt_eq_str, &str);
@@ -99,11 +107,13 @@ impl_op!(lt, lt_u8, u8);
 impl_op!(lt, lt_u16, u16);
 impl_op!(lt, lt_u32, u32);
 impl_op!(lt, lt_u64, u64);
+impl_op!(lt, lt_u128, u128);
 impl_op!(lt, lt_i8, i8);
 impl_op!(lt, lt_i16, i16);
 impl_op!(lt, lt_i32, i32);
 impl_op!(lt, lt_i64, i64);
 impl_op!(lt, lt_i128, i128);
+impl_op!(lt, lt_f16, f32);
 impl_op!(lt, lt_f32, f32);
 impl_op!(lt, lt_f64, f64);
 impl_op!(lt, lt_str, &str);
@@ -112,11 +122,13 @@ impl_op!(lt_eq, lt_eq_u8, u8);
 impl_op!(lt_eq, lt_eq_u16, u16);
 impl_op!(lt_eq, lt_eq_u32, u32);
 impl_op!(lt_eq, lt_eq_u64, u64);
+impl_op!(lt_eq, lt_eq_u128, u128);
 impl_op!(lt_eq, lt_eq_i8, i8);
 impl_op!(lt_eq, lt_eq_i16, i16);
 impl_op!(lt_eq, lt_eq_i32, i32);
 impl_op!(lt_eq, lt_eq_i64, i64);
 impl_op!(lt_eq, lt_eq_i128, i128);
+impl_op!(lt_eq, lt_eq_f16, f32);
 impl_op!(lt_eq, lt_eq_f32, f32);
 impl_op!(lt_eq, lt_eq_f64, f64);
 impl_op!(lt_eq, lt_eq_str, &str);
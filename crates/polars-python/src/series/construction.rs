@@ -174,6 +174,7 @@ init_method_opt!(new_opt_u8, UInt8Type, u8);
 init_method_opt!(new_opt_u16, UInt16Type, u16);
 init_method_opt!(new_opt_u32, UInt32Type, u32);
 init_method_opt!(new_opt_u64, UInt64Type, u64);
+init_method_opt!(new_opt_u128, UInt128Type, u128);
 init_method_opt!(new_opt_i8, Int8Type, i8);
 init_method_opt!(new_opt_i16, Int16Type, i16);
 init_method_opt!(new_opt_i32, Int32Type, i32);
@@ -182,6 +183,42 @@ init_method_opt!(new_opt_i128, Int128Type, i64);
 init_method_opt!(new_opt_f32, Float32Type, f32);
 init_method_opt!(new_opt_f64, Float64Type, f64);
 
+#[pymethods]
+impl PySeries {
+    #[staticmethod]
+    fn new_opt_f16(name: &str, obj: &Bound<PyAny>, _strict: bool) -> PyResult<Self> {
+        // Python has no half-precision float, so values are extracted as f64 and narrowed.
+        let len = obj.len()?;
+        let mut builder = PrimitiveChunkedBuilder::<Float16Type>::new(name.into(), len);
+        for res in obj.try_iter()? {
+            let value = res?;
+            if value.is_none() {
+                builder.append_null()
+            } else {
+                builder.append_value(f16::from_f64(value.extract::<f64>()?))
+            }
+        }
+        Ok(builder.finish().into_series().into())
+    }
+
+    #[staticmethod]
+    fn new_interval(name: &str, obj: &Bound<PyAny>, _strict: bool) -> PyResult<Self> {
+        // Intervals are passed as `(months, days, nanoseconds)` tuples.
+        let len = obj.len()?;
+        let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(name.into(), len);
+        for res in obj.try_iter()? {
+            let value = res?;
+            if value.is_none() {
+                builder.append_null()
+            } else {
+                let (months, days, ns) = value.extract::<(i32, i32, i64)>()?;
+                builder.append_value(interval_to_i128(months_days_ns::new(months, days, ns)))
+            }
+        }
+        Ok(builder.finish().into_interval().into_series().into())
+    }
+}
+
 fn convert_to_avs(
     values: &Bound<'_, PyAny>,
     strict: bool,
@@ -29,6 +29,15 @@ impl PySeries {
                 DataType::Int32 => PyList::new(py, series.i32().map_err(PyPolarsErr::from)?)?,
                 DataType::Int64 => PyList::new(py, series.i64().map_err(PyPolarsErr::from)?)?,
                 DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
+                DataType::UInt128 => PyList::new(py, series.u128().map_err(PyPolarsErr::from)?)?,
+                DataType::Float16 => PyList::new(
+                    py,
+                    series
+                        .f16()
+                        .map_err(PyPolarsErr::from)?
+                        .iter()
+                        .map(|v| v.map(|v| v.to_f32())),
+                )?,
                 DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
                 DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
                 DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -77,6 +86,10 @@ impl PySeries {
                     }
                     v
                 },
+                DataType::Map(_, _) => {
+                    let ca = series.map().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &ca.physical().clone().into_series());
+                },
                 DataType::Date => {
                     let ca = series.date().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
@@ -109,6 +122,16 @@ impl PySeries {
                     let ca = series.binary().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Interval => {
+                    let ca = series.interval().map_err(PyPolarsErr::from)?;
+                    PyList::new(
+                        py,
+                        ca.physical().iter().map(|v| {
+                            v.map(i128_to_interval)
+                                .map(|v| (v.months(), v.days(), v.ns()))
+                        }),
+                    )?
+                },
                 DataType::Null => {
                     let null: Option<u8> = None;
                     let n = series.len();
@@ -75,6 +75,7 @@ impl PySeries {
                     | DataType::Binary
                     | DataType::Array(_, _)
                     | DataType::Time
+                    | DataType::Interval
                     | DataType::Decimal(_, _)
             ) || !skip_nulls
             {
@@ -160,6 +161,17 @@ impl PySeries {
                     )?;
                     ca.into_series()
                 },
+                Some(DataType::UInt128) => {
+                    let ca: UInt128Chunked = dispatch_apply!(
+                        series,
+                        apply_lambda_with_primitive_out_type,
+                        py,
+                        function,
+                        0,
+                        None
+                    )?;
+                    ca.into_series()
+                },
                 Some(DataType::UInt8) => {
                     let ca: UInt8Chunked = dispatch_apply!(
                         series,
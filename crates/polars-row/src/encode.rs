@@ -7,7 +7,7 @@ use arrow::array::{
 };
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
-use arrow::types::{NativeType, Offset};
+use arrow::types::{NativeType, Offset, f16};
 use polars_dtype::categorical::CatNative;
 
 use crate::fixed::numeric::FixedLengthEncoding;
@@ -947,6 +947,7 @@ pub fn fixed_size(
         D::UInt16 => u16::ENCODED_LEN,
         D::UInt32 => u32::ENCODED_LEN,
         D::UInt64 => u64::ENCODED_LEN,
+        D::UInt128 => u128::ENCODED_LEN,
 
         D::Int8 => i8::ENCODED_LEN,
         D::Int16 => i16::ENCODED_LEN,
@@ -958,6 +959,7 @@ pub fn fixed_size(
             _ => unreachable!(),
         },
 
+        D::Float16 => f16::ENCODED_LEN,
         D::Float32 => f32::ENCODED_LEN,
         D::Float64 => f64::ENCODED_LEN,
         D::FixedSizeList(f, width) => 1 + width * fixed_size(f.dtype(), opt, dict)?,
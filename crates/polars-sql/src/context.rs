@@ -7,22 +7,32 @@ use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
+use polars_plan::utils::{expr_output_name, expr_to_leaf_column_names_iter};
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    Assignment, AssignmentTarget, BinaryOperator, ColumnOption, CreateTable, Cte, Delete, Distinct,
+    ExcludeSelectItem, Expr as SQLExpr, ExprWithAlias, FromTable, FunctionArg, FunctionArgExpr,
+    GroupByExpr, GroupByWithModifier, Ident, Insert, JoinConstraint, JoinOperator, MergeAction,
+    MergeClauseKind, MergeInsertKind, NamedWindowDefinition, NamedWindowExpr, ObjectName,
+    ObjectType, Offset, OneOrManyWithParens, OrderBy, PivotValueSource, Query, RenameSelectItem,
+    Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias, TableFactor,
+    TableWithJoins, UnaryOperator, Value as SQLValue, Values, WildcardAdditionalOptions,
+    WindowSpec, WindowType,
 };
-use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::{Token, Tokenizer};
 
+use crate::dialect::{IdentifierCase, SQLDialect};
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::params::{SQLParams, SQLPreparedStatement};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
+use crate::subqueries::{
+    SUBQUERY_COLUMN_PREFIX, any_identifier, relation_names, split_conjunction,
+};
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::{map_polars_dtype_to_sql, map_sql_dtype_to_polars};
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -52,6 +62,24 @@ impl SelectModifiers {
     }
 }
 
+/// The definition of a view, which is planned anew whenever the view is read, so that it
+/// reflects later changes to the tables it reads from.
+#[derive(Clone)]
+struct ViewDefinition {
+    query: Query,
+    /// The column names given in `CREATE VIEW <view> (<col>, ...)`, if any.
+    columns: Vec<PlSmallStr>,
+}
+
+/// Prefix of the columns that tell whether a row aggregates over a GROUP BY key, see `GROUPING()`.
+pub(crate) const GROUPING_INDICATOR_PREFIX: &str = "__POLARS_GROUPING_";
+
+/// The `table_schema` of the registered tables, as reported by the `information_schema`.
+const INFORMATION_SCHEMA_TABLE_SCHEMA: &str = "main";
+
+/// Default maximum number of times the recursive term of a recursive CTE is evaluated.
+const DEFAULT_RECURSIVE_CTE_MAX_ITERATIONS: usize = 10_000;
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
@@ -59,6 +87,25 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
+    pub(crate) recursive_cte_max_iterations: usize,
+    /// Columns that exist on both sides of the join whose ON predicate is being translated;
+    /// unqualified references to them are ambiguous.
+    pub(crate) ambiguous_columns: PlHashSet<String>,
+    /// Columns holding the results of the scalar subqueries of the current statement.
+    pub(crate) subquery_columns: RefCell<PlHashMap<Query, PlSmallStr>>,
+    /// The windows defined in the WINDOW clause of the current SELECT.
+    pub(crate) named_windows: PlHashMap<String, WindowSpec>,
+    /// The values of the bind parameters of the prepared statement being executed.
+    pub(crate) params: SQLParams,
+    /// The session time zone, of `TIMESTAMP WITH TIME ZONE` values.
+    pub(crate) time_zone: TimeZone,
+    /// The SQL dialect of the executed queries.
+    pub(crate) dialect: SQLDialect,
+
+    /// The registered tables that are views, see `CREATE VIEW`.
+    views: PlHashMap<String, ViewDefinition>,
+    /// The NOT NULL columns of the tables created from column definitions.
+    not_null_columns: PlHashMap<String, PlHashSet<PlSmallStr>>,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
@@ -70,11 +117,20 @@ impl Default for SQLContext {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
             table_map: Default::default(),
+            views: Default::default(),
+            not_null_columns: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            recursive_cte_max_iterations: DEFAULT_RECURSIVE_CTE_MAX_ITERATIONS,
+            ambiguous_columns: Default::default(),
+            subquery_columns: Default::default(),
+            named_windows: Default::default(),
+            params: Default::default(),
+            time_zone: TimeZone::UTC,
+            dialect: SQLDialect::default(),
         }
     }
 }
@@ -114,11 +170,15 @@ impl SQLContext {
     /// # }
     ///```
     pub fn register(&mut self, name: &str, lf: LazyFrame) {
+        self.views.remove(name);
+        self.not_null_columns.remove(name);
         self.table_map.insert(name.to_owned(), lf);
     }
 
     /// Unregister a [`LazyFrame`] table from the [`SQLContext`].
     pub fn unregister(&mut self, name: &str) {
+        self.views.remove(name);
+        self.not_null_columns.remove(name);
         self.table_map.remove(&name.to_owned());
     }
 
@@ -141,20 +201,54 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
+        let stmt = parse_statement(query, self.dialect, |words| self.known_names(words))?;
+        self.execute_parsed(&stmt)
+    }
+
+    /// Prepare a SQL statement containing bind parameters (`$1`, `?`, `:name`), for repeated
+    /// execution with [`SQLContext::execute_prepared`].
+    ///
+    /// This parses the statement once; it is still planned on every execution.
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLParams};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let stmt = ctx.prepare("SELECT * FROM df WHERE a > $1").unwrap();
+    /// let params = SQLParams::new().bind(1);
+    /// let sql_df = ctx.execute_prepared(&stmt, &params).unwrap().collect().unwrap();
+    /// assert_eq!(sql_df.height(), 2);
+    /// # }
+    ///```
+    pub fn prepare(&self, query: &str) -> PolarsResult<SQLPreparedStatement> {
+        Ok(SQLPreparedStatement {
+            statement: parse_statement(query, self.dialect, |words| self.known_names(words))?,
+        })
+    }
 
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
+    /// Execute a prepared SQL statement, with the given values for its bind parameters,
+    /// returning a [`LazyFrame`].
+    pub fn execute_prepared(
+        &mut self,
+        stmt: &SQLPreparedStatement,
+        params: &SQLParams,
+    ) -> PolarsResult<LazyFrame> {
+        self.params = params.clone();
+        let res = self.execute_parsed(&stmt.statement);
+        self.params = SQLParams::default();
+        res
+    }
 
-        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+    fn execute_parsed(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let res = self.execute_statement(stmt)?;
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
@@ -166,10 +260,40 @@ impl SQLContext {
         self.cte_map.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
+        self.subquery_columns.borrow_mut().clear();
+        self.named_windows.clear();
 
         Ok(res)
     }
 
+    /// Set the SQL dialect of the executed queries (the generic dialect by default).
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLDialect};
+    /// # fn main() {
+    /// let ctx = SQLContext::new().with_dialect(SQLDialect::PostgreSQL);
+    /// # }
+    /// ```
+    pub fn with_dialect(mut self, dialect: SQLDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// The names of the registered tables that a statement may reference, and of their columns,
+    /// to which case-insensitive identifiers resolve. Only the tables whose name is among the
+    /// (lowercased) `words` of the statement are considered.
+    fn known_names(&self, words: &PlHashSet<String>) -> PolarsResult<PlHashSet<String>> {
+        let mut names = PlHashSet::new();
+        for (tbl_name, lf) in &self.table_map {
+            if !words.contains(&tbl_name.to_lowercase()) {
+                continue;
+            }
+            let schema = lf.clone().collect_schema()?;
+            names.insert(tbl_name.clone());
+            names.extend(schema.iter_names().map(|name| name.to_string()));
+        }
+        Ok(names)
+    }
+
     /// add a function registry to the SQLContext
     /// the registry provides the ability to add custom functions to the SQLContext
     pub fn with_function_registry(mut self, function_registry: Arc<dyn FunctionRegistry>) -> Self {
@@ -177,6 +301,20 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of times the recursive term of a `WITH RECURSIVE` CTE is
+    /// evaluated before the query is considered non-terminating (10,000 by default).
+    pub fn with_recursive_cte_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.recursive_cte_max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the session time zone (UTC by default); `TIMESTAMP WITH TIME ZONE` values are
+    /// in this time zone, and naive timestamps are interpreted as local times in it.
+    pub fn with_time_zone(mut self, time_zone: &str) -> PolarsResult<Self> {
+        self.time_zone = parse_time_zone(time_zone)?;
+        Ok(self)
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -195,13 +333,27 @@ impl SQLContext {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            stmt @ Statement::CreateView { .. } => self.execute_create_view(stmt)?,
             stmt @ Statement::Drop {
-                object_type: ObjectType::Table,
+                object_type: ObjectType::Table | ObjectType::View,
                 ..
             } => self.execute_drop_table(stmt)?,
+            Statement::ExplainTable { table_name, .. } => self.execute_describe(table_name)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert_into_table(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update_table(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge_into_table(stmt)?,
+            Statement::SetTimeZone { value, .. } => self.execute_set_time_zone(value)?,
+            Statement::SetVariable {
+                variables: OneOrManyWithParens::One(name),
+                value,
+                ..
+            } if name.to_string().eq_ignore_ascii_case("timezone") => match value.as_slice() {
+                [value] => self.execute_set_time_zone(value)?,
+                _ => polars_bail!(SQLSyntax: "SET TIMEZONE expects a single value"),
+            },
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -227,10 +379,12 @@ impl SQLContext {
         table
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .cloned()
+                        .or_else(|| self.cte_map.borrow().get(alias).cloned())
+                })
             })
     }
 
@@ -483,19 +637,56 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
-    // DROP TABLE <tbl>
+    // DESCRIBE <tbl>
+    fn execute_describe(&mut self, name: &ObjectName) -> PolarsResult<LazyFrame> {
+        let tbl_name = name.0.last().unwrap().value.clone();
+        if !self.table_map.contains_key(&tbl_name) {
+            polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name)
+        }
+        Ok(self
+            .get_information_schema_table("columns")?
+            .filter(col("table_name").eq(lit(tbl_name)))
+            .select([col("column_name"), col("data_type"), col("is_nullable")]))
+    }
+
+    // DROP TABLE <tbl> | DROP VIEW <view>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Drop { names, .. } => {
-                names.iter().for_each(|name| {
-                    self.table_map.remove(&name.to_string());
-                });
+            Statement::Drop {
+                names, object_type, ..
+            } => {
+                for name in names {
+                    let name = name.to_string();
+                    let is_view = self.views.contains_key(&name);
+                    match object_type {
+                        ObjectType::View if !is_view && self.table_map.contains_key(&name) => {
+                            polars_bail!(SQLInterface: "'{}' is not a view; use DROP TABLE", name)
+                        },
+                        ObjectType::Table if is_view => {
+                            polars_bail!(SQLInterface: "'{}' is a view; use DROP VIEW", name)
+                        },
+                        _ => self.unregister(&name),
+                    }
+                }
                 Ok(DataFrame::empty().lazy())
             },
             _ => polars_bail!(SQLInterface: "unexpected statement type; expected DROP"),
         }
     }
 
+    // SET TIME ZONE <tz>
+    fn execute_set_time_zone(&mut self, value: &SQLExpr) -> PolarsResult<LazyFrame> {
+        self.time_zone = match value {
+            SQLExpr::Value(SQLValue::SingleQuotedString(tz)) => parse_time_zone(tz)?,
+            SQLExpr::Identifier(ident) if ident.value.eq_ignore_ascii_case("default") => {
+                TimeZone::UTC
+            },
+            SQLExpr::Identifier(ident) => parse_time_zone(&ident.value)?,
+            _ => polars_bail!(SQLSyntax: "SET TIME ZONE expects a time zone name; found {}", value),
+        };
+        Ok(DataFrame::empty().lazy())
+    }
+
     // DELETE FROM <tbl> [WHERE ...]
     fn execute_delete_from_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::Delete(Delete {
@@ -567,6 +758,9 @@ impl SQLContext {
                         polars_bail!(SQLInterface: "TRUNCATE expects exactly one table name; found {}", table_names.len())
                     }
                     let tbl = table_names[0].to_string();
+                    if self.views.contains_key(&tbl) {
+                        polars_bail!(SQLInterface: "cannot modify view '{}'", tbl)
+                    }
                     if let Some(lf) = self.table_map.get_mut(&tbl) {
                         *lf = DataFrame::empty_with_schema(
                             lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)
@@ -588,171 +782,920 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO <tbl> [(col, ...)] VALUES ... | SELECT ...
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Insert(Insert {
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            on,
+            returning,
+            ..
+        }) = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        };
+        if on.is_some() || returning.is_some() || partitioned.is_some() {
+            let error_message = match () {
+                _ if on.is_some() => "INSERT does not support the ON CONFLICT/DUPLICATE KEY clause",
+                _ if returning.is_some() => "INSERT does not support the RETURNING clause",
+                _ if partitioned.is_some() => "INSERT does not support the PARTITION clause",
+                _ => unreachable!(),
+            };
+            polars_bail!(SQLInterface: error_message);
+        }
+        let Some(source) = source else {
+            polars_bail!(SQLInterface: "INSERT expects a VALUES clause or a query")
+        };
+        let (tbl_name, mut lf) = self.get_registered_table(table_name)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let mut rows = self.execute_query(source)?;
+        let rows_schema = self.get_frame_schema(&mut rows)?;
+        let target_columns = if columns.is_empty() {
+            schema.iter_names().cloned().collect::<Vec<_>>()
+        } else {
+            columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(c.value.as_str()))
+                .collect()
+        };
+        if target_columns.len() != rows_schema.len() {
+            polars_bail!(
+                SQLSyntax: "INSERT has {} target columns, but {} values",
+                target_columns.len(), rows_schema.len()
+            )
+        }
+        for name in &target_columns {
+            if !schema.contains(name) {
+                polars_bail!(SQLInterface: "column '{}' does not exist in table '{}'", name, tbl_name)
+            }
+        }
+
+        // Match the inserted values to the table columns by position; unspecified columns are NULL.
+        let projection = schema
+            .iter()
+            .map(|(name, dtype)| {
+                let value = match target_columns.iter().position(|c| c == name) {
+                    Some(idx) => col(rows_schema.get_at_index(idx).unwrap().0.clone()),
+                    None => lit(NULL),
+                };
+                value.cast(dtype.clone()).alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        let rows = rows.select(projection);
+        self.check_not_null_columns(&tbl_name, &rows)?;
+        let lf = if *overwrite {
+            rows
+        } else {
+            polars_lazy::dsl::concat(vec![lf, rows], UnionArgs::default())?
+        };
+        self.table_map.insert(tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    // UPDATE <tbl> SET col = value, ... [WHERE ...]
+    fn execute_update_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            or,
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        };
+        if from.is_some() || returning.is_some() || or.is_some() || !table.joins.is_empty() {
+            let error_message = match () {
+                _ if from.is_some() => "UPDATE does not support the FROM clause",
+                _ if returning.is_some() => "UPDATE does not support the RETURNING clause",
+                _ if or.is_some() => "UPDATE does not support the OR clause",
+                _ if !table.joins.is_empty() => "UPDATE does not support table JOINs",
+                _ => unreachable!(),
+            };
+            polars_bail!(SQLInterface: error_message);
+        }
+        let TableFactor::Table { name, .. } = &table.relation else {
+            polars_bail!(SQLInterface: "UPDATE expects a table name; found {}", table.relation)
+        };
+        let (tbl_name, _) = self.get_registered_table(name)?;
+        let (_, mut lf) = self.get_table(&table.relation)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let mut predicate = match selection {
+            Some(expr) => Some(parse_sql_expr(expr, self, Some(&schema))?),
+            None => None,
+        };
+        let mut updates = self.parse_assignments(assignments, &schema, &schema)?;
+        lf = self.process_subqueries(lf, predicate.iter_mut().chain(updates.iter_mut()).collect());
+
+        // All assignments are evaluated on the values from before the update.
+        let updates = updates
+            .into_iter()
+            .map(|update| {
+                let name = expr_output_name(&update)?;
+                Ok(match &predicate {
+                    Some(predicate) => when(predicate.clone())
+                        .then(update)
+                        .otherwise(col(name.clone()))
+                        .alias(name),
+                    None => update,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let lf = lf.with_columns(updates);
+        self.check_not_null_columns(&tbl_name, &lf)?;
+        self.table_map.insert(tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    // MERGE INTO <tbl> USING <source> ON ... WHEN [NOT] MATCHED [AND ...] THEN ...
+    fn execute_merge_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        const TARGET_INDEX: PlSmallStr = PlSmallStr::from_static("__POLARS_MERGE_TARGET_INDEX");
+        const SOURCE_INDEX: PlSmallStr = PlSmallStr::from_static("__POLARS_MERGE_SOURCE_INDEX");
+        const ACTION: PlSmallStr = PlSmallStr::from_static("__POLARS_MERGE_ACTION");
+
+        let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        };
+        let TableFactor::Table { name, .. } = table else {
+            polars_bail!(SQLInterface: "MERGE expects a target table name; found {}", table)
+        };
+        let (tbl_name, _) = self.get_registered_table(name)?;
+        let (target_name, mut target) = self.get_table(table)?;
+        let (source_name, source_lf) = self.get_table(source)?;
+        let target_schema = self.get_frame_schema(&mut target)?;
+
+        // Full join the target and source rows on the ON predicate; the row indices tell whether
+        // a row matched, and order the result.
+        let mut target_lf = target.with_row_index(TARGET_INDEX, None);
+        let mut source_lf = source_lf.with_row_index(SOURCE_INDEX, None);
+        let tbl_left = TableInfo {
+            schema: self.get_frame_schema(&mut target_lf)?,
+            frame: target_lf,
+            name: target_name.as_str().into(),
+        };
+        let tbl_right = TableInfo {
+            schema: self.get_frame_schema(&mut source_lf)?,
+            frame: source_lf,
+            name: source_name.as_str().into(),
+        };
+        let mut joined = self.process_join(
+            &tbl_left,
+            &tbl_right,
+            &JoinConstraint::On(on.as_ref().clone()),
+            JoinType::Full,
+        )?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.register_joined_aliases(
+            &source_name,
+            &tbl_left.schema,
+            &tbl_right.schema,
+            &joined_schema,
+        );
+
+        // The first clause that applies to a row determines its action.
+        let is_matched = col(TARGET_INDEX)
+            .is_not_null()
+            .and(col(SOURCE_INDEX).is_not_null());
+
+        // As in standard SQL, a target row may be matched by at most one source row.
+        let multiple_matches = joined
+            .clone()
+            .filter(is_matched.clone())
+            .select([len().gt(col(TARGET_INDEX).n_unique())])
+            .collect()?;
+        if matches!(
+            multiple_matches.get_columns()[0].get(0)?,
+            AnyValue::Boolean(true)
+        ) {
+            polars_bail!(SQLInterface: "MERGE cannot affect a target row a second time; it is matched by multiple source rows")
+        }
+
+        let mut applies_to = Vec::with_capacity(clauses.len());
+        let mut deleted = lit(false);
+        let mut inserted = lit(false);
+        let mut values: PlIndexMap<PlSmallStr, Vec<(Expr, Expr)>> = target_schema
+            .iter_names()
+            .map(|name| (name.clone(), vec![]))
+            .collect();
+        for (idx, clause) in clauses.iter().enumerate() {
+            let applies = match clause.clause_kind {
+                MergeClauseKind::Matched => is_matched.clone(),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    col(TARGET_INDEX).is_null()
+                },
+                MergeClauseKind::NotMatchedBySource => col(SOURCE_INDEX).is_null(),
+            };
+            let applies = match &clause.predicate {
+                Some(predicate) => {
+                    applies.and(parse_sql_expr(predicate, self, Some(&joined_schema))?)
+                },
+                None => applies,
+            };
+            applies_to.push(applies);
+
+            let is_action = col(ACTION).eq_missing(lit(idx as u32));
+            match (&clause.clause_kind, &clause.action) {
+                (
+                    MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget,
+                    MergeAction::Insert(insert),
+                ) => {
+                    inserted = inserted.or(is_action.clone());
+                    let MergeInsertKind::Values(Values { rows, .. }) = &insert.kind else {
+                        polars_bail!(SQLInterface: "MERGE does not support INSERT ROW")
+                    };
+                    let [row] = rows.as_slice() else {
+                        polars_bail!(SQLSyntax: "MERGE INSERT expects a single row of VALUES")
+                    };
+                    let columns = if insert.columns.is_empty() {
+                        target_schema.iter_names().cloned().collect::<Vec<_>>()
+                    } else {
+                        insert
+                            .columns
+                            .iter()
+                            .map(|c| PlSmallStr::from_str(c.value.as_str()))
+                            .collect()
+                    };
+                    if columns.len() != row.len() {
+                        polars_bail!(
+                            SQLSyntax: "MERGE INSERT has {} target columns, but {} values",
+                            columns.len(), row.len()
+                        )
+                    }
+                    for (name, value) in columns.into_iter().zip(row) {
+                        let value = parse_sql_expr(value, self, Some(&joined_schema))?;
+                        let Some(column_values) = values.get_mut(&name) else {
+                            polars_bail!(SQLInterface: "column '{}' does not exist in table '{}'", name, tbl_name)
+                        };
+                        column_values.push((is_action.clone(), value));
+                    }
+                },
+                (
+                    MergeClauseKind::Matched | MergeClauseKind::NotMatchedBySource,
+                    MergeAction::Update { assignments },
+                ) => {
+                    for update in
+                        self.parse_assignments(assignments, &target_schema, &joined_schema)?
+                    {
+                        let name = expr_output_name(&update)?;
+                        values[&name].push((is_action.clone(), update));
+                    }
+                },
+                (
+                    MergeClauseKind::Matched | MergeClauseKind::NotMatchedBySource,
+                    MergeAction::Delete,
+                ) => deleted = deleted.or(is_action),
+                (kind, action) => {
+                    polars_bail!(SQLSyntax: "MERGE does not support 'WHEN {} THEN {}'", kind, action)
+                },
+            }
+        }
+        if clauses.is_empty() {
+            polars_bail!(SQLSyntax: "MERGE expects at least one WHEN clause")
+        }
+        let action = applies_to.into_iter().enumerate().rev().fold(
+            lit(NULL).cast(DataType::UInt32),
+            |action, (idx, applies)| when(applies).then(lit(idx as u32)).otherwise(action),
+        );
+
+        // Keep the target rows that are not deleted, and the inserted source rows.
+        let keep = col(TARGET_INDEX)
+            .is_not_null()
+            .and(deleted.not())
+            .or(inserted);
+        let projection = target_schema
+            .iter()
+            .map(|(name, dtype)| {
+                // Clauses are checked in order, so the first one that applies takes precedence.
+                let value = values[name].iter().rev().fold(
+                    col(name.clone()),
+                    |value, (is_action, new_value)| {
+                        when(is_action.clone())
+                            .then(new_value.clone())
+                            .otherwise(value)
+                    },
+                );
+                value.cast(dtype.clone()).alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        let lf = joined
+            .with_column(action.alias(ACTION))
+            .filter(keep.fill_null(lit(false)))
+            .sort_by_exprs(
+                [col(TARGET_INDEX), col(SOURCE_INDEX)],
+                SortMultipleOptions::default()
+                    .with_nulls_last(true)
+                    .with_maintain_order(true),
+            )
+            .select(projection);
+        self.check_not_null_columns(&tbl_name, &lf)?;
+        self.table_map.insert(tbl_name, lf.clone());
+        Ok(lf)
+    }
+
+    /// Parse the `col = value` assignments of an UPDATE into `value.alias(col)` expressions,
+    /// with the values cast to the dtype of the (target) column.
+    fn parse_assignments(
+        &mut self,
+        assignments: &[Assignment],
+        target_schema: &Schema,
+        schema: &Schema,
+    ) -> PolarsResult<Vec<Expr>> {
+        assignments
+            .iter()
+            .map(|assignment| {
+                let AssignmentTarget::ColumnName(column) = &assignment.target else {
+                    polars_bail!(SQLInterface: "UPDATE does not support tuple assignments; found {}", assignment)
+                };
+                // The column may be qualified with the table name.
+                let name = column.0.last().unwrap().value.as_str();
+                let Some(dtype) = target_schema.get(name) else {
+                    polars_bail!(SQLInterface: "column '{}' does not exist in the updated table", name)
+                };
+                let value = parse_sql_expr(&assignment.value, self, Some(schema))?;
+                Ok(value.cast(dtype.clone()).alias(name))
+            })
+            .collect()
+    }
+
+    /// Get a registered table (not a CTE or an alias), as the target of a data modification.
+    fn get_registered_table(&self, name: &ObjectName) -> PolarsResult<(String, LazyFrame)> {
+        let tbl_name = name.0.last().unwrap().value.clone();
+        if self.views.contains_key(&tbl_name) {
+            polars_bail!(SQLInterface: "cannot modify view '{}'", tbl_name)
+        }
+        match self.table_map.get(&tbl_name) {
+            Some(lf) => Ok((tbl_name, lf.clone())),
+            None => polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name),
+        }
+    }
+
+    /// Check that the rows written to a table hold no NULL values in its NOT NULL columns.
+    fn check_not_null_columns(&self, tbl_name: &str, rows: &LazyFrame) -> PolarsResult<()> {
+        let Some(not_null) = self.not_null_columns.get(tbl_name) else {
+            return Ok(());
+        };
+        let null_counts = rows
+            .clone()
+            .select(
+                not_null
+                    .iter()
+                    .map(|c| col(c.clone()).null_count())
+                    .collect::<Vec<_>>(),
+            )
+            .collect()?;
+        for c in null_counts.get_columns() {
+            if c.get(0)?.extract::<IdxSize>().unwrap_or(0) > 0 {
+                polars_bail!(SQLInterface: "NULL value in column '{}' violates its NOT NULL constraint", c.name())
+            }
+        }
+        Ok(())
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = match cte.query.body.as_ref() {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive && references_relation(right, &cte_name) => {
+                        self.execute_recursive_cte(cte, left, right, set_quantifier)?
+                    },
+                    _ => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE by fixpoint iteration: the recursive term is evaluated against
+    /// the rows produced by the previous iteration until it no longer produces any (new) rows.
+    fn execute_recursive_cte(
+        &mut self,
+        cte: &Cte,
+        anchor: &SetExpr,
+        recursive: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let query = &cte.query;
+        if query.order_by.is_some() || query.limit.is_some() || query.offset.is_some() {
+            polars_bail!(SQLInterface: "ORDER BY, LIMIT and OFFSET are not supported in recursive CTE '{}'", cte_name)
+        }
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'", quantifier, cte_name)
+            },
+        };
+        self.register_ctes(query)?;
+
+        let mut lf = self.process_query(anchor, query)?;
+        lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+        if distinct {
+            lf = lf.unique_stable(None, UniqueKeepStrategy::First);
+        }
+        let mut result = lf.collect()?;
+        let schema = result.schema().clone();
+
+        let mut delta = result.clone();
+        let mut iterations = 0;
+        while delta.height() > 0 {
+            if iterations == self.recursive_cte_max_iterations {
+                polars_bail!(
+                    SQLInterface:
+                    "recursive CTE '{}' did not terminate after {} iterations",
+                    cte_name, iterations
+                )
+            }
+            iterations += 1;
+
+            // The recursive term only sees the rows produced by the previous iteration.
+            self.register_cte(cte_name, delta.lazy());
+            let mut lf = self.process_query(recursive, query)?;
+            let recursive_schema = self.get_frame_schema(&mut lf)?;
+            if recursive_schema.len() != schema.len() {
+                polars_bail!(
+                    SQLInterface:
+                    "recursive term of CTE '{}' returns {} columns, but its anchor returns {}",
+                    cte_name, recursive_schema.len(), schema.len()
+                )
+            }
+            // The anchor determines the names and types of the columns.
+            let new_rows = lf
+                .select(
+                    recursive_schema
+                        .iter_names()
+                        .zip(schema.iter())
+                        .map(|(name, (anchor_name, dtype))| {
+                            col(name.clone())
+                                .cast(dtype.clone())
+                                .alias(anchor_name.clone())
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .collect()?;
+
+            let height = result.height();
+            result.vstack_mut(&new_rows)?;
+            delta = if distinct {
+                // `result` only held unique rows, so all rows that remain after `height` are new.
+                result = result
+                    .lazy()
+                    .unique_stable(None, UniqueKeepStrategy::First)
+                    .collect()?;
+                result.slice(height as i64, usize::MAX)
+            } else {
+                new_rows
+            };
+        }
+        result.rechunk_mut();
+        Ok(result.lazy())
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
-        if !tbl_expr.joins.is_empty() {
+        for join in &tbl_expr.joins {
+            lf = self.execute_join(lf, &l_name, &join.relation, &join.join_operator)?;
+        }
+        Ok(lf)
+    }
+
+    /// Execute a FROM clause with multiple (comma-separated) relations, which are cross joined.
+    ///
+    /// Equalities in the WHERE clause between the columns of a relation and those of the
+    /// relations before it are taken out of `selection` and used as join keys instead.
+    fn execute_from_list(
+        &mut self,
+        from: &[TableWithJoins],
+        selection: &mut Option<SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let (l_name, mut lf) = self.get_table(&from[0].relation)?;
+        for join in &from[0].joins {
+            lf = self.execute_join(lf, &l_name, &join.relation, &join.join_operator)?;
+        }
+        let mut left_names = relation_names(&from[..1]);
+        for tbl_expr in &from[1..] {
+            // Keys can only be taken for relations that are joined on their own.
+            let join_keys = if tbl_expr.joins.is_empty() {
+                take_join_keys(
+                    selection,
+                    &left_names,
+                    &relation_names(std::slice::from_ref(tbl_expr)),
+                )
+            } else {
+                None
+            };
+            let join_operator = match join_keys {
+                Some(on) => JoinOperator::Inner(JoinConstraint::On(on)),
+                None => JoinOperator::CrossJoin,
+            };
+            lf = self.execute_join(lf, &l_name, &tbl_expr.relation, &join_operator)?;
             for join in &tbl_expr.joins {
-                let (r_name, mut rf) = self.get_table(&join.relation)?;
-                if r_name.is_empty() {
-                    // Require non-empty to avoid duplicate column errors from nested self-joins.
-                    polars_bail!(
-                        SQLInterface:
-                        "cannot join on unnamed relation; please provide an alias"
-                    )
-                }
-                let left_schema = self.get_frame_schema(&mut lf)?;
-                let right_schema = self.get_frame_schema(&mut rf)?;
-
-                lf = match &join.join_operator {
-                    op @ (JoinOperator::FullOuter(constraint)
-                    | JoinOperator::LeftOuter(constraint)
-                    | JoinOperator::RightOuter(constraint)
-                    | JoinOperator::Inner(constraint)
-                    | JoinOperator::Anti(constraint)
-                    | JoinOperator::Semi(constraint)
-                    | JoinOperator::LeftAnti(constraint)
-                    | JoinOperator::LeftSemi(constraint)
-                    | JoinOperator::RightAnti(constraint)
-                    | JoinOperator::RightSemi(constraint)) => {
-                        let (lf, rf) = match op {
-                            JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => (rf, lf),
-                            _ => (lf, rf),
-                        };
-                        self.process_join(
-                            &TableInfo {
-                                frame: lf,
-                                name: (&l_name).into(),
-                                schema: left_schema.clone(),
-                            },
-                            &TableInfo {
-                                frame: rf,
-                                name: (&r_name).into(),
-                                schema: right_schema.clone(),
-                            },
-                            constraint,
-                            match op {
-                                JoinOperator::FullOuter(_) => JoinType::Full,
-                                JoinOperator::LeftOuter(_) => JoinType::Left,
-                                JoinOperator::RightOuter(_) => JoinType::Right,
-                                JoinOperator::Inner(_) => JoinType::Inner,
-                                #[cfg(feature = "semi_anti_join")]
-                                JoinOperator::Anti(_)
-                                | JoinOperator::LeftAnti(_)
-                                | JoinOperator::RightAnti(_) => JoinType::Anti,
-                                #[cfg(feature = "semi_anti_join")]
-                                JoinOperator::Semi(_)
-                                | JoinOperator::LeftSemi(_)
-                                | JoinOperator::RightSemi(_) => JoinType::Semi,
-                                join_type => polars_bail!(
-                                    SQLInterface:
-                                    "join type '{:?}' not currently supported",
-                                    join_type
-                                ),
-                            },
-                        )?
+                lf = self.execute_join(lf, &l_name, &join.relation, &join.join_operator)?;
+            }
+            left_names.extend(relation_names(std::slice::from_ref(tbl_expr)));
+        }
+        Ok(lf)
+    }
+
+    /// Join `lf` (named `l_name`) with the relation on the right-hand side of a join.
+    fn execute_join(
+        &mut self,
+        mut lf: LazyFrame,
+        l_name: &str,
+        relation: &TableFactor,
+        join_operator: &JoinOperator,
+    ) -> PolarsResult<LazyFrame> {
+        if is_lateral(relation) {
+            return self.execute_lateral_join(lf, relation, join_operator);
+        }
+        let (r_name, mut rf) = self.get_table(relation)?;
+        if r_name.is_empty() {
+            // Require non-empty to avoid duplicate column errors from nested self-joins.
+            polars_bail!(
+                SQLInterface:
+                "cannot join on unnamed relation; please provide an alias"
+            )
+        }
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        let right_schema = self.get_frame_schema(&mut rf)?;
+
+        lf = match join_operator {
+            op @ (JoinOperator::FullOuter(constraint)
+            | JoinOperator::LeftOuter(constraint)
+            | JoinOperator::RightOuter(constraint)
+            | JoinOperator::Inner(constraint)
+            | JoinOperator::Anti(constraint)
+            | JoinOperator::Semi(constraint)
+            | JoinOperator::LeftAnti(constraint)
+            | JoinOperator::LeftSemi(constraint)
+            | JoinOperator::RightAnti(constraint)
+            | JoinOperator::RightSemi(constraint)) => {
+                let (lf, rf) = match op {
+                    JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => (rf, lf),
+                    _ => (lf, rf),
+                };
+                self.process_join(
+                    &TableInfo {
+                        frame: lf,
+                        name: l_name.into(),
+                        schema: left_schema.clone(),
                     },
-                    JoinOperator::CrossJoin => {
-                        lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)))
+                    &TableInfo {
+                        frame: rf,
+                        name: (&r_name).into(),
+                        schema: right_schema.clone(),
                     },
-                    join_type => {
-                        polars_bail!(SQLInterface: "join type '{:?}' not currently supported", join_type)
+                    constraint,
+                    match op {
+                        JoinOperator::FullOuter(_) => JoinType::Full,
+                        JoinOperator::LeftOuter(_) => JoinType::Left,
+                        JoinOperator::RightOuter(_) => JoinType::Right,
+                        JoinOperator::Inner(_) => JoinType::Inner,
+                        #[cfg(feature = "semi_anti_join")]
+                        JoinOperator::Anti(_)
+                        | JoinOperator::LeftAnti(_)
+                        | JoinOperator::RightAnti(_) => JoinType::Anti,
+                        #[cfg(feature = "semi_anti_join")]
+                        JoinOperator::Semi(_)
+                        | JoinOperator::LeftSemi(_)
+                        | JoinOperator::RightSemi(_) => JoinType::Semi,
+                        join_type => polars_bail!(
+                            SQLInterface:
+                            "join type '{:?}' not currently supported",
+                            join_type
+                        ),
                     },
-                };
-
-                // track join-aliased columns so we can resolve them later
-                let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
-            }
+                )?
+            },
+            JoinOperator::CrossJoin => lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name))),
+            join_type => {
+                polars_bail!(SQLInterface: "join type '{:?}' not currently supported", join_type)
+            },
         };
+
+        // track join-aliased columns so we can resolve them later
+        let joined_schema = self.get_frame_schema(&mut lf)?;
+        self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
         Ok(lf)
     }
 
-    /// Execute the 'SELECT' part of the query.
-    fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
-        let mut lf = if select_stmt.from.is_empty() {
-            DataFrame::empty().lazy()
-        } else {
-            // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
-            }
-            self.execute_from_statement(from.first().unwrap())?
-        };
+    /// Track the columns of the right-hand relation `r_name` of a join that also exist on the
+    /// left, and are therefore aliased (suffixed with ":r_name") in the joined result.
+    fn register_joined_aliases(
+        &mut self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{name}:{r_name}");
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
 
-        // Filter expression (WHERE clause)
-        let schema = self.get_frame_schema(&mut lf)?;
-        lf = self.process_where(lf, &select_stmt.selection, false)?;
+    /// Join `lf` with a LATERAL relation, which may reference the columns of the relations
+    /// before it: `LATERAL (SELECT ...)` subqueries and `[LATERAL] UNNEST(t.arr)`.
+    fn execute_lateral_join(
+        &mut self,
+        mut lf: LazyFrame,
+        relation: &TableFactor,
+        join_operator: &JoinOperator,
+    ) -> PolarsResult<LazyFrame> {
+        let is_true = |e: &SQLExpr| matches!(e, SQLExpr::Value(SQLValue::Boolean(true)));
+        let (how, predicate) = match join_operator {
+            JoinOperator::CrossJoin | JoinOperator::Inner(JoinConstraint::None) => {
+                (JoinType::Inner, None)
+            },
+            JoinOperator::Inner(JoinConstraint::On(expr)) => {
+                (JoinType::Inner, (!is_true(expr)).then_some(expr))
+            },
+            JoinOperator::LeftOuter(JoinConstraint::On(expr)) if is_true(expr) => {
+                (JoinType::Left, None)
+            },
+            join_type => polars_bail!(
+                SQLInterface:
+                "LATERAL joins only support CROSS JOIN, INNER JOIN and LEFT JOIN ... ON TRUE; found {:?}",
+                join_type
+            ),
+        };
 
-        // 'SELECT *' modifiers
-        let mut select_modifiers = SelectModifiers {
-            ilike: None,
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        let (r_name, joined, mut rf) = match relation {
+            TableFactor::Derived {
+                subquery, alias, ..
+            } => {
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "derived tables must have aliases");
+                };
+                let (joined, rf) = self.join_lateral_subquery(lf, subquery, alias, how)?;
+                (alias.name.value.clone(), joined, rf)
+            },
+            TableFactor::UNNEST {
+                alias,
+                array_exprs,
+                with_offset,
+                with_offset_alias,
+                with_ordinality,
+            } => {
+                let ordinal = UnnestOrdinal::new(*with_ordinality, *with_offset, with_offset_alias);
+                self.join_lateral_unnest(lf, array_exprs, alias, ordinal, how)?
+            },
+            TableFactor::Function {
+                name, args, alias, ..
+            } if name.to_string().eq_ignore_ascii_case("unnest") => {
+                let array_exprs = args
+                    .iter()
+                    .map(|arg| match arg {
+                        FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => Ok(expr.clone()),
+                        _ => polars_bail!(SQLSyntax: "invalid UNNEST argument: {}", arg),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                self.join_lateral_unnest(lf, &array_exprs, alias, None, how)?
+            },
+            _ => polars_bail!(SQLInterface: "not yet implemented: LATERAL {}", relation),
+        };
+        self.table_map.insert(r_name.clone(), rf.clone());
+
+        let mut joined = joined;
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
+        if let Some(predicate) = predicate {
+            let predicate = parse_sql_expr(predicate, self, Some(&joined_schema))?;
+            joined = joined.filter(predicate);
+        }
+        Ok(joined)
+    }
+
+    /// Join `lf` with the rows of the arrays of its current row, zipped together, e.g.
+    /// `FROM t, UNNEST(t.arr) AS u(x)`. Rows with empty arrays only remain for a LEFT JOIN.
+    fn join_lateral_unnest(
+        &mut self,
+        mut lf: LazyFrame,
+        array_exprs: &[SQLExpr],
+        alias: &Option<TableAlias>,
+        ordinal: Option<UnnestOrdinal>,
+        how: JoinType,
+    ) -> PolarsResult<(String, LazyFrame, LazyFrame)> {
+        if array_exprs.is_empty() {
+            polars_bail!(SQLSyntax: "UNNEST requires at least one array argument");
+        }
+        let r_name = alias
+            .as_ref()
+            .map_or_else(|| "unnest".to_string(), |a| a.name.value.clone());
+        let mut column_names: Vec<PlSmallStr> = match alias {
+            Some(alias) if !alias.columns.is_empty() => alias
+                .columns
+                .iter()
+                .map(|c| c.name.value.as_str().into())
+                .collect(),
+            _ if array_exprs.len() == 1 => vec![r_name.as_str().into()],
+            _ => (0..array_exprs.len())
+                .map(|i| format_pl_smallstr!("{}_{}", r_name, i + 1))
+                .collect(),
+        };
+        let ordinal_name = ordinal
+            .as_ref()
+            .map(|o| o.column_name(&mut column_names, array_exprs.len()));
+        if column_names.len() != array_exprs.len() {
+            polars_bail!(
+                SQLSyntax:
+                "UNNEST table alias requires {} column names, found {}", array_exprs.len(), column_names.len()
+            );
+        }
+        column_names.extend(ordinal_name.clone());
+
+        // Columns that already exist on the left are suffixed, as for other joins.
+        let schema = self.get_frame_schema(&mut lf)?;
+        let output_names = column_names
+            .iter()
+            .map(|name| {
+                if schema.contains(name) {
+                    format_pl_smallstr!("{}:{}", name, r_name)
+                } else {
+                    name.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        let arrays = array_exprs
+            .iter()
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut joined = unnest_zipped(
+            lf,
+            arrays,
+            &output_names[..array_exprs.len()],
+            ordinal_name
+                .is_some()
+                .then(|| output_names.last().unwrap().clone()),
+            how,
+        );
+        if let Some(UnnestOrdinal::Offset(_)) = ordinal {
+            let name = output_names.last().unwrap().clone();
+            joined = joined.with_column((col(name.clone()) - lit(1i64)).alias(name));
+        }
+        let rf = joined.clone().select(
+            output_names
+                .iter()
+                .zip(column_names)
+                .map(|(name, column)| col(name.clone()).alias(column))
+                .collect::<Vec<_>>(),
+        );
+        Ok((r_name, joined, rf))
+    }
+
+    /// Explode the `UNNEST(...)` items of a SELECT list that also projects other columns as
+    /// a lateral join, e.g. `SELECT id, UNNEST(tags) AS tag FROM t`: the other columns are
+    /// repeated for each array element, and several UNNEST items are zipped together.
+    fn unnest_projections(
+        &mut self,
+        lf: LazyFrame,
+        projections: Vec<Expr>,
+        schema: &Schema,
+    ) -> PolarsResult<(LazyFrame, Vec<Expr>)> {
+        let unnest_input = |e: &Expr| match e {
+            Expr::Alias(e, _) => match e.as_ref() {
+                Expr::Explode { input, .. } => Some(input.as_ref().clone()),
+                _ => None,
+            },
+            Expr::Explode { input, .. } => Some(input.as_ref().clone()),
+            _ => None,
+        };
+        let is_literal = |e: &Expr| match e {
+            Expr::Alias(e, _) => matches!(e.as_ref(), Expr::Literal(_)),
+            e => matches!(e, Expr::Literal(_)),
+        };
+        let n_unnest = projections
+            .iter()
+            .filter(|p| unnest_input(p).is_some())
+            .count();
+        // Without other columns, UNNEST items explode their arrays independently
+        let is_lateral = projections
+            .iter()
+            .any(|p| unnest_input(p).is_none() && !is_literal(p));
+        if n_unnest == 0
+            || !is_lateral
+            || projections
+                .iter()
+                .any(|p| has_expr(p, |e| matches!(e, Expr::Agg(_) | Expr::Len)))
+        {
+            return Ok((lf, projections));
+        }
+
+        let mut arrays = Vec::with_capacity(n_unnest);
+        let mut names = Vec::with_capacity(n_unnest);
+        let projections = projections
+            .into_iter()
+            .map(|p| {
+                Ok(match unnest_input(&p) {
+                    Some(array) => {
+                        let name = format_pl_smallstr!("__POLARS_UNNEST_{}", names.len());
+                        let output_name = p.to_field(schema, Context::Default)?.name;
+                        arrays.push(array);
+                        names.push(name.clone());
+                        col(name).alias(output_name)
+                    },
+                    None => p,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let lf = unnest_zipped(lf, arrays, &names, None, JoinType::Inner);
+        Ok((lf, projections))
+    }
+
+    /// Execute the 'SELECT' part of the query.
+    fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        // The scalar subquery columns only exist in the frame of this SELECT, so nested
+        // SELECTs start from an empty map and the outer one is restored afterwards.
+        let outer_subquery_columns = self.subquery_columns.take();
+        let res = self.execute_select_scoped(select_stmt, query);
+        self.subquery_columns.replace(outer_subquery_columns);
+        res
+    }
+
+    fn execute_select_scoped(
+        &mut self,
+        select_stmt: &Select,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
+        let mut selection = select_stmt.selection.clone();
+        let mut lf = match select_stmt.from.as_slice() {
+            [] => DataFrame::empty().lazy(),
+            [tbl_expr] => self.execute_from_statement(tbl_expr)?,
+            from => self.execute_from_list(from, &mut selection)?,
+        };
+
+        // Scalar subqueries are evaluated as (left) joins
+        lf = self.process_scalar_subqueries(lf, select_stmt)?;
+
+        // Filter expression (WHERE clause)
+        let schema = self.get_frame_schema(&mut lf)?;
+        lf = self.process_where(lf, &selection, false)?;
+
+        // 'SELECT *' modifiers
+        let mut select_modifiers = SelectModifiers {
+            ilike: None,
             exclude: PlHashSet::new(),
             rename: PlHashMap::new(),
             replace: vec![],
         };
 
+        // Named windows (WINDOW clause), referenced by window functions in the projection
+        if !select_stmt.named_window.is_empty() {
+            self.register_named_windows(&select_stmt.named_window)?;
+        }
         let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets = None;
         match &select_stmt.group_by {
-            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values), optionally
+            // with GROUPING SETS, ROLLUP and CUBE.
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
-                // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
+                (group_by_keys, grouping_sets) =
+                    self.process_group_by_items(group_by_exprs, modifiers, &projections, &schema)?;
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
@@ -791,17 +1734,23 @@ impl SQLContext {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
             };
 
+            // UNNEST items alongside other columns explode the rows they are projected from
+            let (unnested, projections) = self.unnest_projections(lf, projections, &schema)?;
+            lf = unnested;
+            let schema = self.get_frame_schema(&mut lf)?;
+
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
             let mut retained_names = Vec::with_capacity(projections.len());
             let have_order_by = query.order_by.is_some();
+            let have_qualify = select_stmt.qualify.is_some();
             // Initialize containing InheritsContext to handle empty projection case.
             let mut projection_heights = ExprSqlProjectionHeightBehavior::InheritsContext;
 
-            // Note: if there is an 'order by' then we project everything (original cols
-            // and new projections) and *then* select the final cols; the retained cols
-            // are used to ensure a correct final projection. If there's no 'order by',
-            // clause then we can project the final column *expressions* directly.
+            // Note: if there is an 'order by' (or 'qualify') then we project everything
+            // (original cols and new projections) and *then* select the final cols; the
+            // retained cols are used to ensure a correct final projection. If there's no
+            // 'order by' clause then we can project the final column *expressions* directly.
             for p in projections.iter() {
                 let name = p
                     .to_field(schema.deref(), Context::Default)?
@@ -812,7 +1761,7 @@ impl SQLContext {
                 {
                     projection_heights |= ExprSqlProjectionHeightBehavior::identify_from_expr(p);
 
-                    retained_cols.push(if have_order_by {
+                    retained_cols.push(if have_order_by || have_qualify {
                         col(name.as_str())
                     } else {
                         p.clone()
@@ -822,7 +1771,7 @@ impl SQLContext {
             }
 
             // Apply the remaining modifiers and establish the final projection
-            if have_order_by {
+            if have_order_by || have_qualify {
                 // We can safely use `with_columns()` and avoid a join if:
                 // * There is already a projection that projects to the table height.
                 // * All projection heights inherit from context (e.g. all scalar literals that
@@ -870,11 +1819,14 @@ impl SQLContext {
                 lf = lf.with_columns(select_modifiers.renamed_cols());
             }
 
+            // Apply optional 'qualify' clause, post-window functions; it can reference both
+            // the projected and the original columns.
+            lf = self.process_qualify(lf, &select_stmt.qualify)?;
             lf = self.process_order_by(lf, &query.order_by, Some(&retained_cols))?;
 
-            // Note: If `have_order_by`, with_columns is already done above.
+            // Note: If `have_order_by` (or `have_qualify`), with_columns is already done above.
             if projection_heights == ExprSqlProjectionHeightBehavior::InheritsContext
-                && !have_order_by
+                && !(have_order_by || have_qualify)
             {
                 // All projections need to be broadcasted to table height, so evaluate in `with_columns()`
                 lf = lf.with_columns(retained_cols).select(retained_names);
@@ -891,15 +1843,17 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf =
+                self.process_group_by(lf, &group_by_keys, grouping_sets.as_deref(), &projections)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
-            }
+            };
+            self.process_qualify(lf, &select_stmt.qualify)?
         };
 
         // Apply optional DISTINCT clause.
@@ -935,6 +1889,52 @@ impl SQLContext {
         Ok(lf)
     }
 
+    fn process_qualify(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &Option<SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        match expr {
+            Some(expr) => {
+                let schema = self.get_frame_schema(&mut lf)?;
+                let predicate = parse_sql_expr(expr, self, Some(&schema))?;
+                Ok(lf.filter(predicate))
+            },
+            None => Ok(lf),
+        }
+    }
+
+    fn register_named_windows(
+        &mut self,
+        definitions: &[NamedWindowDefinition],
+    ) -> PolarsResult<()> {
+        let mut named_windows = PlHashMap::with_capacity(definitions.len());
+        for NamedWindowDefinition(name, window) in definitions {
+            let window = match window {
+                NamedWindowExpr::NamedWindow(other) => named_windows
+                    .get(&other.value)
+                    .cloned()
+                    .ok_or_else(|| polars_err!(SQLSyntax: "window '{}' is not defined", other))?,
+                NamedWindowExpr::WindowSpec(spec) => {
+                    resolve_window_spec(spec, |name| named_windows.get(name).cloned())?
+                },
+            };
+            named_windows.insert(name.value.clone(), window);
+        }
+        self.named_windows = named_windows;
+        Ok(())
+    }
+
+    /// Resolve a window that is, or refines, a named window from the WINDOW clause.
+    pub(crate) fn resolve_window(&self, window: &WindowType) -> PolarsResult<WindowSpec> {
+        let get_named = |name: &str| self.named_windows.get(name).cloned();
+        match window {
+            WindowType::NamedWindow(name) => get_named(&name.value)
+                .ok_or_else(|| polars_err!(SQLSyntax: "window '{}' is not defined", name)),
+            WindowType::WindowSpec(spec) => resolve_window_spec(spec, get_named),
+        }
+    }
+
     fn column_projections(
         &mut self,
         select_stmt: &Select,
@@ -962,6 +1962,7 @@ impl SQLContext {
                 SelectItem::Wildcard(wildcard_options) => {
                     let cols = schema
                         .iter_names()
+                        .filter(|name| !name.starts_with(SUBQUERY_COLUMN_PREFIX))
                         .map(|name| col(name.clone()))
                         .collect::<Vec<_>>();
 
@@ -986,11 +1987,16 @@ impl SQLContext {
 
     fn process_where(
         &mut self,
-        mut lf: LazyFrame,
+        lf: LazyFrame,
         expr: &Option<SQLExpr>,
         invert_filter: bool,
     ) -> PolarsResult<LazyFrame> {
-        if let Some(expr) = expr {
+        // EXISTS and correlated IN subqueries are evaluated as semi/anti joins
+        let (mut lf, expr) = match expr {
+            Some(expr) if !invert_filter => self.process_subquery_predicates(lf, expr)?,
+            _ => (lf, expr.clone()),
+        };
+        if let Some(expr) = &expr {
             let schema = self.get_frame_schema(&mut lf)?;
 
             // shortcut filter evaluation if given expression is just TRUE or FALSE
@@ -1033,7 +2039,13 @@ impl SQLContext {
         constraint: &JoinConstraint,
         join_type: JoinType,
     ) -> PolarsResult<LazyFrame> {
-        let (left_on, right_on) = process_join_constraint(constraint, tbl_left, tbl_right)?;
+        let Some((left_on, right_on)) = process_join_constraint(constraint, tbl_left, tbl_right)?
+        else {
+            let JoinConstraint::On(expr) = constraint else {
+                unreachable!()
+            };
+            return self.process_join_on_predicate(tbl_left, tbl_right, expr, join_type);
+        };
 
         let joined = tbl_left
             .frame
@@ -1050,6 +2062,140 @@ impl SQLContext {
         Ok(joined)
     }
 
+    /// Join on an arbitrary ON predicate, e.g. inequalities, BETWEEN or OR.
+    ///
+    /// The matching rows are found with `join_where`, which turns equalities and inequalities in
+    /// the predicate into equi- and IE-joins and filters on the remainder. For outer, semi and
+    /// anti joins the matches are joined back to the inputs on their row index.
+    fn process_join_on_predicate(
+        &mut self,
+        tbl_left: &TableInfo,
+        tbl_right: &TableInfo,
+        expr: &SQLExpr,
+        join_type: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        const LEFT_INDEX: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_LEFT_INDEX");
+        const RIGHT_INDEX: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_RIGHT_INDEX");
+        const MATCHED: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_MATCHED");
+
+        // Right columns that also exist on the left are suffixed in the join output.
+        let suffix = format!(":{}", tbl_right.name);
+        let right_aliases = tbl_right
+            .schema
+            .iter_names()
+            .filter(|name| tbl_left.schema.contains(name))
+            .map(|name| (name.to_string(), format!("{name}{suffix}")))
+            .collect::<PlHashMap<_, _>>();
+        let mut joined_schema = tbl_left.schema.as_ref().clone();
+        for (name, dtype) in tbl_right.schema.iter() {
+            let name = match right_aliases.get(name.as_str()) {
+                Some(alias) => PlSmallStr::from_str(alias),
+                None => name.clone(),
+            };
+            joined_schema.with_column(name, dtype.clone());
+        }
+
+        // Resolve the predicate against the join output.
+        self.joined_aliases
+            .borrow_mut()
+            .insert(tbl_right.name.to_string(), right_aliases.clone());
+        self.ambiguous_columns = right_aliases.keys().cloned().collect();
+        let predicate = parse_sql_expr(expr, self, Some(&joined_schema));
+        self.ambiguous_columns.clear();
+        let predicate = predicate?;
+
+        let left_cols = tbl_left
+            .schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        let right_cols = joined_schema
+            .iter_names()
+            .skip(tbl_left.schema.len())
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+
+        let left = tbl_left.frame.clone().with_row_index(LEFT_INDEX, None);
+        let right = tbl_right
+            .frame
+            .clone()
+            .rename(right_aliases.keys(), right_aliases.values(), true)
+            .with_row_index(RIGHT_INDEX, None);
+        let matched = left
+            .clone()
+            .join_builder()
+            .with(right.clone())
+            .suffix(suffix)
+            .join_where(vec![predicate]);
+
+        // Join the matches back to the rows of `input`, keeping the unmatched ones.
+        let left_join_matches = |input: LazyFrame, index: PlSmallStr, cols: &[Expr]| {
+            let mut match_cols = vec![col(index.clone())];
+            match_cols.extend_from_slice(cols);
+            input.join(
+                matched.clone().select(match_cols),
+                [col(index.clone())],
+                [col(index)],
+                JoinArgs::new(JoinType::Left),
+            )
+        };
+        let output_cols = [left_cols.as_slice(), right_cols.as_slice()].concat();
+
+        Ok(match join_type {
+            JoinType::Inner => matched.select(output_cols),
+            JoinType::Left => left_join_matches(left, LEFT_INDEX, &right_cols).select(output_cols),
+            JoinType::Right => {
+                left_join_matches(right, RIGHT_INDEX, &left_cols).select(output_cols)
+            },
+            JoinType::Full => {
+                let mut right_match_cols = right_cols.clone();
+                right_match_cols.push(col(RIGHT_INDEX));
+                let left_part = left_join_matches(left, LEFT_INDEX, &right_match_cols);
+
+                // The right rows that did not match any left row, with null left columns.
+                let right_matched = matched
+                    .clone()
+                    .select([col(RIGHT_INDEX)])
+                    .unique(None, UniqueKeepStrategy::Any)
+                    .with_column(lit(true).alias(MATCHED));
+                let mut null_left_cols = tbl_left
+                    .schema
+                    .iter()
+                    .map(|(name, dtype)| lit(NULL).cast(dtype.clone()).alias(name.clone()))
+                    .collect::<Vec<_>>();
+                null_left_cols.extend(right_cols);
+                let right_part = right
+                    .join(
+                        right_matched,
+                        [col(RIGHT_INDEX)],
+                        [col(RIGHT_INDEX)],
+                        JoinArgs::new(JoinType::Left),
+                    )
+                    .filter(col(MATCHED).is_null())
+                    .select(null_left_cols);
+
+                polars_lazy::dsl::concat(
+                    vec![left_part.select(output_cols), right_part],
+                    UnionArgs::default(),
+                )?
+            },
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => left
+                .join(
+                    matched.select([col(LEFT_INDEX)]),
+                    [col(LEFT_INDEX)],
+                    [col(LEFT_INDEX)],
+                    JoinArgs::new(join_type),
+                )
+                .select(left_cols),
+            join_type => polars_bail!(
+                SQLInterface:
+                "join type '{:?}' is not supported with non-equi join constraints",
+                join_type
+            ),
+        })
+    }
+
     fn process_subqueries(&self, lf: LazyFrame, exprs: Vec<&mut Expr>) -> LazyFrame {
         let mut contexts = vec![];
         for expr in exprs {
@@ -1077,6 +2223,7 @@ impl SQLContext {
         if let Statement::CreateTable(CreateTable {
             if_not_exists,
             name,
+            columns,
             query,
             ..
         }) = stmt
@@ -1096,14 +2243,162 @@ impl SQLContext {
                 .unwrap()
                 .lazy();
                 Ok(out)
+            } else if !columns.is_empty() {
+                // CREATE TABLE <tbl> (<col> <type> [NOT NULL], ...)
+                let mut schema = Schema::with_capacity(columns.len());
+                let mut not_null = PlHashSet::new();
+                for column in columns {
+                    let name = PlSmallStr::from_str(column.name.value.as_str());
+                    for opt in &column.options {
+                        match opt.option {
+                            ColumnOption::Null => {},
+                            ColumnOption::NotNull => {
+                                not_null.insert(name.clone());
+                            },
+                            _ => {
+                                polars_bail!(SQLInterface: "column option '{}' is not supported", opt.option)
+                            },
+                        }
+                    }
+                    let dtype = map_sql_dtype_to_polars(&column.data_type, &self.time_zone)?;
+                    if schema.insert(name.clone(), dtype).is_some() {
+                        polars_bail!(SQLInterface: "column '{}' is specified more than once", name)
+                    }
+                }
+                self.register(tbl_name, DataFrame::empty_with_schema(&schema).lazy());
+                if !not_null.is_empty() {
+                    self.not_null_columns.insert(tbl_name.to_string(), not_null);
+                }
+                Ok(df! { "Response" => ["CREATE TABLE"] }.unwrap().lazy())
             } else {
-                polars_bail!(SQLInterface: "only `CREATE TABLE AS SELECT ...` is currently supported");
+                polars_bail!(SQLInterface: "CREATE TABLE expects column definitions or `AS SELECT ...`");
             }
         } else {
             unreachable!()
         }
     }
 
+    // CREATE [OR REPLACE] VIEW <view> [(<col>, ...)] AS <query>
+    fn execute_create_view(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let Statement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            columns,
+            query,
+            if_not_exists,
+            ..
+        } = stmt
+        else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected CREATE VIEW")
+        };
+        if *materialized {
+            polars_bail!(SQLInterface: "CREATE MATERIALIZED VIEW is not supported")
+        }
+        let view_name = name.0.last().unwrap().value.clone();
+        if self.table_map.contains_key(&view_name) {
+            if *if_not_exists {
+                return Ok(DataFrame::empty().lazy());
+            }
+            if !*or_replace || !self.views.contains_key(&view_name) {
+                polars_bail!(SQLInterface: "relation '{}' already exists", view_name)
+            }
+        }
+        let view = ViewDefinition {
+            query: query.as_ref().clone(),
+            columns: columns
+                .iter()
+                .map(|c| PlSmallStr::from_str(c.name.value.as_str()))
+                .collect(),
+        };
+        // Plan the view once up front, to validate it and to describe its schema.
+        let lf = self.plan_view(&view)?;
+        self.register(&view_name, lf);
+        self.views.insert(view_name, view);
+        Ok(df! { "Response" => ["CREATE VIEW"] }.unwrap().lazy())
+    }
+
+    /// Plan the query of a view against the current state of the registered tables.
+    fn plan_view(&self, view: &ViewDefinition) -> PolarsResult<LazyFrame> {
+        // The view query is planned in a context of its own, so that it doesn't see (or
+        // clobber) the CTEs and aliases of the statement that reads from the view.
+        let mut ctx = SQLContext {
+            table_map: self.table_map.clone(),
+            function_registry: self.function_registry.clone(),
+            recursive_cte_max_iterations: self.recursive_cte_max_iterations,
+            time_zone: self.time_zone.clone(),
+            dialect: self.dialect,
+            views: self.views.clone(),
+            ..Default::default()
+        };
+        let mut lf = ctx.execute_query(&view.query)?;
+        if !view.columns.is_empty() {
+            let schema = ctx.get_frame_schema(&mut lf)?;
+            if view.columns.len() != schema.len() {
+                polars_bail!(
+                    SQLSyntax: "CREATE VIEW has {} column names, but the query returns {} columns",
+                    view.columns.len(), schema.len()
+                )
+            }
+            lf = lf.rename(
+                schema.iter_names().cloned(),
+                view.columns.iter().cloned(),
+                true,
+            );
+        }
+        Ok(lf)
+    }
+
+    /// Get a (virtual) relation of the `information_schema`, describing the registered tables.
+    fn get_information_schema_table(&mut self, name: &str) -> PolarsResult<LazyFrame> {
+        let tables = self.get_tables();
+        let df = match name.to_lowercase().as_str() {
+            "tables" => {
+                let table_types = tables
+                    .iter()
+                    .map(|t| match self.views.contains_key(t) {
+                        true => "VIEW",
+                        false => "BASE TABLE",
+                    })
+                    .collect::<Vec<_>>();
+                df! {
+                    "table_schema" => vec![INFORMATION_SCHEMA_TABLE_SCHEMA; tables.len()],
+                    "table_name" => tables,
+                    "table_type" => table_types,
+                }?
+            },
+            "columns" => {
+                let (mut table_names, mut column_names, mut positions) = (vec![], vec![], vec![]);
+                let (mut data_types, mut is_nullable) = (vec![], vec![]);
+                for tbl_name in tables {
+                    let mut lf = self.table_map.get(&tbl_name).unwrap().clone();
+                    let schema = self.get_frame_schema(&mut lf)?;
+                    let not_null = self.not_null_columns.get(&tbl_name);
+                    for (idx, (name, dtype)) in schema.iter().enumerate() {
+                        table_names.push(tbl_name.clone());
+                        column_names.push(name.to_string());
+                        positions.push(idx as i32 + 1);
+                        data_types.push(map_polars_dtype_to_sql(dtype));
+                        is_nullable.push(match not_null.is_some_and(|nn| nn.contains(name)) {
+                            true => "NO",
+                            false => "YES",
+                        });
+                    }
+                }
+                df! {
+                    "table_schema" => vec![INFORMATION_SCHEMA_TABLE_SCHEMA; table_names.len()],
+                    "table_name" => table_names,
+                    "column_name" => column_names,
+                    "ordinal_position" => positions,
+                    "data_type" => data_types,
+                    "is_nullable" => is_nullable,
+                }?
+            },
+            _ => polars_bail!(SQLInterface: "relation 'information_schema.{}' was not found", name),
+        };
+        Ok(df.lazy())
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1112,8 +2407,22 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
-                let tbl_name = name.0.first().unwrap().value.as_str();
-                if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                let (tbl_name, lf) = match name.0.as_slice() {
+                    [schema, tbl] if schema.value.eq_ignore_ascii_case("information_schema") => {
+                        // register the relation for the statement, so that it can be referenced
+                        let lf = self.get_information_schema_table(&tbl.value)?;
+                        self.register_cte(&tbl.value, lf.clone());
+                        (tbl.value.as_str(), Some(lf))
+                    },
+                    _ => {
+                        let tbl_name = name.0.first().unwrap().value.as_str();
+                        match self.views.get(tbl_name) {
+                            Some(view) => (tbl_name, Some(self.plan_view(view)?)),
+                            None => (tbl_name, self.get_table_from_current_scope(tbl_name)),
+                        }
+                    },
+                };
+                if let Some(lf) = lf {
                     match alias {
                         Some(alias) => {
                             self.table_aliases
@@ -1128,11 +2437,8 @@ impl SQLContext {
                 }
             },
             TableFactor::Derived {
-                lateral,
-                subquery,
-                alias,
+                subquery, alias, ..
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -1146,11 +2452,11 @@ impl SQLContext {
                 alias,
                 array_exprs,
                 with_offset,
-                with_offset_alias: _,
-                ..
+                with_offset_alias,
+                with_ordinality,
             } => {
                 if let Some(alias) = alias {
-                    let column_names: Vec<Option<PlSmallStr>> = alias
+                    let mut column_names: Vec<Option<PlSmallStr>> = alias
                         .columns
                         .iter()
                         .map(|c| {
@@ -1171,6 +2477,17 @@ impl SQLContext {
                         SQLSyntax:
                         "UNNEST table alias must also declare column names, eg: {} (a,b,c)", alias.name.to_string()
                     );
+                    let ordinal =
+                        UnnestOrdinal::new(*with_ordinality, *with_offset, with_offset_alias);
+                    let ordinal_name = ordinal.as_ref().map(|o| {
+                        let mut names: Vec<PlSmallStr> = column_names
+                            .iter()
+                            .map(|name| name.clone().unwrap_or_default())
+                            .collect();
+                        let name = o.column_name(&mut names, column_values.len());
+                        column_names.truncate(names.len());
+                        name
+                    });
                     if column_names.len() != column_values.len() {
                         let plural = if column_values.len() > 1 { "s" } else { "" };
                         polars_bail!(
@@ -1178,26 +2495,33 @@ impl SQLContext {
                             "UNNEST table alias requires {} column name{}, found {}", column_values.len(), plural, column_names.len()
                         );
                     }
-                    let column_series: Vec<Column> = column_values
+
+                    // Zip the arrays, padding the shorter ones with NULLs
+                    let height = column_values.iter().map(|s| s.len()).max().unwrap_or(0);
+                    let mut column_series = column_values
                         .into_iter()
                         .zip(column_names)
                         .map(|(s, name)| {
-                            if let Some(name) = name {
-                                s.clone().with_name(name)
+                            let s = s.extend_constant(AnyValue::Null, height - s.len())?;
+                            Ok(if let Some(name) = name {
+                                s.with_name(name)
                             } else {
-                                s.clone()
-                            }
+                                s
+                            })
                         })
-                        .map(Column::from)
-                        .collect();
-
-                    let lf = DataFrame::new(column_series)?.lazy();
-
-                    if *with_offset {
-                        // TODO: support 'WITH ORDINALITY|OFFSET' modifier.
-                        //  (note that 'WITH OFFSET' is BigQuery-specific syntax, not PostgreSQL)
-                        polars_bail!(SQLInterface: "UNNEST tables do not (yet) support WITH ORDINALITY|OFFSET");
+                        .map(|s| s.map(Column::from))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    if let Some(name) = ordinal_name {
+                        let start = match ordinal {
+                            Some(UnnestOrdinal::Offset(_)) => 0,
+                            _ => 1,
+                        };
+                        column_series.push(Column::new(
+                            name,
+                            (start..start + height as i64).collect::<Vec<_>>(),
+                        ));
                     }
+                    let lf = DataFrame::new(column_series)?.lazy();
                     let table_name = alias.name.value.clone();
                     self.table_map.insert(table_name.clone(), lf.clone());
                     Ok((table_name.clone(), lf))
@@ -1215,11 +2539,180 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot { .. } => self.execute_pivot(relation),
+            TableFactor::Unpivot { .. } => self.execute_unpivot(relation),
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    // <tbl> PIVOT (agg, ... FOR col IN (value, ...)) [alias]
+    fn execute_pivot(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
+        let TableFactor::Pivot {
+            table,
+            aggregate_functions,
+            value_column,
+            value_source,
+            default_on_null,
+            alias,
+        } = relation
+        else {
+            polars_bail!(SQLInterface: "unexpected table factor; expected PIVOT")
+        };
+        let PivotValueSource::List(values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values; found {}", value_source)
+        };
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+        let pivot_column = match value_column.as_slice() {
+            [ident] => SQLExpr::Identifier(ident.clone()),
+            idents => SQLExpr::CompoundIdentifier(idents.to_vec()),
+        };
+        let default_on_null = match default_on_null {
+            Some(expr) => Some(parse_sql_expr(expr, self, Some(&schema))?),
+            None => None,
+        };
+
+        // The pivot column and the aggregated columns are consumed; the remaining columns
+        // are the (implicit) group keys.
+        let mut consumed = PlHashSet::new();
+        consumed.insert(expr_output_name(&parse_sql_expr(
+            &pivot_column,
+            self,
+            Some(&schema),
+        )?)?);
+        for agg in aggregate_functions {
+            let expr = parse_sql_expr(&agg.expr, self, Some(&schema))?;
+            consumed.extend(expr_to_leaf_column_names_iter(&expr));
+        }
+        let index = schema
+            .iter_names()
+            .filter(|name| !consumed.contains(*name))
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+
+        // Each (value, aggregate) pair is an aggregate that is filtered on the pivot value.
+        let mut aggs = Vec::with_capacity(values.len() * aggregate_functions.len());
+        for value in values {
+            let value_name = match value {
+                ExprWithAlias {
+                    alias: Some(alias), ..
+                } => alias.value.clone(),
+                ExprWithAlias {
+                    expr: SQLExpr::Value(SQLValue::SingleQuotedString(s)),
+                    ..
+                } => s.clone(),
+                ExprWithAlias { expr, .. } => expr.to_string(),
+            };
+            let is_value = SQLExpr::BinaryOp {
+                left: Box::new(pivot_column.clone()),
+                op: BinaryOperator::Eq,
+                right: Box::new(value.expr.clone()),
+            };
+            // Groups without the pivot value aggregate to NULL.
+            let has_value = parse_sql_expr(&is_value, self, Some(&schema))?.any(true);
+            for agg in aggregate_functions {
+                let SQLExpr::Function(func) = &agg.expr else {
+                    polars_bail!(SQLSyntax: "PIVOT expects aggregate functions; found {}", agg.expr)
+                };
+                let mut func = func.clone();
+                func.filter = Some(Box::new(match func.filter.take() {
+                    Some(filter) => SQLExpr::BinaryOp {
+                        left: filter,
+                        op: BinaryOperator::And,
+                        right: Box::new(is_value.clone()),
+                    },
+                    None => is_value.clone(),
+                }));
+                let mut expr = when(has_value.clone())
+                    .then(parse_sql_expr(
+                        &SQLExpr::Function(func),
+                        self,
+                        Some(&schema),
+                    )?)
+                    .otherwise(lit(NULL));
+                if let Some(default) = &default_on_null {
+                    expr = expr.fill_null(default.clone());
+                }
+                let name = match (aggregate_functions.len(), &agg.alias) {
+                    (1, _) => value_name.clone(),
+                    (_, Some(alias)) => format!("{}_{}", value_name, alias.value),
+                    (_, None) => format!("{}_{}", value_name, agg.expr),
+                };
+                aggs.push(expr.alias(name));
+            }
+        }
+        let lf = if index.is_empty() {
+            lf.select(aggs)
+        } else {
+            lf.group_by_stable(index).agg(aggs)
+        };
+        self.register_table_factor(tbl_name, lf, alias)
+    }
+
+    // <tbl> UNPIVOT (value FOR name IN (col, ...)) [alias]
+    fn execute_unpivot(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
+        let TableFactor::Unpivot {
+            table,
+            value,
+            name,
+            columns,
+            alias,
+        } = relation
+        else {
+            polars_bail!(SQLInterface: "unexpected table factor; expected UNPIVOT")
+        };
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on = columns
+            .iter()
+            .map(|c| PlSmallStr::from_str(c.value.as_str()))
+            .collect::<Vec<_>>();
+        if let Some(missing) = on.iter().find(|c| !schema.contains(c)) {
+            polars_bail!(SQLInterface: "UNPIVOT column '{}' does not exist", missing)
+        }
+        let index = schema
+            .iter_names()
+            .filter(|c| !on.contains(*c))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // As in other SQL engines, rows with a NULL value are excluded.
+        let value_name = PlSmallStr::from_str(value.value.as_str());
+        let lf = lf
+            .unpivot(UnpivotArgsDSL {
+                on: Selector::ByName {
+                    names: on.into(),
+                    strict: true,
+                },
+                index: Selector::ByName {
+                    names: index.into(),
+                    strict: true,
+                },
+                variable_name: Some(PlSmallStr::from_str(name.value.as_str())),
+                value_name: Some(value_name.clone()),
+            })
+            .filter(col(value_name).is_not_null());
+        self.register_table_factor(tbl_name, lf, alias)
+    }
+
+    /// Register the result of a PIVOT/UNPIVOT under its alias (if any).
+    fn register_table_factor(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1227,19 +2720,52 @@ impl SQLContext {
         args: &[FunctionArg],
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.first().unwrap().value.as_str();
-        let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
-        let (tbl_name, lf) = read_fn.execute(args)?;
+        let (tbl_name, lf) = match self
+            .function_registry
+            .get_table_function(&tbl_fn.to_lowercase())?
+        {
+            Some(udtf) => {
+                let args = self.table_function_args(args)?;
+                (tbl_fn.to_string(), udtf.call(&args)?)
+            },
+            None => {
+                let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
+                let (path, lf) = read_fn.execute(args)?;
+                (path.to_str().to_string(), lf)
+            },
+        };
         #[allow(clippy::useless_asref)]
         let tbl_name = alias
             .as_ref()
             .map(|a| a.name.value.clone())
-            .unwrap_or_else(|| tbl_name.to_str().to_string());
+            .unwrap_or(tbl_name);
 
         self.table_map.insert(tbl_name.clone(), lf.clone());
         Ok((tbl_name, lf))
     }
 
-    fn process_order_by(
+    /// Evaluate the arguments of a user defined table function, which must be literals.
+    fn table_function_args(&mut self, args: &[FunctionArg]) -> PolarsResult<Vec<Scalar>> {
+        args.iter()
+            .map(|arg| match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => {
+                    match parse_sql_expr(expr, self, None)? {
+                        Expr::Literal(lv) => {
+                            let av = lv
+                                .to_any_value()
+                                .ok_or_else(|| polars_err!(SQLInterface: "invalid literal value: {:?}", lv))?
+                                .into_static();
+                            Ok(Scalar::new(av.dtype(), av))
+                        },
+                        e => polars_bail!(SQLInterface: "table function arguments must be literals; found {}", e),
+                    }
+                },
+                _ => polars_bail!(SQLInterface: "unsupported table function argument: {}", arg),
+            })
+            .collect()
+    }
+
+    pub(crate) fn process_order_by(
         &mut self,
         mut lf: LazyFrame,
         order_by: &Option<OrderBy>,
@@ -1265,15 +2791,22 @@ impl SQLContext {
                 by.extend(columns_iter);
             };
             let desc_order = !order_by[0].asc.unwrap_or(true);
-            nulls_last.resize(by.len(), !order_by[0].nulls_first.unwrap_or(desc_order));
+            let nulls_first = order_by[0]
+                .nulls_first
+                .unwrap_or_else(|| self.dialect.nulls_first(desc_order));
+            nulls_last.resize(by.len(), !nulls_first);
             descending.resize(by.len(), desc_order);
         } else {
             let columns = &columns_iter.collect::<Vec<_>>();
             for ob in order_by {
-                // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
+                // note: if not specified the dialect determines the position of NULLs; for
+                // PostgreSQL, 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
                 // https://www.postgresql.org/docs/current/queries-order.html
                 let desc_order = !ob.asc.unwrap_or(true);
-                nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
+                nulls_last.push(
+                    !ob.nulls_first
+                        .unwrap_or_else(|| self.dialect.nulls_first(desc_order)),
+                );
                 descending.push(desc_order);
 
                 // translate order expression, allowing ordinal values
@@ -1295,16 +2828,141 @@ impl SQLContext {
         ))
     }
 
+    /// Translate the GROUP BY items into the distinct key expressions and, if they contain
+    /// GROUPING SETS, ROLLUP or CUBE, the grouping sets (as indices into the keys).
+    fn process_group_by_items(
+        &mut self,
+        items: &[SQLExpr],
+        modifiers: &[GroupByWithModifier],
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Option<Vec<Vec<usize>>>)> {
+        let mut keys = Vec::with_capacity(items.len());
+        let mut key_indices = |ctx: &mut Self, exprs: &[SQLExpr]| -> PolarsResult<Vec<usize>> {
+            exprs
+                .iter()
+                .map(|e| {
+                    // translate the group expressions, allowing ordinal values
+                    let key =
+                        ctx.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY")?;
+                    Ok(match keys.iter().position(|k| k == &key) {
+                        Some(idx) => idx,
+                        None => {
+                            keys.push(key);
+                            keys.len() - 1
+                        },
+                    })
+                })
+                .collect()
+        };
+
+        // Every item contributes one or more grouping sets, the grouping sets of the GROUP BY
+        // clause are their cross product.
+        let mut has_grouping_sets = false;
+        let mut grouping_sets: Vec<Vec<usize>> = vec![vec![]];
+        for item in items {
+            let item_sets = match item {
+                SQLExpr::GroupingSets(sets) => sets
+                    .iter()
+                    .map(|set| key_indices(self, set))
+                    .collect::<PolarsResult<Vec<_>>>()?,
+                SQLExpr::Rollup(elements) => rollup_sets(
+                    &elements
+                        .iter()
+                        .map(|element| key_indices(self, element))
+                        .collect::<PolarsResult<Vec<_>>>()?,
+                ),
+                SQLExpr::Cube(elements) => cube_sets(
+                    &elements
+                        .iter()
+                        .map(|element| key_indices(self, element))
+                        .collect::<PolarsResult<Vec<_>>>()?,
+                )?,
+                e => vec![key_indices(self, std::slice::from_ref(e))?],
+            };
+            has_grouping_sets |= matches!(
+                item,
+                SQLExpr::GroupingSets(_) | SQLExpr::Rollup(_) | SQLExpr::Cube(_)
+            );
+            grouping_sets = grouping_sets
+                .iter()
+                .flat_map(|set| {
+                    item_sets.iter().map(move |item_set| {
+                        let mut set = set.clone();
+                        for &idx in item_set {
+                            if !set.contains(&idx) {
+                                set.push(idx);
+                            }
+                        }
+                        set
+                    })
+                })
+                .collect();
+        }
+
+        // "GROUP BY x, y WITH ROLLUP" syntax
+        if let Some(modifier) = modifiers.first() {
+            if has_grouping_sets || modifiers.len() > 1 {
+                polars_bail!(SQLSyntax: "GROUP BY modifiers cannot be combined with other modifiers, GROUPING SETS, ROLLUP, or CUBE")
+            }
+            let elements = (0..keys.len()).map(|idx| vec![idx]).collect::<Vec<_>>();
+            grouping_sets = match modifier {
+                GroupByWithModifier::Rollup => rollup_sets(&elements),
+                GroupByWithModifier::Cube => cube_sets(&elements)?,
+                _ => {
+                    polars_bail!(SQLInterface: "GROUP BY does not support the '{}' modifier", modifier)
+                },
+            };
+            has_grouping_sets = true;
+        }
+        Ok((keys, has_grouping_sets.then_some(grouping_sets)))
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<&[Vec<usize>]>,
         projections: &[Expr],
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = self.get_frame_schema(&mut lf)?;
-        let group_by_keys_schema =
+        let mut schema_before = self.get_frame_schema(&mut lf)?;
+        let mut group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, Context::Default)?;
 
+        // GROUPING() refers to the indicator columns of the keys; without grouping sets all rows
+        // are grouped by all keys.
+        let indicators = projections
+            .iter()
+            .flat_map(|e| e.into_iter())
+            .filter_map(|e| match e {
+                Expr::Column(name) => name.strip_prefix(GROUPING_INDICATOR_PREFIX),
+                _ => None,
+            })
+            .collect::<PlHashSet<_>>();
+        if let Some(name) = indicators
+            .iter()
+            .find(|name| !group_by_keys_schema.contains(name))
+        {
+            polars_bail!(SQLSyntax: "GROUPING() arguments must be GROUP BY keys; found '{}'", name)
+        }
+        let grouping_sets = match grouping_sets {
+            Some(grouping_sets) => Some(grouping_sets.to_vec()),
+            None if !indicators.is_empty() => Some(vec![(0..group_by_keys.len()).collect()]),
+            None => None,
+        };
+        if grouping_sets.is_some() {
+            let indicator_names = group_by_keys_schema
+                .iter_names()
+                .map(|name| format_pl_smallstr!("{GROUPING_INDICATOR_PREFIX}{name}"))
+                .collect::<Vec<_>>();
+            let mut schema = schema_before.as_ref().clone();
+            for name in indicator_names {
+                schema.with_column(name.clone(), DataType::Int32);
+                group_by_keys_schema.with_column(name, DataType::Int32);
+            }
+            schema_before = Arc::new(schema);
+        }
+
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
         let mut projection_overrides = PlHashMap::with_capacity(projections.len());
@@ -1371,7 +3029,13 @@ impl SQLContext {
                 polars_bail!(SQLSyntax: "Unsupported operation in the GROUP BY clause: {}", e);
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated = match grouping_sets {
+            Some(grouping_sets) => lf
+                .group_by_grouping_sets(group_by_keys, grouping_sets)
+                .with_grouping_indicators(PlSmallStr::from_static(GROUPING_INDICATOR_PREFIX))
+                .agg(&aggregation_projection)?,
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+        };
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
 
@@ -1402,7 +3066,20 @@ impl SQLContext {
         limit: &Option<SQLExpr>,
         offset: &Option<Offset>,
     ) -> PolarsResult<LazyFrame> {
-        match (offset, limit) {
+        let limit = limit
+            .as_ref()
+            .map(|expr| self.bind_limit_offset_param(expr))
+            .transpose()?;
+        let offset = offset
+            .as_ref()
+            .map(|offset| {
+                Ok::<_, PolarsError>(Offset {
+                    value: self.bind_limit_offset_param(&offset.value)?,
+                    ..offset.clone()
+                })
+            })
+            .transpose()?;
+        match (&offset, &limit) {
             (
                 Some(Offset {
                     value: SQLExpr::Value(SQLValue::Number(offset, _)),
@@ -1441,6 +3118,24 @@ impl SQLContext {
         }
     }
 
+    /// Substitute an (integer) bind parameter in LIMIT/OFFSET with the equivalent literal.
+    fn bind_limit_offset_param(&self, expr: &SQLExpr) -> PolarsResult<SQLExpr> {
+        match expr {
+            SQLExpr::Value(SQLValue::Placeholder(p)) => {
+                let value = self.params.get(p)?;
+                match value.value().extract::<i64>() {
+                    Some(n) if value.dtype().is_integer() => {
+                        Ok(SQLExpr::Value(SQLValue::Number(n.to_string(), false)))
+                    },
+                    _ => polars_bail!(
+                        SQLSyntax: "LIMIT/OFFSET parameter {} must be bound to an integer; found {:?}", p, value.value()
+                    ),
+                }
+            },
+            _ => Ok(expr.clone()),
+        }
+    }
+
     fn process_qualified_wildcard(
         &mut self,
         ObjectName(idents): &ObjectName,
@@ -1528,7 +3223,7 @@ impl SQLContext {
         Ok(exprs)
     }
 
-    fn rename_columns_from_table_alias(
+    pub(crate) fn rename_columns_from_table_alias(
         &mut self,
         mut lf: LazyFrame,
         alias: &TableAlias,
@@ -1567,6 +3262,83 @@ impl SQLContext {
     }
 }
 
+/// Parse (and validate) a time zone name, eg: 'Europe/Amsterdam' or '+01:00'.
+pub(crate) fn parse_time_zone(time_zone: &str) -> PolarsResult<TimeZone> {
+    TimeZone::opt_try_new(Some(time_zone))?
+        .ok_or_else(|| polars_err!(SQLSyntax: "invalid time zone '{}'", time_zone))
+}
+
+/// Parse a single SQL statement.
+///
+/// Anonymous `?` placeholders are numbered from left to right (as `?1`, `?2`, ...), so that
+/// they bind to positional parameter values independently of the order they are translated in.
+///
+/// Case-insensitive identifiers resolve to the names given by `known_names`, which is called
+/// with the lowercased words of the statement.
+fn parse_statement(
+    query: &str,
+    dialect: SQLDialect,
+    known_names: impl FnOnce(&PlHashSet<String>) -> PolarsResult<PlHashSet<String>>,
+) -> PolarsResult<Statement> {
+    let options = ParserOptions {
+        trailing_commas: true,
+        ..Default::default()
+    };
+    let parser_dialect = dialect.parser_dialect();
+    let mut tokens = Tokenizer::new(parser_dialect.as_ref(), query)
+        .with_unescape(options.unescape)
+        .tokenize_with_location()
+        .map_err(to_sql_interface_err)?;
+    let known_names = match dialect.identifier_case() {
+        IdentifierCase::Insensitive => known_names(
+            &tokens
+                .iter()
+                .filter_map(|tok| match &tok.token {
+                    Token::Word(w) => Some(w.value.to_lowercase()),
+                    _ => None,
+                })
+                .collect(),
+        )?,
+        _ => PlHashSet::new(),
+    };
+    let mut n_placeholders = 0;
+    // case-insensitive identifiers resolve to a known name, or else to their first spelling
+    let mut spellings = PlHashMap::new();
+    for name in known_names {
+        spellings
+            .entry(name.to_lowercase())
+            .or_insert_with(|| name.clone());
+    }
+    for tok in tokens.iter_mut() {
+        match &mut tok.token {
+            Token::Placeholder(p) if p == "?" => {
+                n_placeholders += 1;
+                tok.token = Token::Placeholder(format!("?{n_placeholders}"));
+            },
+            Token::Word(w) if w.quote_style.is_none() => match dialect.identifier_case() {
+                IdentifierCase::Sensitive => {},
+                IdentifierCase::Lowercase => w.value = w.value.to_lowercase(),
+                IdentifierCase::Insensitive if !known_names.contains(&w.value) => {
+                    w.value = spellings
+                        .entry(w.value.to_lowercase())
+                        .or_insert_with(|| w.value.clone())
+                        .clone();
+                },
+                IdentifierCase::Insensitive => {},
+            },
+            _ => {},
+        }
+    }
+    let ast = Parser::new(parser_dialect.as_ref())
+        .with_options(options)
+        .with_tokens_with_locations(tokens)
+        .parse_statements()
+        .map_err(to_sql_interface_err)?;
+
+    polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
+    Ok(ast.into_iter().next().unwrap())
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -1588,6 +3360,156 @@ fn collect_compound_identifiers(
     }
 }
 
+/// The row numbers requested for the elements of an UNNEST.
+enum UnnestOrdinal {
+    /// `WITH ORDINALITY` (PostgreSQL); numbered from 1, and named by an extra column in
+    /// the table alias (or "ordinality").
+    Ordinality,
+    /// `WITH OFFSET [AS name]` (BigQuery); numbered from 0, named "offset" by default.
+    Offset(Option<PlSmallStr>),
+}
+
+impl UnnestOrdinal {
+    fn new(with_ordinality: bool, with_offset: bool, offset_alias: &Option<Ident>) -> Option<Self> {
+        if with_ordinality {
+            Some(Self::Ordinality)
+        } else if with_offset {
+            Some(Self::Offset(
+                offset_alias.as_ref().map(|a| a.value.as_str().into()),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// The name of the ordinal column, taking it from the alias `column_names` (which
+    /// name the `n_arrays` arrays) when they include it.
+    fn column_name(&self, column_names: &mut Vec<PlSmallStr>, n_arrays: usize) -> PlSmallStr {
+        match self {
+            Self::Ordinality if column_names.len() == n_arrays + 1 => column_names.pop().unwrap(),
+            Self::Ordinality => PlSmallStr::from_static("ordinality"),
+            Self::Offset(name) => name
+                .clone()
+                .unwrap_or_else(|| PlSmallStr::from_static("offset")),
+        }
+    }
+}
+
+/// Explode the `arrays` of each row of `lf` (into the `names` columns), zipping them together
+/// as PostgreSQL does: arrays shorter than the longest array of the row are padded with NULLs.
+/// Rows with only empty (or NULL) arrays are dropped, or kept with NULLs for a LEFT join. The
+/// elements of each row are numbered from 1 in the `ordinality` column, if given.
+fn unnest_zipped(
+    lf: LazyFrame,
+    arrays: Vec<Expr>,
+    names: &[PlSmallStr],
+    ordinality: Option<PlSmallStr>,
+    how: JoinType,
+) -> LazyFrame {
+    const ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_UNNEST_ROW");
+    const ORDINAL: PlSmallStr = PlSmallStr::from_static("__POLARS_UNNEST_ORDINAL");
+
+    let lf = lf.with_row_index(ROW, None);
+    let elements = arrays
+        .into_iter()
+        .zip(names)
+        .map(|(array, name)| {
+            lf.clone()
+                .select([col(ROW), array.alias(name.clone())])
+                .filter(col(name.clone()).list().len().gt(lit(0)))
+                .explode(Selector::ByName {
+                    names: [name.clone()].into(),
+                    strict: true,
+                })
+                .with_column(col(ROW).cum_count(false).over([col(ROW)]).alias(ORDINAL))
+        })
+        .reduce(|left, right| {
+            left.join(
+                right,
+                [col(ROW), col(ORDINAL)],
+                [col(ROW), col(ORDINAL)],
+                JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+            )
+        })
+        .expect("UNNEST has at least one array");
+
+    let joined = lf
+        .join(elements, [col(ROW)], [col(ROW)], JoinArgs::new(how))
+        .sort_by_exprs(
+            [col(ROW), col(ORDINAL)],
+            SortMultipleOptions::default()
+                .with_nulls_last(true)
+                .with_maintain_order(true),
+        );
+    let joined = match ordinality {
+        Some(name) => joined.with_column(col(ORDINAL).cast(DataType::Int64).alias(name)),
+        None => joined,
+    };
+    joined.drop(Selector::ByName {
+        names: [ROW, ORDINAL].into(),
+        strict: true,
+    })
+}
+
+/// Whether a relation may reference the relations before it in the FROM clause.
+fn is_lateral(relation: &TableFactor) -> bool {
+    match relation {
+        TableFactor::Derived { lateral, .. } | TableFactor::Function { lateral, .. } => *lateral,
+        // UNNEST of array columns, rather than of array literals
+        TableFactor::UNNEST { array_exprs, .. } => {
+            array_exprs.iter().any(|e| any_identifier(e, |_| true))
+        },
+        _ => false,
+    }
+}
+
+/// Take the equalities between the columns of the `left` and `right` relations out of the
+/// conjunction in `selection`, and return them as a join predicate.
+fn take_join_keys(
+    selection: &mut Option<SQLExpr>,
+    left: &PlHashSet<String>,
+    right: &PlHashSet<String>,
+) -> Option<SQLExpr> {
+    let qualifier = |e: &SQLExpr| match e {
+        SQLExpr::CompoundIdentifier(idents) if idents.len() == 2 => Some(idents[0].value.clone()),
+        _ => None,
+    };
+    let is_join_key = |e: &&SQLExpr| match e {
+        SQLExpr::BinaryOp {
+            left: a,
+            op: BinaryOperator::Eq,
+            right: b,
+        } => match (qualifier(a), qualifier(b)) {
+            (Some(a), Some(b)) => {
+                (left.contains(&a) && right.contains(&b))
+                    || (right.contains(&a) && left.contains(&b))
+            },
+            _ => false,
+        },
+        _ => false,
+    };
+    let conjunction = |exprs: Vec<&SQLExpr>| {
+        exprs
+            .into_iter()
+            .cloned()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            })
+    };
+
+    let (keys, remaining): (Vec<_>, Vec<_>) = split_conjunction(selection.as_ref()?)
+        .into_iter()
+        .partition(is_join_key);
+    if keys.is_empty() {
+        return None;
+    }
+    let (on, remaining) = (conjunction(keys), conjunction(remaining));
+    *selection = remaining;
+    on
+}
+
 fn expand_exprs(expr: Expr, schema: &SchemaRef) -> Vec<Expr> {
     match expr {
         Expr::Column(nm) if is_regex_colname(nm.as_str()) => {
@@ -1608,6 +3530,92 @@ fn expand_exprs(expr: Expr, schema: &SchemaRef) -> Vec<Expr> {
     }
 }
 
+/// Resolve `OVER (name ORDER BY ...)`: the window inherits the partitioning, and unless it
+/// specifies them itself, the ordering and frame of the named window.
+fn resolve_window_spec(
+    spec: &WindowSpec,
+    get_named: impl Fn(&str) -> Option<WindowSpec>,
+) -> PolarsResult<WindowSpec> {
+    let Some(name) = &spec.window_name else {
+        return Ok(spec.clone());
+    };
+    let base = get_named(&name.value)
+        .ok_or_else(|| polars_err!(SQLSyntax: "window '{}' is not defined", name))?;
+    if !spec.partition_by.is_empty() {
+        polars_bail!(SQLSyntax: "cannot override PARTITION BY of window '{}'", name)
+    }
+    Ok(WindowSpec {
+        window_name: None,
+        partition_by: base.partition_by,
+        order_by: if spec.order_by.is_empty() {
+            base.order_by
+        } else {
+            spec.order_by.clone()
+        },
+        window_frame: spec.window_frame.clone().or(base.window_frame),
+    })
+}
+
+/// The grouping sets of `ROLLUP(e1, e2, ...)`: every prefix of the elements, longest first.
+fn rollup_sets(elements: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    (0..=elements.len())
+        .rev()
+        .map(|n| elements[..n].concat())
+        .collect()
+}
+
+/// The grouping sets of `CUBE(e1, e2, ...)`: every subset of the elements, largest first.
+fn cube_sets(elements: &[Vec<usize>]) -> PolarsResult<Vec<Vec<usize>>> {
+    let n = elements.len();
+    if n > 12 {
+        polars_bail!(SQLInterface: "CUBE supports at most 12 elements (found {})", n)
+    }
+    Ok((0..1usize << n)
+        .rev()
+        .map(|mask| {
+            (0..n)
+                .filter(|i| mask & (1 << (n - 1 - i)) != 0)
+                .flat_map(|i| elements[i].iter().copied())
+                .collect()
+        })
+        .collect())
+}
+
+/// Whether `expr` reads from the relation `name` in one of its `FROM` clauses.
+fn references_relation(expr: &SetExpr, name: &str) -> bool {
+    fn table_factor_references(relation: &TableFactor, name: &str) -> bool {
+        match relation {
+            TableFactor::Table { name: tbl, .. } => {
+                tbl.0.first().is_some_and(|ident| ident.value == name)
+            },
+            TableFactor::Derived { subquery, .. } => references_relation(&subquery.body, name),
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => table_with_joins_references(table_with_joins, name),
+            _ => false,
+        }
+    }
+    fn table_with_joins_references(tbl: &TableWithJoins, name: &str) -> bool {
+        table_factor_references(&tbl.relation, name)
+            || tbl
+                .joins
+                .iter()
+                .any(|join| table_factor_references(&join.relation, name))
+    }
+
+    match expr {
+        SetExpr::Select(select) => select
+            .from
+            .iter()
+            .any(|tbl| table_with_joins_references(tbl, name)),
+        SetExpr::Query(query) => references_relation(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            references_relation(left, name) || references_relation(right, name)
+        },
+        _ => false,
+    }
+}
+
 fn is_regex_colname(nm: &str) -> bool {
     nm.starts_with('^') && nm.ends_with('$')
 }
@@ -1616,47 +3624,64 @@ fn process_join_on(
     expression: &sqlparser::ast::Expr,
     tbl_left: &TableInfo,
     tbl_right: &TableInfo,
-) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+) -> PolarsResult<Option<(Vec<Expr>, Vec<Expr>)>> {
     match expression {
         SQLExpr::BinaryOp { left, op, right } => match op {
             BinaryOperator::And => {
-                let (mut left_i, mut right_i) = process_join_on(left, tbl_left, tbl_right)?;
-                let (mut left_j, mut right_j) = process_join_on(right, tbl_left, tbl_right)?;
+                let Some((mut left_i, mut right_i)) = process_join_on(left, tbl_left, tbl_right)?
+                else {
+                    return Ok(None);
+                };
+                let Some((mut left_j, mut right_j)) = process_join_on(right, tbl_left, tbl_right)?
+                else {
+                    return Ok(None);
+                };
                 left_i.append(&mut left_j);
                 right_i.append(&mut right_j);
-                Ok((left_i, right_i))
+                Ok(Some((left_i, right_i)))
             },
             BinaryOperator::Eq => match (left.as_ref(), right.as_ref()) {
-                (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right)) => {
-                    collect_compound_identifiers(left, right, &tbl_left.name, &tbl_right.name)
-                },
-                _ => {
-                    polars_bail!(SQLInterface: "only equi-join constraints (on identifiers) are currently supported; found lhs={:?}, rhs={:?}", left, right)
+                (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right))
+                    if left.len() == 2 && right.len() == 2 =>
+                {
+                    // Only an equality between columns of either side of the join is a join key.
+                    let is_right = |idents: &[Ident]| idents[0].value == tbl_right.name.as_str();
+                    if is_right(left) == is_right(right) {
+                        return Ok(None);
+                    }
+                    let (left_on, right_on) =
+                        collect_compound_identifiers(left, right, &tbl_left.name, &tbl_right.name)?;
+                    for (on, tbl) in [(&left_on, tbl_left), (&right_on, tbl_right)] {
+                        let name = expr_output_name(&on[0])?;
+                        if !tbl.schema.contains(&name) {
+                            polars_bail!(
+                                ColumnNotFound: "column '{}' not found in '{}'", name, tbl.name
+                            )
+                        }
+                    }
+                    Ok(Some((left_on, right_on)))
                 },
+                _ => Ok(None),
             },
-            _ => {
-                polars_bail!(SQLInterface: "only equi-join constraints (combined with 'AND') are currently supported; found op = '{:?}'", op)
-            },
+            _ => Ok(None),
         },
         SQLExpr::Nested(expr) => process_join_on(expr, tbl_left, tbl_right),
-        _ => {
-            polars_bail!(SQLInterface: "only equi-join constraints are currently supported; found expression = {:?}", expression)
-        },
+        _ => Ok(None),
     }
 }
 
+/// Translate the join constraint into equi-join keys. Returns `None` for ON clauses that are not
+/// (a conjunction of) equalities between columns, which are joined on the ON predicate instead.
 fn process_join_constraint(
     constraint: &JoinConstraint,
     tbl_left: &TableInfo,
     tbl_right: &TableInfo,
-) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+) -> PolarsResult<Option<(Vec<Expr>, Vec<Expr>)>> {
     match constraint {
-        JoinConstraint::On(expr @ SQLExpr::BinaryOp { .. }) => {
-            process_join_on(expr, tbl_left, tbl_right)
-        },
+        JoinConstraint::On(expr) => process_join_on(expr, tbl_left, tbl_right),
         JoinConstraint::Using(idents) if !idents.is_empty() => {
             let using: Vec<Expr> = idents.iter().map(|id| col(id.value.as_str())).collect();
-            Ok((using.clone(), using))
+            Ok(Some((using.clone(), using)))
         },
         JoinConstraint::Natural => {
             let left_names = tbl_left.schema.iter_names().collect::<PlHashSet<_>>();
@@ -1668,7 +3693,7 @@ fn process_join_constraint(
             if on.is_empty() {
                 polars_bail!(SQLInterface: "no common columns found for NATURAL JOIN")
             }
-            Ok((on.clone(), on))
+            Ok(Some((on.clone(), on)))
         },
         _ => polars_bail!(SQLInterface: "unsupported SQL join constraint:\n{:?}", constraint),
     }
@@ -0,0 +1,116 @@
+//! The SQL dialects that can be selected for a [`SQLContext`](crate::SQLContext).
+use std::str::FromStr;
+
+use polars_core::prelude::{PolarsError, polars_bail};
+use sqlparser::dialect::{
+    Dialect, DuckDbDialect, GenericDialect, MySqlDialect, PostgreSqlDialect, SnowflakeDialect,
+};
+
+/// The SQL dialect of the queries executed by a [`SQLContext`], see
+/// [`SQLContext::with_dialect`].
+///
+/// Besides the syntax accepted by the parser (and the identifier quote characters), the
+/// dialect determines how unquoted identifiers are resolved, the semantics of integer
+/// division, the default position of NULL values in an ORDER BY, and function aliases.
+///
+/// [`SQLContext`]: crate::SQLContext
+/// [`SQLContext::with_dialect`]: crate::SQLContext::with_dialect
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SQLDialect {
+    /// The default dialect; PostgreSQL-like, with case-sensitive identifiers.
+    #[default]
+    Generic,
+    /// DuckDB; case-insensitive identifiers, and NULLs last in every ORDER BY.
+    DuckDB,
+    /// MySQL; case-insensitive (`backtick` quoted) identifiers, NULLs sort as the smallest
+    /// value, and `DIV` integer division.
+    MySQL,
+    /// PostgreSQL; unquoted identifiers fold to lowercase, NULLs sort as the largest value,
+    /// and dividing integers truncates the result.
+    PostgreSQL,
+    /// Snowflake; case-insensitive identifiers, NULLs sort as the largest value, and
+    /// Snowflake function aliases (such as `NVL`, `IFF`, `TIMEADD` and `TRUNC`).
+    Snowflake,
+}
+
+/// How unquoted identifiers are matched with the names of tables and columns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum IdentifierCase {
+    /// Identifiers are used as written.
+    Sensitive,
+    /// Identifiers are folded to lowercase.
+    Lowercase,
+    /// Identifiers match known names irrespective of case.
+    Insensitive,
+}
+
+impl SQLDialect {
+    /// The sqlparser dialect used to tokenize and parse queries.
+    pub(crate) fn parser_dialect(&self) -> Box<dyn Dialect> {
+        match self {
+            Self::Generic => Box::new(GenericDialect),
+            Self::DuckDB => Box::new(DuckDbDialect),
+            Self::MySQL => Box::new(MySqlDialect {}),
+            Self::PostgreSQL => Box::new(PostgreSqlDialect {}),
+            Self::Snowflake => Box::new(SnowflakeDialect),
+        }
+    }
+
+    pub(crate) fn identifier_case(&self) -> IdentifierCase {
+        match self {
+            Self::Generic => IdentifierCase::Sensitive,
+            Self::PostgreSQL => IdentifierCase::Lowercase,
+            Self::DuckDB | Self::MySQL | Self::Snowflake => IdentifierCase::Insensitive,
+        }
+    }
+
+    /// Whether dividing two integers truncates the result (instead of returning a float).
+    pub(crate) fn integer_division_truncates(&self) -> bool {
+        matches!(self, Self::PostgreSQL)
+    }
+
+    /// Whether NULL values come first in an ORDER BY that doesn't specify NULLS FIRST/LAST.
+    pub(crate) fn nulls_first(&self, descending: bool) -> bool {
+        match self {
+            Self::Generic | Self::PostgreSQL | Self::Snowflake => descending,
+            Self::MySQL => !descending,
+            Self::DuckDB => false,
+        }
+    }
+
+    /// The (lowercase) name of the function that a dialect-specific function name is an
+    /// alias of, if any.
+    pub(crate) fn function_alias(&self, name: &str) -> Option<&'static str> {
+        Some(match (self, name) {
+            (Self::Snowflake, "nvl") => "ifnull",
+            (Self::Snowflake, "iff") => "if",
+            (Self::Snowflake, "timeadd" | "timestampadd") => "dateadd",
+            (Self::MySQL, "timestampadd") => "dateadd",
+            (Self::Snowflake, "trunc" | "truncate") => "date_trunc",
+            (Self::DuckDB, "datetrunc") => "date_trunc",
+            _ => return None,
+        })
+    }
+
+    /// Whether the date part follows the date (or datetime) in the arguments of the date
+    /// truncation function `name`, as in Snowflake's `TRUNC(<date>, <part>)`; otherwise the
+    /// part comes first, as in `DATE_TRUNC(<part>, <date>)`.
+    pub(crate) fn date_trunc_part_last(&self, name: &str) -> bool {
+        matches!((self, name), (Self::Snowflake, "trunc" | "truncate"))
+    }
+}
+
+impl FromStr for SQLDialect {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "generic" => Self::Generic,
+            "duckdb" => Self::DuckDB,
+            "mysql" => Self::MySQL,
+            "postgres" | "postgresql" => Self::PostgreSQL,
+            "snowflake" => Self::Snowflake,
+            _ => polars_bail!(SQLInterface: "unknown SQL dialect '{}'", s),
+        })
+    }
+}
@@ -1,8 +1,16 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
+use std::sync::Arc;
+
+use polars_core::prelude::Scalar;
 use polars_error::{PolarsResult, polars_bail};
+use polars_lazy::prelude::LazyFrame;
+use polars_plan::dsl::Expr;
 use polars_plan::prelude::udf::UserDefinedFunction;
 pub use polars_plan::prelude::{Context, FunctionOptions};
+use polars_plan::utils::has_expr;
+use polars_utils::pl_str::PlSmallStr;
+
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
@@ -11,6 +19,97 @@ pub trait FunctionRegistry: Send + Sync {
     fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>>;
     /// Check if a function is registered.
     fn contains(&self, name: &str) -> bool;
+
+    /// Register an aggregate function.
+    fn register_aggregate(
+        &mut self,
+        _name: &str,
+        _fun: UserDefinedAggregateFunction,
+    ) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "'register_aggregate' not implemented on this FunctionRegistry")
+    }
+    /// Get a user defined aggregate function.
+    fn get_udaf(&self, _name: &str) -> PolarsResult<Option<UserDefinedAggregateFunction>> {
+        Ok(None)
+    }
+    /// Register a table function.
+    fn register_table_function(
+        &mut self,
+        _name: &str,
+        _fun: UserDefinedTableFunction,
+    ) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "'register_table_function' not implemented on this FunctionRegistry")
+    }
+    /// Get a user defined table function.
+    fn get_table_function(&self, _name: &str) -> PolarsResult<Option<UserDefinedTableFunction>> {
+        Ok(None)
+    }
+}
+
+type AggregateFn = dyn Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync;
+type TableFn = dyn Fn(&[Scalar]) -> PolarsResult<LazyFrame> + Send + Sync;
+
+/// A user defined aggregate function, that can be used with GROUP BY and OVER.
+///
+/// The function builds the aggregation from the expressions of its arguments, eg:
+/// `|args| Ok(args[0].clone().sum() / args[0].clone().count())`; opaque aggregations
+/// can `implode` the argument and map the resulting (per-group) list.
+#[derive(Clone)]
+pub struct UserDefinedAggregateFunction {
+    /// The name of the function.
+    pub name: PlSmallStr,
+    function: Arc<AggregateFn>,
+}
+
+impl UserDefinedAggregateFunction {
+    /// Create a new aggregate function.
+    pub fn new(
+        name: PlSmallStr,
+        function: impl Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            function: Arc::new(function),
+        }
+    }
+
+    /// Build the aggregation over the given arguments.
+    pub fn call(&self, args: Vec<Expr>) -> PolarsResult<Expr> {
+        let expr = (self.function)(args)?;
+        if !has_expr(&expr, |e| matches!(e, Expr::Agg(_) | Expr::Len)) {
+            polars_bail!(SQLInterface: "aggregate function '{}' does not aggregate; found {}", self.name, expr)
+        }
+        Ok(expr)
+    }
+}
+
+/// A user defined table function, returning a [`LazyFrame`] from its (literal) arguments:
+/// ```sql
+/// SELECT * FROM my_source('x', 3)
+/// ```
+#[derive(Clone)]
+pub struct UserDefinedTableFunction {
+    /// The name of the function.
+    pub name: PlSmallStr,
+    function: Arc<TableFn>,
+}
+
+impl UserDefinedTableFunction {
+    /// Create a new table function.
+    pub fn new(
+        name: PlSmallStr,
+        function: impl Fn(&[Scalar]) -> PolarsResult<LazyFrame> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            function: Arc::new(function),
+        }
+    }
+
+    /// Call the function with the given arguments.
+    pub fn call(&self, args: &[Scalar]) -> PolarsResult<LazyFrame> {
+        (self.function)(args)
+    }
 }
 
 /// A default registry that does not support registering or calling functions.
@@ -1,26 +1,38 @@
 use std::ops::Sub;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, FillNullStrategy, IDX_DTYPE, NonExistent, PolarsResult, QuantileMethod,
+    RollingOptionsFixedWindow, Schema, TimeUnit, polars_bail, polars_err,
 };
 use polars_lazy::dsl::Expr;
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::series::RoundMode;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_plan::dsl::{
+    WindowMapping, coalesce, concat_str, len, max_horizontal, min_horizontal, when,
+};
 use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_plan::utils::expr_output_name;
+use polars_time::Duration;
+use polars_time::prelude::{ClosedWindow, RollingOptionsDynamicWindow};
+use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    NullTreatment, OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits,
+    WindowSpec, WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::context::GROUPING_INDICATOR_PREFIX;
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -284,6 +296,38 @@ pub(crate) enum PolarsSQLFunctions {
     // ----
     // Temporal functions
     // ----
+    /// SQL 'current_date' function.
+    /// Returns the current date (in the session time zone).
+    /// ```sql
+    /// SELECT CURRENT_DATE FROM df;
+    /// ```
+    CurrentDate,
+    /// SQL 'current_timestamp' function.
+    /// Returns the current timestamp, in the session time zone.
+    /// ```sql
+    /// SELECT CURRENT_TIMESTAMP FROM df;
+    /// SELECT NOW() FROM df;
+    /// ```
+    CurrentTimestamp,
+    /// SQL 'localtimestamp' function.
+    /// Returns the current (naive) local timestamp in the session time zone.
+    /// ```sql
+    /// SELECT LOCALTIMESTAMP FROM df;
+    /// ```
+    LocalTimestamp,
+    /// SQL 'dateadd' function.
+    /// Adds a number of date/time parts (such as 'day', 'month', etc) to a date or datetime.
+    /// ```sql
+    /// SELECT DATEADD('month', 3, column_1) FROM df;
+    /// SELECT DATEADD(day, -1, column_1) FROM df;
+    /// ```
+    DateAdd,
+    /// SQL 'date_trunc' function.
+    /// Truncates a date (or datetime) to the start of the given part, eg: 'month'.
+    /// ```sql
+    /// SELECT DATE_TRUNC('month', column_1) FROM df;
+    /// ```
+    DateTrunc,
     /// SQL 'date_part' function.
     /// Extracts a part of a date (or datetime) such as 'year', 'month', etc.
     /// ```sql
@@ -573,6 +617,27 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT MEDIAN(column_1) FROM df;
     /// ```
     Median,
+    /// SQL 'mode' ordered-set aggregate function.
+    /// Returns the most frequent value of the ordering expression; ties are
+    /// resolved in favour of the value that sorts first.
+    /// ```sql
+    /// SELECT MODE() WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// ```
+    Mode,
+    /// SQL 'percentile_cont' ordered-set aggregate function.
+    /// Returns the continuous percentile of the ordering expression
+    /// (interpolated value between two closest values).
+    /// ```sql
+    /// SELECT PERCENTILE_CONT(0.25) WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// ```
+    PercentileCont,
+    /// SQL 'percentile_disc' ordered-set aggregate function.
+    /// Returns the first value of the ordering expression whose position in
+    /// the ordering equals or exceeds the given fraction.
+    /// ```sql
+    /// SELECT PERCENTILE_DISC(0.25) WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// ```
+    PercentileDisc,
     /// SQL 'quantile_cont' function.
     /// Returns the continuous quantile element from the grouping
     /// (interpolated value between two closest values).
@@ -611,6 +676,56 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT VARIANCE(column_1) FROM df;
     /// ```
     Variance,
+
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'dense_rank' function.
+    /// Returns the rank of the current row in the window, without gaps.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'first_value' function.
+    /// Returns the value of the first row in the window frame.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) IGNORE NULLS OVER (ORDER BY column_2) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'lag' function.
+    /// Returns the value of the row at the given offset (default 1) before the
+    /// current row, or a default value (default NULL) if there is none.
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'last_value' function.
+    /// Returns the value of the last row in the window frame.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) IGNORE NULLS OVER (ORDER BY column_2) FROM df;
+    /// ```
+    LastValue,
+    /// SQL 'lead' function.
+    /// Returns the value of the row at the given offset (default 1) after the
+    /// current row, or a default value (default NULL) if there is none.
+    /// ```sql
+    /// SELECT LEAD(column_1) IGNORE NULLS OVER (ORDER BY column_2) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'rank' function.
+    /// Returns the rank of the current row in the window, with gaps; rows with equal
+    /// ORDER BY values have the same rank.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'row_number' function.
+    /// Returns the number of the current row in the window, starting from 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -688,6 +803,17 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     ArrayContains,
 
+    // ----
+    // Grouping functions
+    // ----
+    /// SQL 'grouping' function.
+    /// Returns a bitmask telling which of the given GROUP BY keys are aggregated over (1)
+    /// rather than grouped by (0) in the current row; used with GROUPING SETS, ROLLUP and CUBE.
+    /// ```sql
+    /// SELECT a, b, GROUPING(a, b), SUM(c) FROM df GROUP BY ROLLUP(a, b);
+    /// ```
+    Grouping,
+
     // ----
     // Column selection
     // ----
@@ -697,6 +823,7 @@ pub(crate) enum PolarsSQLFunctions {
     // User-defined
     // ----
     Udf(String),
+    Udaf(String),
 }
 
 impl PolarsSQLFunctions {
@@ -745,18 +872,29 @@ impl PolarsSQLFunctions {
             "covar",
             "covar_pop",
             "covar_samp",
+            "current_date",
+            "current_timestamp",
             "date",
             "date_part",
+            "date_trunc",
+            "dateadd",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
+            "grouping",
+            "grouping_id",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -765,6 +903,7 @@ impl PolarsSQLFunctions {
             "log10",
             "log1p",
             "log2",
+            "localtimestamp",
             "lower",
             "ltrim",
             "max",
@@ -772,19 +911,25 @@ impl PolarsSQLFunctions {
             "quantile_disc",
             "min",
             "mod",
+            "mode",
+            "now",
             "nullif",
             "octet_length",
+            "percentile_cont",
+            "percentile_disc",
             "pi",
             "pow",
             "power",
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -814,6 +959,10 @@ impl PolarsSQLFunctions {
 impl PolarsSQLFunctions {
     fn try_from_sql(function: &'_ SQLFunction, ctx: &'_ SQLContext) -> PolarsResult<Self> {
         let function_name = function.name.0[0].value.to_lowercase();
+        let function_name = match ctx.dialect.function_alias(&function_name) {
+            Some(name) => name.to_string(),
+            None => function_name,
+        };
         Ok(match function_name.as_str() {
             // ----
             // Bitwise functions
@@ -880,7 +1029,12 @@ impl PolarsSQLFunctions {
             // ----
             // Date functions
             // ----
+            "current_date" => Self::CurrentDate,
+            "current_timestamp" | "now" => Self::CurrentTimestamp,
             "date_part" => Self::DatePart,
+            "date_trunc" => Self::DateTrunc,
+            "dateadd" => Self::DateAdd,
+            "localtimestamp" => Self::LocalTimestamp,
             "strftime" => Self::Strftime,
 
             // ----
@@ -926,6 +1080,9 @@ impl PolarsSQLFunctions {
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
+            "mode" => Self::Mode,
+            "percentile_cont" => Self::PercentileCont,
+            "percentile_disc" => Self::PercentileDisc,
             "quantile_cont" => Self::QuantileCont,
             "quantile_disc" => Self::QuantileDisc,
             "min" => Self::Min,
@@ -933,6 +1090,17 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "dense_rank" => Self::DenseRank,
+            "first_value" => Self::FirstValue,
+            "lag" => Self::Lag,
+            "last_value" => Self::LastValue,
+            "lead" => Self::Lead,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -954,9 +1122,16 @@ impl PolarsSQLFunctions {
             // ----
             "columns" => Self::Columns,
 
+            // ----
+            // Grouping functions
+            // ----
+            "grouping" | "grouping_id" => Self::Grouping,
+
             other => {
                 if ctx.function_registry.contains(other) {
                     Self::Udf(other.to_string())
+                } else if ctx.function_registry.get_udaf(other)?.is_some() {
+                    Self::Udaf(other.to_string())
                 } else {
                     polars_bail!(SQLInterface: "unsupported function '{}'", other);
                 }
@@ -965,21 +1140,141 @@ impl PolarsSQLFunctions {
     }
 }
 
+/// Aggregate functions that can be evaluated over a window frame (`ROWS/RANGE BETWEEN ...`).
+#[derive(Clone, Copy, PartialEq)]
+enum FrameAggregate {
+    Count,
+    Max,
+    Mean,
+    Median,
+    Min,
+    StdDev,
+    Sum,
+    Variance,
+}
+
+impl FrameAggregate {
+    fn from_function(function: &PolarsSQLFunctions) -> Option<Self> {
+        Some(match function {
+            PolarsSQLFunctions::Avg => Self::Mean,
+            PolarsSQLFunctions::Count => Self::Count,
+            PolarsSQLFunctions::Max => Self::Max,
+            PolarsSQLFunctions::Median => Self::Median,
+            PolarsSQLFunctions::Min => Self::Min,
+            PolarsSQLFunctions::StdDev => Self::StdDev,
+            PolarsSQLFunctions::Sum => Self::Sum,
+            PolarsSQLFunctions::Variance => Self::Variance,
+            _ => return None,
+        })
+    }
+
+    /// The aggregate over the whole partition.
+    fn aggregate(self, e: Expr) -> Expr {
+        match self {
+            Self::Count | Self::Sum => e.sum(),
+            Self::Max => e.max(),
+            Self::Mean => e.mean(),
+            Self::Median => e.median(),
+            Self::Min => e.min(),
+            Self::StdDev => e.std(1),
+            Self::Variance => e.var(1),
+        }
+    }
+
+    /// The aggregate over the rows from the start (or to the end) of the partition.
+    fn cumulative(self, e: Expr, reverse: bool) -> Option<Expr> {
+        let cumulative = match self {
+            Self::Count | Self::Sum => e.cum_sum(reverse),
+            Self::Max => e.cum_max(reverse),
+            Self::Min => e.cum_min(reverse),
+            Self::Mean => e.clone().cum_sum(reverse) / e.cum_count(reverse),
+            Self::Median | Self::StdDev | Self::Variance => return None,
+        };
+        // Cumulative functions are null where the input is null.
+        Some(cumulative.fill_null_with_strategy(if reverse {
+            FillNullStrategy::Backward(None)
+        } else {
+            FillNullStrategy::Forward(None)
+        }))
+    }
+
+    /// The aggregate over a window of a fixed number of rows, ending at the current row.
+    fn rolling(self, e: Expr, options: RollingOptionsFixedWindow) -> Expr {
+        match self {
+            Self::Count | Self::Sum => e.rolling_sum(options),
+            Self::Max => e.rolling_max(options),
+            Self::Mean => e.rolling_mean(options),
+            Self::Median => e.rolling_median(options),
+            Self::Min => e.rolling_min(options),
+            Self::StdDev => e.rolling_std(options),
+            Self::Variance => e.rolling_var(options),
+        }
+    }
+
+    /// The aggregate over a window of a fixed duration, ending at the current row.
+    fn rolling_by(self, e: Expr, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        match self {
+            Self::Count | Self::Sum => e.rolling_sum_by(by, options),
+            Self::Max => e.rolling_max_by(by, options),
+            Self::Mean => e.rolling_mean_by(by, options),
+            Self::Median => e.rolling_median_by(by, options),
+            Self::Min => e.rolling_min_by(by, options),
+            Self::StdDev => e.rolling_std_by(by, options),
+            Self::Variance => e.rolling_var_by(by, options),
+        }
+    }
+}
+
 impl SQLFunctionVisitor<'_> {
     pub(crate) fn visit_function(&mut self) -> PolarsResult<Expr> {
         use PolarsSQLFunctions::*;
+
+        // Resolve references to the windows of the WINDOW clause.
+        if let Some(window) = &self.func.over {
+            if matches!(window, WindowType::NamedWindow(_))
+                || matches!(window, WindowType::WindowSpec(spec) if spec.window_name.is_some())
+            {
+                let func = SQLFunction {
+                    over: Some(WindowType::WindowSpec(self.ctx.resolve_window(window)?)),
+                    ..self.func.clone()
+                };
+                let mut visitor = SQLFunctionVisitor {
+                    func: &func,
+                    ctx: &mut *self.ctx,
+                    active_schema: self.active_schema,
+                };
+                return visitor.visit_function();
+            }
+        }
+
         let function_name = PolarsSQLFunctions::try_from_sql(self.func, self.ctx)?;
         let function = self.func;
 
-        // TODO: implement the following functions where possible
-        if !function.within_group.is_empty() {
-            polars_bail!(SQLInterface: "'WITHIN GROUP' is not currently supported")
+        if let Some(filter) = &function.filter {
+            return self.visit_filtered_aggregate(filter);
         }
-        if function.filter.is_some() {
-            polars_bail!(SQLInterface: "'FILTER' is not currently supported")
+        if !function.within_group.is_empty()
+            && !matches!(function_name, Mode | PercentileCont | PercentileDisc)
+        {
+            polars_bail!(SQLInterface: "'WITHIN GROUP' is only supported for MODE, PERCENTILE_CONT and PERCENTILE_DISC; found {}", function)
         }
-        if function.null_treatment.is_some() {
-            polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
+        if function.null_treatment.is_some()
+            && !matches!(function_name, FirstValue | LastValue | Lag | Lead)
+        {
+            polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is only supported for FIRST_VALUE, LAST_VALUE, LAG and LEAD; found {}", function)
+        }
+        if let Some(WindowType::WindowSpec(
+            spec @ WindowSpec {
+                window_frame: Some(frame),
+                ..
+            },
+        )) = &function.over
+        {
+            if let Some(agg) = FrameAggregate::from_function(&function_name) {
+                if let Some(expr) = self.visit_framed_aggregate(agg, spec, frame)? {
+                    return Ok(expr);
+                }
+            }
         }
 
         match function_name {
@@ -1095,6 +1390,67 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Date functions
             // ----
+            CurrentDate => self.visit_nullary(|| self.local_now().dt().date()),
+            CurrentTimestamp => self.visit_nullary(|| self.now()),
+            LocalTimestamp => self.visit_nullary(|| self.local_now()),
+            DateAdd => {
+                let args = extract_args(function)?;
+                match args.as_slice() {
+                    [
+                        FunctionArgExpr::Expr(part),
+                        FunctionArgExpr::Expr(n),
+                        FunctionArgExpr::Expr(e),
+                    ] => {
+                        let Some(part) = date_part_name(part) else {
+                            polars_bail!(SQLSyntax: "invalid date part for DATEADD ({})", part)
+                        };
+                        let (multiple, unit) = date_part_duration(part)?;
+                        let n = parse_sql_expr(n, self.ctx, self.active_schema)?;
+                        let e = parse_sql_expr(e, self.ctx, self.active_schema)?;
+                        let offset = match n {
+                            Expr::Literal(lv) if lv.to_any_value().and_then(|av| av.extract::<i64>()).is_some() => {
+                                let n = lv.to_any_value().unwrap().extract::<i64>().unwrap();
+                                let Some(n) = n.checked_mul(multiple) else {
+                                    polars_bail!(SQLSyntax: "DATEADD offset is out of range ({} {})", n, part)
+                                };
+                                lit(format!("{n}{unit}"))
+                            },
+                            n => concat_str(
+                                [(n * lit(multiple)).cast(DataType::String), lit(unit)],
+                                "",
+                                false,
+                            ),
+                        };
+                        Ok(e.dt().offset_by(offset))
+                    },
+                    _ => {
+                        polars_bail!(SQLSyntax: "DATEADD expects 3 arguments (found {})", args.len())
+                    },
+                }
+            },
+            DateTrunc => {
+                let args = extract_args(function)?;
+                // the argument order depends on the dialect (and the name of the function)
+                let name = function.name.0[0].value.to_lowercase();
+                let (part, e) = match args.as_slice() {
+                    [FunctionArgExpr::Expr(a), FunctionArgExpr::Expr(b)] => {
+                        if self.ctx.dialect.date_trunc_part_last(&name) {
+                            (b, a)
+                        } else {
+                            (a, b)
+                        }
+                    },
+                    _ => {
+                        polars_bail!(SQLSyntax: "DATE_TRUNC expects 2 arguments (found {})", args.len())
+                    },
+                };
+                let Some(part) = date_part_name(part) else {
+                    polars_bail!(SQLSyntax: "invalid date part for DATE_TRUNC ({})", part)
+                };
+                let (multiple, unit) = date_part_duration(part)?;
+                let e = parse_sql_expr(e, self.ctx, self.active_schema)?;
+                Ok(e.dt().truncate(lit(format!("{multiple}{unit}"))))
+            },
             DatePart => self.try_visit_binary(|part, e| {
                 match part {
                     Expr::Literal(p) if p.extract_str().is_some() => {
@@ -1437,6 +1793,9 @@ impl SQLFunctionVisitor<'_> {
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
+            Mode | PercentileCont | PercentileDisc => {
+                self.visit_ordered_set_aggregate(&function_name)
+            },
             QuantileCont => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1493,6 +1852,31 @@ impl SQLFunctionVisitor<'_> {
             StdDev => self.visit_unary(|e| e.std(1)),
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
             Variance => self.visit_unary(|e| e.var(1)),
+            Grouping => self.try_visit_variadic(|exprs: &[Expr]| {
+                if exprs.is_empty() || exprs.len() > 31 {
+                    polars_bail!(SQLSyntax: "GROUPING expects 1-31 arguments (found {})", exprs.len())
+                }
+                // The GROUP BY sets an indicator column per key; the leftmost argument
+                // corresponds to the most significant bit.
+                let names = exprs
+                    .iter()
+                    .map(expr_output_name)
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let bitmask = names
+                    .iter()
+                    .map(|name| col(format_pl_smallstr!("{GROUPING_INDICATOR_PREFIX}{name}")))
+                    .reduce(|acc, e| acc * lit(2i32) + e)
+                    .unwrap();
+                // Name the result after its arguments, so that several GROUPING() calls
+                // don't clash.
+                Ok(bitmask.alias(format_pl_smallstr!("grouping({})", names.join(", "))))
+            }),
+
+            // ----
+            // Window functions
+            // ----
+            FirstValue | LastValue | Lag | Lead => self.visit_navigation_function(&function_name),
+            DenseRank | Rank | RowNumber => self.visit_ranking_function(&function_name),
 
             // ----
             // Array functions
@@ -1562,6 +1946,7 @@ impl SQLFunctionVisitor<'_> {
             // User-defined
             // ----
             Udf(func_name) => self.visit_udf(&func_name),
+            Udaf(func_name) => self.visit_udaf(&func_name),
         }
     }
 
@@ -1585,6 +1970,27 @@ impl SQLFunctionVisitor<'_> {
             .call(args))
     }
 
+    fn visit_udaf(&mut self, func_name: &str) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?
+            .into_iter()
+            .map(|arg| {
+                if let FunctionArgExpr::Expr(e) = arg {
+                    parse_sql_expr(e, self.ctx, self.active_schema)
+                } else {
+                    polars_bail!(SQLInterface: "only expressions are supported in UDFs")
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let expr = self
+            .ctx
+            .function_registry
+            .get_udaf(func_name)?
+            .ok_or_else(|| polars_err!(SQLInterface: "UDAF {} not found", func_name))?
+            .call(args)?;
+        self.apply_window_spec(expr, &self.func.over)
+    }
+
     /// Window specs without partition bys are essentially cumulative functions
     /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
     fn apply_cumulative_window(
@@ -1658,10 +2064,9 @@ impl SQLFunctionVisitor<'_> {
             Some(WindowType::WindowSpec(spec)) => {
                 self.apply_cumulative_window(f, cumulative_f, spec)
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
+            Some(WindowType::NamedWindow(_)) => {
+                unreachable!("named windows are resolved before visiting the function")
+            },
             _ => self.visit_unary(f),
         }
     }
@@ -1743,6 +2148,24 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    /// The current time (as of query planning), in the session time zone.
+    fn now(&self) -> Expr {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_micros() as i64);
+        lit(now).cast(DataType::Datetime(
+            TimeUnit::Microseconds,
+            Some(self.ctx.time_zone.clone()),
+        ))
+    }
+
+    /// The current (naive) local time in the session time zone.
+    fn local_now(&self) -> Expr {
+        self.now()
+            .dt()
+            .replace_time_zone(None, lit("raise"), NonExistent::Raise)
+    }
+
     fn visit_nullary(&self, f: impl Fn() -> Expr) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?;
         if !args.is_empty() {
@@ -1841,17 +2264,236 @@ impl SQLFunctionVisitor<'_> {
         self.apply_window_spec(count_expr, &self.func.over)
     }
 
+    /// Evaluate `agg(...) FILTER (WHERE cond)`, aggregating only the rows for which `cond` holds.
+    fn visit_filtered_aggregate(&mut self, filter: &SQLExpr) -> PolarsResult<Expr> {
+        if self.func.over.is_some() {
+            polars_bail!(SQLInterface: "'FILTER' is not supported for window functions; found {}", self.func)
+        }
+        let cond = parse_sql_expr(filter, self.ctx, self.active_schema)?;
+        let func = SQLFunction {
+            filter: None,
+            ..self.func.clone()
+        };
+        let expr = SQLFunctionVisitor {
+            func: &func,
+            ctx: &mut *self.ctx,
+            active_schema: self.active_schema,
+        }
+        .visit_function()?;
+
+        // Filter every column that feeds into the aggregate; COUNT(*) counts the matching rows.
+        Ok(expr.map_expr(|e| match e {
+            Expr::Column(_) => e.filter(cond.clone()),
+            Expr::Len => cond.clone().fill_null(lit(false)).sum(),
+            e => e,
+        }))
+    }
+
+    /// Evaluate the ordered-set aggregates `MODE()`, `PERCENTILE_CONT(q)` and
+    /// `PERCENTILE_DISC(q)` over their `WITHIN GROUP (ORDER BY ...)` expression.
+    fn visit_ordered_set_aggregate(&mut self, function: &PolarsSQLFunctions) -> PolarsResult<Expr> {
+        let name = self.func.name.to_string().to_uppercase();
+        let [order_by] = self.func.within_group.as_slice() else {
+            polars_bail!(SQLSyntax: "{} requires WITHIN GROUP with exactly one ORDER BY expression", name)
+        };
+        let descending = !order_by.asc.unwrap_or(true);
+        let expr = parse_sql_expr(&order_by.expr, self.ctx, self.active_schema)?;
+        let args = extract_args(self.func)?;
+
+        let agg = match function {
+            PolarsSQLFunctions::Mode => {
+                if !args.is_empty() {
+                    polars_bail!(SQLSyntax: "MODE expects no arguments (found {})", args.len())
+                }
+                let modes = expr.mode();
+                if descending { modes.max() } else { modes.min() }
+            },
+            _ => {
+                let fraction = match args.as_slice() {
+                    [FunctionArgExpr::Expr(SQLExpr::Value(SQLValue::Number(n, _)))] => n
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|q| (0.0..=1.0).contains(q))
+                        .ok_or_else(|| polars_err!(SQLSyntax: "{} value must be between 0 and 1 ({})", name, n))?,
+                    [arg] => polars_bail!(SQLSyntax: "invalid value for {} ({})", name, arg),
+                    _ => polars_bail!(SQLSyntax: "{} expects 1 argument (found {})", name, args.len()),
+                };
+                match function {
+                    // A descending ordering interpolates at the complementary fraction.
+                    PolarsSQLFunctions::PercentileCont => {
+                        let fraction = if descending { 1.0 - fraction } else { fraction };
+                        expr.quantile(lit(fraction), QuantileMethod::Linear)
+                    },
+                    // The first value (in descending order) whose cumulative distribution
+                    // reaches the fraction, at position `ceil(fraction * n)`.
+                    _ if descending => {
+                        let values = expr.drop_nulls();
+                        let n = values.clone().count().cast(DataType::Float64);
+                        let position = (n * lit(fraction)).ceil();
+                        let idx = (position - lit(1.0)).clip_min(lit(0.0)).cast(IDX_DTYPE);
+                        values
+                            .sort(SortOptions::default().with_order_descending(true))
+                            .slice(idx, lit(1))
+                            .first()
+                    },
+                    _ => expr.quantile(lit(fraction), QuantileMethod::Equiprobable),
+                }
+            },
+        };
+        self.apply_window_spec(agg, &self.func.over)
+    }
+
+    /// Evaluate `FIRST_VALUE`, `LAST_VALUE`, `LAG` and `LEAD` in the order of their window,
+    /// optionally skipping over NULL values (`IGNORE NULLS`).
+    fn visit_navigation_function(&mut self, function: &PolarsSQLFunctions) -> PolarsResult<Expr> {
+        let name = self.func.name.to_string().to_uppercase();
+        let (args, is_distinct, clauses) = extract_args_and_clauses(self.func)?;
+        if is_distinct {
+            polars_bail!(SQLSyntax: "unexpected use of DISTINCT found in '{}'", name)
+        }
+        // Some dialects place the null treatment inside the argument list.
+        let mut ignore_nulls = matches!(self.func.null_treatment, Some(NullTreatment::IgnoreNulls));
+        for clause in &clauses {
+            match clause {
+                FunctionArgumentClause::IgnoreOrRespectNulls(treatment) => {
+                    ignore_nulls = matches!(treatment, NullTreatment::IgnoreNulls)
+                },
+                _ => polars_bail!(SQLSyntax: "unexpected clause found in '{}' ({})", name, clause),
+            }
+        }
+
+        let (partition_by, ordering, frame) = match &self.func.over {
+            Some(WindowType::WindowSpec(spec)) => {
+                let (partition_by, ordering) = self.window_partition_and_order(spec)?;
+                (partition_by, ordering, spec.window_frame.as_ref())
+            },
+            Some(WindowType::NamedWindow(_)) => {
+                unreachable!("named windows are resolved before visiting the function")
+            },
+            None => (vec![], None, None),
+        };
+        let expr = match args.first() {
+            Some(FunctionArgExpr::Expr(sql_expr)) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            _ => return self.not_supported_error(),
+        };
+
+        let result = match function {
+            PolarsSQLFunctions::FirstValue | PolarsSQLFunctions::LastValue => {
+                if args.len() != 1 {
+                    polars_bail!(SQLSyntax: "{} expects 1 argument (found {})", name, args.len())
+                }
+                // Without a frame (or with one that starts at the partition start and ends at the
+                // current row) the frame grows with each row; otherwise it spans the partition.
+                let whole_partition = match frame {
+                    None => ordering.is_none(),
+                    Some(frame) => match (&frame.start_bound, &frame.end_bound) {
+                        (
+                            WindowFrameBound::Preceding(None),
+                            None | Some(WindowFrameBound::CurrentRow),
+                        ) => false,
+                        (
+                            WindowFrameBound::Preceding(None),
+                            Some(WindowFrameBound::Following(None)),
+                        ) => true,
+                        _ => {
+                            polars_bail!(SQLInterface: "unsupported window frame for {}: {:?}", name, frame)
+                        },
+                    },
+                };
+                let is_first = matches!(function, PolarsSQLFunctions::FirstValue);
+                match (is_first, whole_partition, ignore_nulls) {
+                    (true, _, false) => expr.first(),
+                    (true, true, true) => expr.drop_nulls().first(),
+                    // The first non-null value, once the frame has reached it.
+                    (true, false, true) => {
+                        when(expr.clone().is_not_null().cum_sum(false).gt(lit(0)))
+                            .then(expr.drop_nulls().first())
+                            .otherwise(lit(LiteralValue::untyped_null()))
+                    },
+                    (false, true, false) => expr.last(),
+                    (false, true, true) => expr.drop_nulls().last(),
+                    (false, false, false) => expr,
+                    (false, false, true) => {
+                        expr.fill_null_with_strategy(FillNullStrategy::Forward(None))
+                    },
+                }
+            },
+            _ => {
+                let offset = match args.get(1) {
+                    None => 1,
+                    Some(FunctionArgExpr::Expr(SQLExpr::Value(SQLValue::Number(n, _)))) => {
+                        match n.parse::<i64>() {
+                            Ok(n) if n >= 0 => n,
+                            _ => {
+                                polars_bail!(SQLSyntax: "{} offset must be a non-negative integer ({})", name, n)
+                            },
+                        }
+                    },
+                    Some(arg) => {
+                        polars_bail!(SQLSyntax: "{} offset must be a non-negative integer ({})", name, arg)
+                    },
+                };
+                let default = match args.get(2) {
+                    None => None,
+                    Some(FunctionArgExpr::Expr(sql_expr)) => {
+                        Some(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?)
+                    },
+                    Some(_) => return self.not_supported_error(),
+                };
+                if args.len() > 3 {
+                    polars_bail!(SQLSyntax: "{} expects 1-3 arguments (found {})", name, args.len())
+                }
+                let is_lag = matches!(function, PolarsSQLFunctions::Lag);
+                if ignore_nulls {
+                    // Index into the non-null values: the n-th non-null value before (LAG) or
+                    // after (LEAD) the current row.
+                    let is_valid = expr.clone().is_not_null().cast(DataType::Int64);
+                    let n_valid = is_valid.clone().cum_sum(false);
+                    let idx = if is_lag {
+                        n_valid - is_valid - lit(offset)
+                    } else {
+                        n_valid + lit(offset - 1)
+                    };
+                    let in_bounds = idx
+                        .clone()
+                        .gt_eq(lit(0))
+                        .and(idx.clone().lt(expr.clone().count().cast(DataType::Int64)));
+                    let idx = when(in_bounds)
+                        .then(idx)
+                        .otherwise(lit(LiteralValue::untyped_null()));
+                    // The gathered values are non-null; NULL marks rows without an n-th value.
+                    let shifted = expr.drop_nulls().gather(idx);
+                    match default {
+                        Some(default) => shifted.fill_null(default),
+                        None => shifted,
+                    }
+                } else {
+                    let n = lit(if is_lag { offset } else { -offset });
+                    match default {
+                        Some(default) => expr.shift_and_fill(n, default),
+                        None => expr.shift(n),
+                    }
+                }
+            },
+        };
+        over_window(result, partition_by, ordering)
+    }
+
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
         let mut nulls_last = Vec::with_capacity(order_by.len());
 
         for ob in order_by {
-            // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
-            // https://www.postgresql.org/docs/current/queries-order.html
+            // note: if not specified the dialect determines the position of NULLs
             let desc_order = !ob.asc.unwrap_or(true);
             by.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
-            nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
+            nulls_last.push(
+                !ob.nulls_first
+                    .unwrap_or_else(|| self.ctx.dialect.nulls_first(desc_order)),
+            );
             descending.push(desc_order);
         }
         Ok(expr.sort_by(
@@ -1892,14 +2534,221 @@ impl SQLFunctionVisitor<'_> {
                     expr.over(partition_by)
                 }
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
+            Some(WindowType::NamedWindow(_)) => {
+                unreachable!("named windows are resolved before visiting the function")
+            },
             None => expr,
         })
     }
 
+    /// Evaluate an aggregate over an explicit window frame, e.g.
+    /// `AVG(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)`.
+    ///
+    /// Returns `None` for `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`, which is the
+    /// default frame of windows with an ORDER BY.
+    fn visit_framed_aggregate(
+        &mut self,
+        agg: FrameAggregate,
+        spec: &WindowSpec,
+        frame: &WindowFrame,
+    ) -> PolarsResult<Option<Expr>> {
+        let end_bound = frame
+            .end_bound
+            .as_ref()
+            .unwrap_or(&WindowFrameBound::CurrentRow);
+        if frame.units == WindowFrameUnits::Range
+            && matches!(frame.start_bound, WindowFrameBound::Preceding(None))
+            && matches!(end_bound, WindowFrameBound::CurrentRow)
+        {
+            return Ok(None);
+        }
+
+        let (args, is_distinct) = extract_args_distinct(self.func)?;
+        if is_distinct {
+            polars_bail!(SQLInterface: "DISTINCT is not supported for aggregates over a window frame; found {}", self.func)
+        }
+        let (partition_by, ordering) = self.window_partition_and_order(spec)?;
+
+        let expr = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            // Any expression that is non-null for every row works for COUNT(*).
+            [FunctionArgExpr::Wildcard] | [] if agg == FrameAggregate::Count => {
+                let order_by = ordering.as_ref().and_then(|(order_by, _)| order_by.first());
+                match order_by.or(partition_by.first()) {
+                    Some(e) => e.clone().is_null().or(e.clone().is_not_null()),
+                    None => lit(true),
+                }
+            },
+            _ => return self.not_supported_error().map(Some),
+        };
+        // COUNT sums the non-null indicators; over an empty frame it is zero rather than null.
+        let (expr, fill_zero) = match agg {
+            FrameAggregate::Count => (expr.is_not_null().cast(IDX_DTYPE), true),
+            _ => (expr, false),
+        };
+
+        let framed = match frame.units {
+            WindowFrameUnits::Rows => {
+                let start = frame_row_offset(&frame.start_bound)?;
+                let end = frame_row_offset(end_bound)?;
+                match (start, end) {
+                    (None, None) => agg.aggregate(expr),
+                    (None, Some(0)) | (Some(0), None) => agg
+                        .cumulative(expr, start.is_some())
+                        .ok_or_else(|| polars_err!(SQLInterface: "{} does not support cumulative window frames", self.func.name))?,
+                    // The rows in [i + start, i + end] are a window ending at row i of the input
+                    // shifted by `end` rows.
+                    (Some(start), Some(end)) if start <= end => {
+                        let shifted = if end == 0 {
+                            expr
+                        } else {
+                            expr.shift(lit(-end))
+                        };
+                        let options = RollingOptionsFixedWindow {
+                            window_size: (end - start + 1) as usize,
+                            min_periods: 1,
+                            ..Default::default()
+                        };
+                        agg.rolling(shifted, options)
+                    },
+                    _ => polars_bail!(SQLInterface: "unsupported window frame: {:?}", frame),
+                }
+            },
+            WindowFrameUnits::Range => match (&frame.start_bound, end_bound) {
+                (WindowFrameBound::Preceding(None), WindowFrameBound::Following(None)) => {
+                    agg.aggregate(expr)
+                },
+                (WindowFrameBound::Preceding(Some(offset)), WindowFrameBound::CurrentRow) => {
+                    let Some(([by], options)) = ordering
+                        .as_ref()
+                        .map(|(order_by, options)| (order_by.as_slice(), options))
+                    else {
+                        polars_bail!(SQLSyntax: "RANGE window frames with an offset require exactly one ORDER BY expression")
+                    };
+                    if options.descending {
+                        polars_bail!(SQLInterface: "RANGE window frames with an offset require an ascending ORDER BY")
+                    }
+                    let options = RollingOptionsDynamicWindow {
+                        window_size: frame_range_offset(offset)?,
+                        min_periods: 1,
+                        closed_window: ClosedWindow::Both,
+                        fn_params: None,
+                    };
+                    let framed = agg.rolling_by(expr, by.clone(), options);
+                    let framed = if fill_zero {
+                        framed.fill_null(lit(0))
+                    } else {
+                        framed
+                    };
+                    // The window is determined by the ORDER BY values, not the row order.
+                    return Ok(Some(if partition_by.is_empty() {
+                        framed
+                    } else {
+                        framed.over(partition_by)
+                    }));
+                },
+                _ => polars_bail!(SQLInterface: "unsupported window frame: {:?}", frame),
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not currently supported")
+            },
+        };
+        let framed = if fill_zero {
+            framed.fill_null(lit(0))
+        } else {
+            framed
+        };
+
+        over_window(framed, partition_by, ordering).map(Some)
+    }
+
+    /// Evaluate `ROW_NUMBER`, `RANK` and `DENSE_RANK` in the order of their window.
+    fn visit_ranking_function(&mut self, function: &PolarsSQLFunctions) -> PolarsResult<Expr> {
+        let name = self.func.name.to_string().to_uppercase();
+        let args = extract_args(self.func)?;
+        if !args.is_empty() {
+            polars_bail!(SQLSyntax: "{} expects no arguments (found {})", name, args.len())
+        }
+        let (partition_by, ordering) = match &self.func.over {
+            Some(WindowType::WindowSpec(spec)) => self.window_partition_and_order(spec)?,
+            Some(WindowType::NamedWindow(_)) => {
+                unreachable!("named windows are resolved before visiting the function")
+            },
+            None => polars_bail!(SQLSyntax: "{} requires an OVER clause", name),
+        };
+        let order_by = ordering
+            .as_ref()
+            .map(|(order_by, _)| order_by.clone())
+            .unwrap_or_default();
+
+        // Number the rows of each partition by counting the (never null) validity of a column.
+        let base = match (order_by.first(), partition_by.first(), self.active_schema) {
+            (Some(e), _, _) | (None, Some(e), _) => e.clone(),
+            (None, None, Some(schema)) if !schema.is_empty() => {
+                col(schema.get_at_index(0).unwrap().0.clone())
+            },
+            _ => polars_bail!(SQLSyntax: "{} requires a PARTITION BY or ORDER BY clause", name),
+        };
+        let row_number = base.is_null().cum_count(false);
+
+        let expr = match function {
+            PolarsSQLFunctions::RowNumber => row_number,
+            _ => {
+                if order_by.is_empty() {
+                    polars_bail!(SQLSyntax: "{} requires an ORDER BY clause", name)
+                }
+                // A row starts a new rank if its ORDER BY values differ from the previous row.
+                let is_new_rank = order_by
+                    .into_iter()
+                    .map(|e| e.clone().neq_missing(e.shift(lit(1))))
+                    .fold(row_number.clone().eq(lit(1)), Expr::or);
+                if matches!(function, PolarsSQLFunctions::Rank) {
+                    when(is_new_rank)
+                        .then(row_number)
+                        .otherwise(lit(LiteralValue::untyped_null()))
+                        .fill_null_with_strategy(FillNullStrategy::Forward(None))
+                } else {
+                    is_new_rank.cast(IDX_DTYPE).cum_sum(false)
+                }
+            },
+        };
+        over_window(expr, partition_by, ordering)
+    }
+
+    /// Parse the PARTITION BY expressions and the ORDER BY (with its sort options) of a window.
+    fn window_partition_and_order(
+        &mut self,
+        spec: &WindowSpec,
+    ) -> PolarsResult<(Vec<Expr>, Option<(Vec<Expr>, SortOptions)>)> {
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut sort_options = None;
+        for o in &spec.order_by {
+            let descending = !o.asc.unwrap_or(true);
+            let options = SortOptions::default()
+                .with_order_descending(descending)
+                .with_nulls_last(
+                    !o.nulls_first
+                        .unwrap_or_else(|| self.ctx.dialect.nulls_first(descending)),
+                );
+            if sort_options.is_some_and(|opts| opts != options) {
+                polars_bail!(SQLInterface: "ordered window functions do not support ORDER BY with mixed sort orders")
+            }
+            sort_options = Some(options);
+            order_by.push(parse_sql_expr(&o.expr, self.ctx, self.active_schema)?);
+        }
+        Ok((
+            partition_by,
+            sort_options.map(|options| (order_by, options)),
+        ))
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1909,6 +2758,95 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Evaluate `expr` per window partition, with the rows of each partition in ORDER BY order.
+fn over_window(
+    expr: Expr,
+    partition_by: Vec<Expr>,
+    ordering: Option<(Vec<Expr>, SortOptions)>,
+) -> PolarsResult<Expr> {
+    match ordering {
+        Some(ordering) => expr.over_with_options(
+            (!partition_by.is_empty()).then_some(partition_by),
+            Some(ordering),
+            WindowMapping::default(),
+        ),
+        None if partition_by.is_empty() => Ok(expr),
+        None => Ok(expr.over(partition_by)),
+    }
+}
+
+/// The offset of a ROWS frame bound from the current row; `None` if it is unbounded.
+fn frame_row_offset(bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+    let (offset, sign) = match bound {
+        WindowFrameBound::CurrentRow => return Ok(Some(0)),
+        WindowFrameBound::Preceding(offset) => (offset, -1),
+        WindowFrameBound::Following(offset) => (offset, 1),
+    };
+    match offset.as_deref() {
+        None => Ok(None),
+        Some(SQLExpr::Value(SQLValue::Number(n, _))) => match n.parse::<i64>() {
+            Ok(n) if n >= 0 => Ok(Some(sign * n)),
+            _ => {
+                polars_bail!(SQLSyntax: "ROWS frame offset must be a non-negative integer; found {}", n)
+            },
+        },
+        Some(e) => {
+            polars_bail!(SQLSyntax: "ROWS frame offset must be a non-negative integer; found {}", e)
+        },
+    }
+}
+
+/// The length of a `RANGE BETWEEN <offset> PRECEDING` frame, which is an interval for temporal,
+/// or a number for integer ORDER BY expressions.
+fn frame_range_offset(offset: &SQLExpr) -> PolarsResult<Duration> {
+    match offset {
+        SQLExpr::Interval(interval) => interval_to_duration(interval, false),
+        SQLExpr::Value(SQLValue::Number(n, _)) => match n.parse::<i64>() {
+            Ok(n) if n >= 0 => Ok(Duration::parse(&format!("{n}i"))),
+            _ => {
+                polars_bail!(SQLSyntax: "RANGE frame offset must be a non-negative integer or an interval; found {}", n)
+            },
+        },
+        _ => {
+            polars_bail!(SQLSyntax: "RANGE frame offset must be a non-negative integer or an interval; found {}", offset)
+        },
+    }
+}
+
+/// The name of the date/time part given by a string literal (or unquoted identifier).
+fn date_part_name(expr: &SQLExpr) -> Option<&str> {
+    match expr {
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s.as_str()),
+        SQLExpr::Identifier(ident)
+            if ident.quote_style.is_none() && date_part_duration(&ident.value).is_ok() =>
+        {
+            Some(ident.value.as_str())
+        },
+        _ => None,
+    }
+}
+
+/// The multiple of a Polars duration unit that is equivalent to a date/time part.
+fn date_part_duration(part: &str) -> PolarsResult<(i64, &'static str)> {
+    Ok(match part.to_lowercase().as_str() {
+        "millennium" | "millennia" => (1000, "y"),
+        "century" | "centuries" => (100, "y"),
+        "decade" | "decades" => (10, "y"),
+        "year" | "years" | "y" | "yy" | "yyyy" => (1, "y"),
+        "quarter" | "quarters" | "q" => (1, "q"),
+        "month" | "months" | "mon" | "mons" | "mm" => (1, "mo"),
+        "week" | "weeks" | "w" | "wk" => (1, "w"),
+        "day" | "days" | "d" | "dd" => (1, "d"),
+        "hour" | "hours" | "h" | "hh" => (1, "h"),
+        "minute" | "minutes" | "mins" | "min" | "mi" | "m" => (1, "m"),
+        "second" | "seconds" | "sec" | "secs" | "s" => (1, "s"),
+        "millisecond" | "milliseconds" | "ms" => (1, "ms"),
+        "microsecond" | "microseconds" | "us" => (1, "us"),
+        "nanosecond" | "nanoseconds" | "ns" => (1, "ns"),
+        _ => polars_bail!(SQLSyntax: "invalid date/time part '{}'", part),
+    })
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -51,6 +51,8 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::OR,
         keywords::ORDER,
         keywords::OUTER,
+        keywords::PIVOT,
+        keywords::QUALIFY,
         keywords::REGEXP,
         keywords::RENAME,
         keywords::REPLACE,
@@ -65,6 +67,7 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::TIME,
         keywords::TRUNCATE,
         keywords::UNION,
+        keywords::UNPIVOT,
         keywords::USING,
         keywords::VARCHAR,
         keywords::WHEN,
@@ -2,12 +2,17 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+mod dialect;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+mod params;
 mod sql_expr;
+mod subqueries;
 mod table_functions;
 mod types;
 
 pub use context::SQLContext;
+pub use dialect::SQLDialect;
+pub use params::{SQLParams, SQLPreparedStatement};
 pub use sql_expr::sql_expr;
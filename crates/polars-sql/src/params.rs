@@ -0,0 +1,77 @@
+use polars_core::prelude::*;
+use sqlparser::ast::Statement;
+
+/// A parsed SQL statement that can contain bind parameters, see [`SQLContext::prepare`].
+///
+/// The statement is parsed once, and can be executed repeatedly with different
+/// [`SQLParams`]; the bound values are substituted as typed literals, never as SQL text.
+///
+/// Only the parsing is cached: each execution plans the statement anew, against the tables
+/// registered at that time, so there is no planning work saved between executions.
+///
+/// [`SQLContext::prepare`]: crate::SQLContext::prepare
+#[derive(Clone, Debug)]
+pub struct SQLPreparedStatement {
+    pub(crate) statement: Statement,
+}
+
+impl SQLPreparedStatement {
+    /// The SQL text of the prepared statement.
+    pub fn sql(&self) -> String {
+        self.statement.to_string()
+    }
+}
+
+/// Values for the bind parameters of a [`SQLPreparedStatement`].
+///
+/// Positional values are bound to the `$1`, `?1` (or anonymous `?`, numbered from left to
+/// right) placeholders, and named values to the `:name`, `@name` or `$name` placeholders.
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_sql::SQLParams;
+/// # fn main() {
+/// let params = SQLParams::new()
+///     .bind(10)
+///     .bind_named("name", PlSmallStr::from_static("foo"));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SQLParams {
+    positional: Vec<Scalar>,
+    named: PlHashMap<PlSmallStr, Scalar>,
+}
+
+impl SQLParams {
+    /// Create an empty set of parameter values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind the value of the next positional parameter.
+    pub fn bind(mut self, value: impl Into<Scalar>) -> Self {
+        self.positional.push(value.into());
+        self
+    }
+
+    /// Bind the value of a named parameter.
+    pub fn bind_named(mut self, name: &str, value: impl Into<Scalar>) -> Self {
+        self.named.insert(PlSmallStr::from_str(name), value.into());
+        self
+    }
+
+    /// Get the value bound to a placeholder, as found in the SQL (eg: `$1` or `:name`).
+    pub(crate) fn get(&self, placeholder: &str) -> PolarsResult<&Scalar> {
+        let key = placeholder.get(1..).unwrap_or_default();
+        let value = match placeholder.chars().next() {
+            Some('$' | '?') if key.chars().all(|c| c.is_ascii_digit()) => key
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| self.positional.get(idx.checked_sub(1)?)),
+            Some('$' | ':' | '@') => self.named.get(key),
+            _ => None,
+        };
+        value.ok_or_else(
+            || polars_err!(SQLInterface: "no value is bound to parameter '{}'", placeholder),
+        )
+    }
+}
@@ -27,9 +27,11 @@ use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
 use crate::SQLContext;
+use crate::context::parse_time_zone;
 use crate::functions::SQLFunctionVisitor;
 use crate::types::{
-    bitstring_to_bytes_literal, is_iso_date, is_iso_datetime, is_iso_time, map_sql_dtype_to_polars,
+    bitstring_to_bytes_literal, is_iso_date, is_iso_datetime, is_iso_datetime_with_offset,
+    is_iso_time, map_sql_dtype_to_polars,
 };
 
 #[inline]
@@ -93,6 +95,10 @@ impl SQLExprVisitor<'_> {
                 is_some: _,
             } => self.visit_any(left, compare_op, right),
             SQLExpr::Array(arr) => self.visit_array_expr(&arr.elem, true, None),
+            SQLExpr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => self.visit_at_time_zone(timestamp, time_zone),
             SQLExpr::Between {
                 expr,
                 negated,
@@ -131,7 +137,7 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
-            SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
+            SQLExpr::Interval(interval) => interval_to_lit(interval),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
             },
@@ -192,7 +198,12 @@ impl SQLExprVisitor<'_> {
                 Ok(if *negated { matches.not() } else { matches })
             },
             SQLExpr::Subscript { expr, subscript } => self.visit_subscript(expr, subscript),
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(subquery) => {
+                match self.ctx.subquery_columns.borrow().get(subquery.as_ref()) {
+                    Some(name) => Ok(col(name.clone())),
+                    None => polars_bail!(SQLInterface: "unexpected subquery"),
+                }
+            },
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -217,7 +228,34 @@ impl SQLExprVisitor<'_> {
                         polars_bail!(SQLSyntax: "invalid TIME literal '{}'", value)
                     }
                 },
-                SQLDataType::Timestamp(None, TimezoneInfo::None) | SQLDataType::Datetime(None) => {
+                SQLDataType::Timestamp(None, TimezoneInfo::WithTimeZone | TimezoneInfo::Tz) => {
+                    let time_zone = self.ctx.time_zone.clone();
+                    let opts = StrptimeOptions {
+                        strict: true,
+                        ..Default::default()
+                    };
+                    if is_iso_datetime(value) {
+                        // naive timestamps are local times in the session time zone
+                        Ok(lit(value.as_str())
+                            .str()
+                            .to_datetime(None, None, opts, lit("latest"))
+                            .dt()
+                            .replace_time_zone(Some(time_zone), lit("raise"), NonExistent::Raise))
+                    } else if is_iso_datetime_with_offset(value) {
+                        Ok(lit(value.replacen(' ', "T", 1))
+                            .str()
+                            .to_datetime(None, None, opts, lit("raise"))
+                            .dt()
+                            .convert_time_zone(time_zone))
+                    } else {
+                        polars_bail!(SQLSyntax: "invalid TIMESTAMPTZ literal '{}'", value)
+                    }
+                },
+                SQLDataType::Timestamp(
+                    None,
+                    TimezoneInfo::None | TimezoneInfo::WithoutTimeZone,
+                )
+                | SQLDataType::Datetime(None) => {
                     if is_iso_datetime(value) {
                         Ok(lit(value.as_str()).str().to_datetime(
                             None,
@@ -289,6 +327,9 @@ impl SQLExprVisitor<'_> {
     ///
     /// e.g. column
     fn visit_identifier(&self, ident: &Ident) -> PolarsResult<Expr> {
+        if self.ctx.ambiguous_columns.contains(&ident.value) {
+            polars_bail!(SQLSyntax: "column reference '{}' is ambiguous; qualify it with a table name", ident.value)
+        }
         Ok(col(ident.value.as_str()))
     }
 
@@ -516,8 +557,18 @@ impl SQLExprVisitor<'_> {
             // General operators
             // ----
             SQLBinaryOperator::And => lhs.and(rhs),  // "x AND y"
-            SQLBinaryOperator::Divide => lhs / rhs,  // "x / y"
+            SQLBinaryOperator::Divide => {  // "x / y"
+                if self.ctx.dialect.integer_division_truncates()
+                    && self.is_integer_expr(&lhs)
+                    && self.is_integer_expr(&rhs)
+                {
+                    truncating_div(lhs, rhs)
+                } else {
+                    lhs / rhs
+                }
+            },
             SQLBinaryOperator::DuckIntegerDivide => lhs.floor_div(rhs).cast(DataType::Int64),  // "x // y"
+            SQLBinaryOperator::MyIntegerDivide => truncating_div(lhs, rhs).cast(DataType::Int64),  // "x DIV y"
             SQLBinaryOperator::Eq => lhs.eq(rhs),  // "x = y"
             SQLBinaryOperator::Gt => lhs.gt(rhs),  // "x > y"
             SQLBinaryOperator::GtEq => lhs.gt_eq(rhs),  // "x >= y"
@@ -778,13 +829,84 @@ impl SQLExprVisitor<'_> {
         if dtype == &SQLDataType::JSON {
             return Ok(expr.str().json_decode(<Option<DataTypeExpr>>::None, None));
         }
-        let polars_type = map_sql_dtype_to_polars(dtype)?;
+        let polars_type = map_sql_dtype_to_polars(dtype, &self.ctx.time_zone)?;
+
+        // casting between naive and time zone-aware datetimes changes the time zone
+        // (interpreting naive values as local times in the session time zone)
+        if let DataType::Datetime(_, to_tz) = &polars_type {
+            if let Some(DataType::Datetime(_, from_tz)) = self.expr_dtype(&expr) {
+                let expr = match (from_tz, to_tz) {
+                    (None, Some(tz)) => expr.dt().replace_time_zone(
+                        Some(tz.clone()),
+                        lit("raise"),
+                        NonExistent::Raise,
+                    ),
+                    (Some(_), None) => expr
+                        .dt()
+                        .convert_time_zone(self.ctx.time_zone.clone())
+                        .dt()
+                        .replace_time_zone(None, lit("raise"), NonExistent::Raise),
+                    (Some(from), Some(tz)) if &from != tz => {
+                        expr.dt().convert_time_zone(tz.clone())
+                    },
+                    _ => expr,
+                };
+                return Ok(expr.cast(polars_type));
+            }
+        }
         Ok(match cast_kind {
             CastKind::Cast | CastKind::DoubleColon => expr.strict_cast(polars_type),
             CastKind::TryCast | CastKind::SafeCast => expr.cast(polars_type),
         })
     }
 
+    /// Visit a SQL `AT TIME ZONE` expression.
+    ///
+    /// e.g. `ts AT TIME ZONE 'Europe/Paris'`; a time zone-aware value is converted to the
+    /// (naive) local time in the given time zone, and a naive value is interpreted as a local
+    /// time in the given time zone (returning a value in the session time zone).
+    fn visit_at_time_zone(&mut self, expr: &SQLExpr, time_zone: &SQLExpr) -> PolarsResult<Expr> {
+        let tz = match time_zone {
+            SQLExpr::Value(SQLValue::SingleQuotedString(tz)) => parse_time_zone(tz)?,
+            _ => {
+                polars_bail!(SQLSyntax: "AT TIME ZONE expects a string literal; found {}", time_zone)
+            },
+        };
+        let expr = self.visit_expr(expr)?;
+        Ok(match self.expr_dtype(&expr) {
+            Some(DataType::Datetime(_, Some(_))) => expr
+                .dt()
+                .convert_time_zone(tz)
+                .dt()
+                .replace_time_zone(None, lit("raise"), NonExistent::Raise),
+            Some(DataType::Datetime(_, None)) | None => expr
+                .dt()
+                .replace_time_zone(Some(tz), lit("raise"), NonExistent::Raise)
+                .dt()
+                .convert_time_zone(self.ctx.time_zone.clone()),
+            Some(dtype) => {
+                polars_bail!(SQLSyntax: "AT TIME ZONE expects a timestamp; found {}", dtype)
+            },
+        })
+    }
+
+    /// Check if an expression (including a dynamic literal) is known to be an integer.
+    fn is_integer_expr(&self, expr: &Expr) -> bool {
+        matches!(
+            self.expr_dtype(expr),
+            Some(dtype) if dtype.is_integer() || matches!(dtype, DataType::Unknown(UnknownKind::Int(_)))
+        )
+    }
+
+    /// Resolve the dtype of an expression (if known) against the active schema.
+    fn expr_dtype(&self, expr: &Expr) -> Option<DataType> {
+        let empty = Schema::default();
+        let schema = self.active_schema.unwrap_or(&empty);
+        expr.to_field(schema, Context::Default)
+            .ok()
+            .map(|fld| fld.dtype)
+    }
+
     /// Visit a SQL literal.
     ///
     /// e.g. 1, 'foo', 1.0, NULL
@@ -803,6 +925,9 @@ impl SQLExprVisitor<'_> {
                 lit(hex::decode(x.clone()).unwrap())
             },
             SQLValue::Null => Expr::Literal(LiteralValue::untyped_null()),
+            SQLValue::Placeholder(p) => {
+                Expr::Literal(LiteralValue::Scalar(self.ctx.params.get(p)?.clone()))
+            },
             SQLValue::Number(s, _) => {
                 // Check for existence of decimal separator dot
                 if s.contains('.') {
@@ -842,6 +967,12 @@ impl SQLExprVisitor<'_> {
                 AnyValue::BinaryOwned(hex::decode(x.clone()).unwrap())
             },
             SQLValue::Null => AnyValue::Null,
+            SQLValue::Placeholder(p) => {
+                if let Some(op) = op {
+                    polars_bail!(SQLInterface: "unary op {:?} not supported for bind parameter {}", op, p)
+                }
+                self.ctx.params.get(p)?.value().clone()
+            },
             SQLValue::Number(s, _) => {
                 let negate = match op {
                     Some(UnaryOperator::Minus) => true,
@@ -1111,6 +1242,36 @@ pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsRe
     }
 }
 
+/// Convert a SQL interval to a literal; intervals with a years, quarters or months part
+/// have no fixed length and become an `Interval`, all others become a `Duration`.
+fn interval_to_lit(interval: &Interval) -> PolarsResult<Expr> {
+    let duration = interval_to_duration(interval, false)?;
+    if duration.months() == 0 {
+        return Ok(lit(duration));
+    }
+    let sign = if duration.negative() { -1 } else { 1 };
+    let to_i32 = |v: i64| {
+        i32::try_from(sign * v)
+            .map_err(|_| polars_err!(SQLSyntax: "interval out of range; found {}", interval))
+    };
+    let value = months_days_ns::new(
+        to_i32(duration.months())?,
+        to_i32(duration.weeks() * 7 + duration.days())?,
+        sign * duration.nanoseconds(),
+    );
+    Ok(lit(Scalar::new_interval(value)))
+}
+
+/// Integer division that truncates towards zero (unlike `floor_div`), eg: -7 / 2 = -3.
+fn truncating_div(lhs: Expr, rhs: Expr) -> Expr {
+    let quotient = lhs.clone().floor_div(rhs.clone());
+    let inexact = (lhs.clone() % rhs.clone()).neq(lit(0));
+    let negative = lhs.lt(lit(0)).xor(rhs.lt(lit(0)));
+    when(inexact.and(negative))
+        .then(quotient.clone() + lit(1))
+        .otherwise(quotient)
+}
+
 pub(crate) fn parse_sql_expr(
     expr: &SQLExpr,
     ctx: &mut SQLContext,
@@ -0,0 +1,762 @@
+//! Decorrelation of SQL subqueries.
+//!
+//! A correlated subquery references columns of the enclosing query in its WHERE clause. Its
+//! equality predicates on such columns are turned into join keys, so that the subquery can be
+//! evaluated once for all rows of the enclosing query and joined back to it: `EXISTS` and `IN`
+//! become semi and anti joins, scalar subqueries a left join on the subquery aggregated by the
+//! join keys. LATERAL subqueries in the FROM clause are joined on the keys in the same way.
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
+use polars_utils::format_pl_smallstr;
+use sqlparser::ast::{
+    BinaryOperator, Expr as SQLExpr, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr,
+    Ident, Offset, Query, Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins,
+    Value as SQLValue,
+};
+
+use crate::context::SQLContext;
+use crate::sql_expr::parse_sql_expr;
+
+/// Prefix of the columns holding the results of scalar subqueries.
+pub(crate) const SUBQUERY_COLUMN_PREFIX: &str = "__POLARS_SUBQUERY_";
+
+/// Prefix of the join key columns of a decorrelated subquery.
+const SUBQUERY_KEY_PREFIX: &str = "__POLARS_SUBQUERY_KEY_";
+
+/// A subquery whose correlated predicates have been turned into join keys.
+struct DecorrelatedSubquery {
+    /// The result of the subquery, starting with the inner join keys.
+    frame: LazyFrame,
+    /// Names of the inner join keys.
+    inner_keys: Vec<PlSmallStr>,
+    /// The expressions of the enclosing query that the inner join keys are equal to.
+    outer_keys: Vec<SQLExpr>,
+}
+
+impl DecorrelatedSubquery {
+    fn inner_key_exprs(&self) -> Vec<Expr> {
+        self.inner_keys
+            .iter()
+            .map(|name| col(name.clone()))
+            .collect()
+    }
+}
+
+impl SQLContext {
+    /// Add the results of the scalar subqueries in the WHERE clause and the projection of
+    /// `select` as columns of `lf`, which the subquery expressions then refer to.
+    pub(crate) fn process_scalar_subqueries(
+        &mut self,
+        mut lf: LazyFrame,
+        select: &Select,
+    ) -> PolarsResult<LazyFrame> {
+        let projection = select.projection.iter().filter_map(|item| match item {
+            SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => Some(expr),
+            _ => None,
+        });
+        let mut subqueries = vec![];
+        for expr in select.selection.iter().chain(projection) {
+            walk_sql_expr(expr, &mut |e| {
+                if let SQLExpr::Subquery(subquery) = e {
+                    subqueries.push(subquery.as_ref());
+                }
+            });
+        }
+
+        for subquery in subqueries {
+            if !self.subquery_columns.borrow().contains_key(subquery) {
+                lf = self.join_scalar_subquery(lf, subquery)?;
+            }
+        }
+        Ok(lf)
+    }
+
+    /// Apply the `[NOT] EXISTS` and correlated `[NOT] IN` conjuncts of a WHERE clause to `lf` as
+    /// semi and anti joins. Returns the remaining predicate.
+    pub(crate) fn process_subquery_predicates(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &SQLExpr,
+    ) -> PolarsResult<(LazyFrame, Option<SQLExpr>)> {
+        let predicates = split_conjunction(expr);
+        let mut remaining = Vec::with_capacity(predicates.len());
+        for predicate in predicates.iter().copied() {
+            match predicate {
+                SQLExpr::Exists { subquery, negated } => {
+                    lf = self.join_exists_subquery(lf, subquery, *negated)?;
+                },
+                SQLExpr::InSubquery {
+                    expr,
+                    subquery,
+                    negated,
+                } if self.is_correlated(subquery) => {
+                    lf = self.join_in_subquery(lf, expr, subquery, *negated)?;
+                },
+                _ => remaining.push(predicate),
+            }
+        }
+
+        let remaining = if remaining.len() == predicates.len() {
+            Some(expr.clone())
+        } else {
+            remaining
+                .into_iter()
+                .cloned()
+                .reduce(|left, right| SQLExpr::BinaryOp {
+                    left: Box::new(left),
+                    op: BinaryOperator::And,
+                    right: Box::new(right),
+                })
+        };
+        Ok((lf, remaining))
+    }
+
+    fn join_scalar_subquery(
+        &mut self,
+        mut lf: LazyFrame,
+        subquery: &Query,
+    ) -> PolarsResult<LazyFrame> {
+        let mut decorrelated = self.decorrelate_subquery(subquery, true)?;
+        let schema = self.get_frame_schema(&mut decorrelated.frame)?;
+        let n_keys = decorrelated.inner_keys.len();
+        if schema.len() != n_keys + 1 {
+            polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
+        }
+
+        // Register the column before parsing the outer keys, which may hold subqueries too.
+        let name = format_pl_smallstr!(
+            "{SUBQUERY_COLUMN_PREFIX}{}",
+            self.subquery_columns.borrow().len()
+        );
+        self.subquery_columns
+            .borrow_mut()
+            .insert(subquery.clone(), name.clone());
+
+        let value = schema.get_at_index(n_keys).unwrap().0.clone();
+        let mut projection = decorrelated.inner_key_exprs();
+        projection.push(col(value).alias(name.clone()));
+        let frame = decorrelated.frame.clone().select(projection);
+
+        // An uncorrelated subquery yields a single value (or null) for all rows.
+        if decorrelated.outer_keys.is_empty() {
+            let value = col(name).agg_with_fmt_str(
+                |c: Column| match c.len() {
+                    0 => Ok(Some(Column::full_null(c.name().clone(), 1, c.dtype()))),
+                    1 => Ok(Some(c)),
+                    _ => polars_bail!(SQLSyntax: "SQL subquery returns more than one row"),
+                },
+                GetOutput::same_type(),
+                "scalar_subquery",
+            );
+            return Ok(lf.cross_join(frame.select([value]), None));
+        }
+
+        let outer_schema = self.get_frame_schema(&mut lf)?;
+        let left_on = decorrelated
+            .outer_keys
+            .iter()
+            .map(|e| parse_sql_expr(e, self, Some(&outer_schema)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut output = outer_schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        // COUNT over no rows is zero, rather than null for outer rows without a match.
+        output.push(if is_count_projection(subquery) {
+            col(name.clone()).fill_null(lit(0)).alias(name)
+        } else {
+            col(name)
+        });
+
+        Ok(lf
+            .join_builder()
+            .with(frame)
+            .left_on(left_on)
+            .right_on(decorrelated.inner_key_exprs())
+            .how(JoinType::Left)
+            .coalesce(JoinCoalesce::KeepColumns)
+            .maintain_order(MaintainOrderJoin::Left)
+            .finish()
+            .select(output))
+    }
+
+    fn join_exists_subquery(
+        &mut self,
+        mut lf: LazyFrame,
+        subquery: &Query,
+        negated: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let decorrelated = self.decorrelate_subquery(subquery, false)?;
+
+        // An uncorrelated subquery either keeps or removes all rows.
+        if decorrelated.outer_keys.is_empty() {
+            const EXISTS: PlSmallStr = PlSmallStr::from_static("__POLARS_SUBQUERY_EXISTS");
+            let schema = self.get_frame_schema(&mut lf)?;
+            let exists = decorrelated.frame.select([len().gt(lit(0)).alias(EXISTS)]);
+            let predicate = if negated {
+                col(EXISTS).not()
+            } else {
+                col(EXISTS)
+            };
+            return Ok(lf.cross_join(exists, None).filter(predicate).select(
+                schema
+                    .iter_names()
+                    .map(|name| col(name.clone()))
+                    .collect::<Vec<_>>(),
+            ));
+        }
+
+        let left_on = self.parse_outer_keys(&mut lf, &decorrelated.outer_keys)?;
+        let right_on = decorrelated.inner_key_exprs();
+        semi_anti_join(lf, decorrelated.frame, left_on, right_on, negated)
+    }
+
+    fn join_in_subquery(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &SQLExpr,
+        subquery: &Query,
+        negated: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let mut decorrelated = self.decorrelate_subquery(subquery, false)?;
+        let schema = self.get_frame_schema(&mut decorrelated.frame)?;
+        let n_keys = decorrelated.inner_keys.len();
+        if schema.len() != n_keys + 1 {
+            polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
+        }
+
+        let outer_keys = self.parse_outer_keys(&mut lf, &decorrelated.outer_keys)?;
+        let outer_schema = self.get_frame_schema(&mut lf)?;
+        let outer_value = parse_sql_expr(expr, self, Some(&outer_schema))?;
+        let inner_keys = decorrelated.inner_key_exprs();
+        let inner_value = col(schema.get_at_index(n_keys).unwrap().0.clone());
+
+        // `x NOT IN (...)` is NULL rather than TRUE if `x` or any value of the subquery is NULL,
+        // unless the subquery returns no rows.
+        if negated {
+            const IS_NULL: PlSmallStr = PlSmallStr::from_static("__POLARS_SUBQUERY_IS_NULL");
+            let with_nulls = decorrelated
+                .frame
+                .clone()
+                .filter(inner_value.clone().is_null())
+                .select(inner_keys.clone());
+            lf = semi_anti_join(lf, with_nulls, outer_keys.clone(), inner_keys.clone(), true)?;
+
+            let non_empty = decorrelated
+                .frame
+                .clone()
+                .select([inner_keys.clone(), vec![lit(true).alias(IS_NULL)]].concat());
+            lf = semi_anti_join(
+                lf,
+                non_empty,
+                [outer_keys.clone(), vec![outer_value.clone().is_null()]].concat(),
+                [inner_keys.clone(), vec![col(IS_NULL)]].concat(),
+                true,
+            )?;
+        }
+
+        let left_on = [outer_keys, vec![outer_value]].concat();
+        let right_on = [inner_keys, vec![inner_value]].concat();
+        semi_anti_join(lf, decorrelated.frame, left_on, right_on, negated)
+    }
+
+    /// Join `lf` with a LATERAL subquery, e.g. `FROM t, LATERAL (SELECT ... WHERE s.k = t.k) x`.
+    /// Returns the joined frame, and the (aliased) result of the subquery.
+    ///
+    /// The ORDER BY and LIMIT/OFFSET of a correlated subquery apply to the rows of each join key,
+    /// so that `ORDER BY ... LIMIT n` gives the top-n rows for each row of the enclosing query.
+    pub(crate) fn join_lateral_subquery(
+        &mut self,
+        mut lf: LazyFrame,
+        subquery: &Query,
+        alias: &TableAlias,
+        how: JoinType,
+    ) -> PolarsResult<(LazyFrame, LazyFrame)> {
+        let name = alias.name.value.as_str();
+        if !self.is_correlated(subquery) {
+            let rf = self.execute_query_no_ctes(subquery)?;
+            let rf = self.rename_columns_from_table_alias(rf, alias)?;
+            let joined = lf.cross_join(rf.clone(), Some(format_pl_smallstr!(":{name}")));
+            return Ok((joined, rf));
+        }
+
+        let mut query = subquery.clone();
+        let order_by = query.order_by.take();
+        let (limit, offset) = (query.limit.take(), query.offset.take());
+        let group_by_keys =
+            matches!(query.body.as_ref(), SetExpr::Select(select) if is_aggregate_select(select));
+        let decorrelated = self.decorrelate_subquery(&query, group_by_keys)?;
+        let keys = decorrelated.inner_key_exprs();
+        let left_on = self.parse_outer_keys(&mut lf, &decorrelated.outer_keys)?;
+        let mut frame = self.process_order_by(decorrelated.frame, &order_by, None)?;
+
+        if limit.is_some() || offset.is_some() {
+            let parse_count = |value: &SQLExpr, clause: &str| match value {
+                SQLExpr::Value(SQLValue::Number(n, _)) => n
+                    .parse::<IdxSize>()
+                    .map_err(|e| polars_err!(SQLInterface: "{} conversion error: {}", clause, e)),
+                _ => {
+                    polars_bail!(SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported")
+                },
+            };
+            let offset = match &offset {
+                Some(Offset { value, .. }) => parse_count(value, "OFFSET")?,
+                None => 0,
+            };
+            // The (one-based) position of each row among the rows of its join key.
+            let position = keys[0].clone().cum_count(false).over(keys.clone());
+            let mut predicate = position.clone().gt(lit(offset));
+            if let Some(limit) = &limit {
+                let limit = parse_count(limit, "LIMIT")?;
+                predicate = predicate.and(position.lt_eq(lit(offset + limit)));
+            }
+            frame = frame.filter(predicate);
+        }
+
+        // Columns that also exist on the left are suffixed, as for other joins.
+        let schema = self.get_frame_schema(&mut frame)?;
+        let values = schema
+            .iter_names()
+            .skip(keys.len())
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        let mut rf = self.rename_columns_from_table_alias(frame.clone().select(values), alias)?;
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        let mut right_cols = keys.clone();
+        let mut output = left_schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        for (value, column) in schema
+            .iter_names()
+            .skip(keys.len())
+            .zip(right_schema.iter_names())
+        {
+            let output_name = if left_schema.contains(column) {
+                format_pl_smallstr!("{column}:{name}")
+            } else {
+                column.clone()
+            };
+            right_cols.push(col(value.clone()).alias(output_name.clone()));
+            output.push(col(output_name));
+        }
+
+        let joined = lf
+            .join_builder()
+            .with(frame.select(right_cols))
+            .left_on(left_on)
+            .right_on(keys)
+            .how(how)
+            .coalesce(JoinCoalesce::KeepColumns)
+            .maintain_order(MaintainOrderJoin::Left)
+            .finish()
+            .select(output);
+        Ok((joined, rf))
+    }
+
+    fn parse_outer_keys(
+        &mut self,
+        lf: &mut LazyFrame,
+        outer_keys: &[SQLExpr],
+    ) -> PolarsResult<Vec<Expr>> {
+        let schema = self.get_frame_schema(lf)?;
+        outer_keys
+            .iter()
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .collect()
+    }
+
+    /// Whether the WHERE clause of `subquery` references the enclosing query.
+    fn is_correlated(&self, subquery: &Query) -> bool {
+        match subquery.body.as_ref() {
+            // Unsupported correlated predicates are reported when decorrelating.
+            SetExpr::Select(select) => self
+                .correlated_predicates(select)
+                .map_or(true, |(_, keys)| !keys.is_empty()),
+            _ => false,
+        }
+    }
+
+    /// Evaluate `subquery` with the inner sides of its correlated equality predicates as
+    /// leading columns. If `group_by_keys` is set, the subquery is also grouped by them.
+    fn decorrelate_subquery(
+        &mut self,
+        subquery: &Query,
+        group_by_keys: bool,
+    ) -> PolarsResult<DecorrelatedSubquery> {
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        let (select, predicates, keys) = match subquery.body.as_ref() {
+            SetExpr::Select(select) => {
+                let (predicates, keys) = self.correlated_predicates(select)?;
+                (select, predicates, keys)
+            },
+            _ => {
+                return Ok(DecorrelatedSubquery {
+                    frame: self.execute_query_no_ctes(subquery)?,
+                    inner_keys: vec![],
+                    outer_keys: vec![],
+                });
+            },
+        };
+        if keys.is_empty() {
+            return Ok(DecorrelatedSubquery {
+                frame: self.execute_query_no_ctes(subquery)?,
+                inner_keys: vec![],
+                outer_keys: vec![],
+            });
+        }
+        if subquery.limit.is_some() || subquery.offset.is_some() {
+            polars_bail!(SQLInterface: "LIMIT and OFFSET are not supported in correlated subqueries");
+        }
+
+        let (inner_exprs, outer_keys): (Vec<_>, Vec<_>) = keys.into_iter().unzip();
+        let inner_keys = (0..inner_exprs.len())
+            .map(|i| format_pl_smallstr!("{SUBQUERY_KEY_PREFIX}{i}"))
+            .collect::<Vec<_>>();
+
+        let mut select = select.as_ref().clone();
+        select.selection = predicates
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            });
+        if group_by_keys {
+            // Note: GROUP BY ALL picks up the keys from the projection by itself.
+            if let GroupByExpr::Expressions(exprs, _) = &mut select.group_by {
+                exprs.extend(inner_exprs.iter().cloned());
+            }
+        }
+        select.projection = inner_exprs
+            .into_iter()
+            .zip(&inner_keys)
+            .map(|(expr, name)| SelectItem::ExprWithAlias {
+                expr,
+                alias: Ident::new(name.as_str()),
+            })
+            .chain(select.projection)
+            .collect();
+
+        let mut query = subquery.clone();
+        query.body = Box::new(SetExpr::Select(Box::new(select)));
+        query.order_by = None;
+        Ok(DecorrelatedSubquery {
+            frame: self.execute_query_no_ctes(&query)?,
+            inner_keys,
+            outer_keys,
+        })
+    }
+
+    /// Split the WHERE clause of `select` into the predicates that only reference the subquery
+    /// itself, and the (inner, outer) sides of the equalities that reference the enclosing query.
+    #[allow(clippy::type_complexity)]
+    fn correlated_predicates(
+        &self,
+        select: &Select,
+    ) -> PolarsResult<(Vec<SQLExpr>, Vec<(SQLExpr, SQLExpr)>)> {
+        let mut predicates = vec![];
+        let mut keys = vec![];
+        let Some(selection) = &select.selection else {
+            return Ok((predicates, keys));
+        };
+
+        let inner_relations = relation_names(&select.from);
+        let is_outer = |idents: &[Ident]| {
+            idents.len() > 1
+                && !inner_relations.contains(idents[0].value.as_str())
+                && self
+                    .get_table_from_current_scope(&idents[0].value)
+                    .is_some()
+        };
+        let references_outer = |e: &SQLExpr| any_identifier(e, |idents| is_outer(idents));
+        let only_outer = |e: &SQLExpr| references_outer(e) && !any_identifier(e, |i| !is_outer(i));
+
+        for predicate in split_conjunction(selection) {
+            if !references_outer(predicate) {
+                predicates.push(predicate.clone());
+                continue;
+            }
+            match predicate {
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } if only_outer(right) && !references_outer(left) => {
+                    keys.push((left.as_ref().clone(), right.as_ref().clone()))
+                },
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } if only_outer(left) && !references_outer(right) => {
+                    keys.push((right.as_ref().clone(), left.as_ref().clone()))
+                },
+                _ => polars_bail!(
+                    SQLInterface:
+                    "correlated subquery predicates must be equalities between subquery and outer query expressions; found {}",
+                    predicate
+                ),
+            }
+        }
+        Ok((predicates, keys))
+    }
+}
+
+fn semi_anti_join(
+    lf: LazyFrame,
+    other: LazyFrame,
+    left_on: Vec<Expr>,
+    right_on: Vec<Expr>,
+    negated: bool,
+) -> PolarsResult<LazyFrame> {
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let how = if negated {
+            JoinType::Anti
+        } else {
+            JoinType::Semi
+        };
+        Ok(lf.join(other, left_on, right_on, JoinArgs::new(how)))
+    }
+    #[cfg(not(feature = "semi_anti_join"))]
+    {
+        let _ = (lf, other, left_on, right_on, negated);
+        polars_bail!(SQLInterface: "EXISTS and correlated IN subqueries require the 'semi_anti_join' feature")
+    }
+}
+
+/// Whether the subquery projects a single `COUNT` aggregate.
+fn is_count_projection(query: &Query) -> bool {
+    let SetExpr::Select(select) = query.body.as_ref() else {
+        return false;
+    };
+    match select.projection.as_slice() {
+        [
+            SelectItem::UnnamedExpr(SQLExpr::Function(function))
+            | SelectItem::ExprWithAlias {
+                expr: SQLExpr::Function(function),
+                ..
+            },
+        ] => function
+            .name
+            .0
+            .last()
+            .is_some_and(|ident| ident.value.eq_ignore_ascii_case("count")),
+        _ => false,
+    }
+}
+
+/// Whether `select` aggregates its rows, with a GROUP BY or aggregate functions in its projection.
+fn is_aggregate_select(select: &Select) -> bool {
+    const AGGREGATE_FUNCTIONS: &[&str] = &[
+        "array_agg",
+        "avg",
+        "corr",
+        "count",
+        "covar",
+        "covar_pop",
+        "covar_samp",
+        "first",
+        "last",
+        "max",
+        "median",
+        "min",
+        "mode",
+        "percentile_cont",
+        "percentile_disc",
+        "quantile_cont",
+        "quantile_disc",
+        "stddev",
+        "stddev_samp",
+        "stdev",
+        "stdev_samp",
+        "sum",
+        "var",
+        "var_samp",
+        "variance",
+    ];
+    if !matches!(&select.group_by, GroupByExpr::Expressions(exprs, _) if exprs.is_empty()) {
+        return true;
+    }
+    select.projection.iter().any(|item| match item {
+        SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+            let mut found = false;
+            walk_sql_expr(expr, &mut |e| {
+                if let SQLExpr::Function(function) = e {
+                    found |= function.over.is_none()
+                        && function.name.0.last().is_some_and(|ident| {
+                            AGGREGATE_FUNCTIONS.contains(&ident.value.to_lowercase().as_str())
+                        });
+                }
+            });
+            found
+        },
+        _ => false,
+    })
+}
+
+/// The names and aliases of the relations in a FROM clause.
+pub(crate) fn relation_names(from: &[TableWithJoins]) -> PlHashSet<String> {
+    fn add_relation(relation: &TableFactor, names: &mut PlHashSet<String>) {
+        let alias = match relation {
+            TableFactor::Table { name, alias, .. } => {
+                if let Some(ident) = name.0.last() {
+                    names.insert(ident.value.clone());
+                }
+                alias.as_ref()
+            },
+            TableFactor::Derived { alias, .. } | TableFactor::UNNEST { alias, .. } => {
+                alias.as_ref()
+            },
+            TableFactor::NestedJoin {
+                table_with_joins,
+                alias,
+            } => {
+                add_table_with_joins(table_with_joins, names);
+                alias.as_ref()
+            },
+            _ => None,
+        };
+        if let Some(alias) = alias {
+            names.insert(alias.name.value.clone());
+        }
+    }
+    fn add_table_with_joins(tbl: &TableWithJoins, names: &mut PlHashSet<String>) {
+        add_relation(&tbl.relation, names);
+        for join in &tbl.joins {
+            add_relation(&join.relation, names);
+        }
+    }
+
+    let mut names = PlHashSet::new();
+    for tbl in from {
+        add_table_with_joins(tbl, &mut names);
+    }
+    names
+}
+
+/// The operands of the top-level `AND`s of a predicate.
+pub(crate) fn split_conjunction(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut predicates = split_conjunction(left);
+            predicates.extend(split_conjunction(right));
+            predicates
+        },
+        SQLExpr::Nested(expr) => split_conjunction(expr),
+        _ => vec![expr],
+    }
+}
+
+/// Whether `f` holds for one of the (compound) identifiers in `expr`, outside of subqueries.
+pub(crate) fn any_identifier(expr: &SQLExpr, f: impl Fn(&[Ident]) -> bool) -> bool {
+    let mut found = false;
+    walk_sql_expr(expr, &mut |e| match e {
+        SQLExpr::Identifier(ident) => found |= f(std::slice::from_ref(ident)),
+        SQLExpr::CompoundIdentifier(idents) => found |= f(idents),
+        _ => {},
+    });
+    found
+}
+
+/// Call `f` on `expr` and its sub-expressions, not descending into subqueries.
+fn walk_sql_expr<'a>(expr: &'a SQLExpr, f: &mut dyn FnMut(&'a SQLExpr)) {
+    f(expr);
+    match expr {
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::AnyOp { left, right, .. }
+        | SQLExpr::AllOp { left, right, .. }
+        | SQLExpr::IsDistinctFrom(left, right)
+        | SQLExpr::IsNotDistinctFrom(left, right)
+        | SQLExpr::Like {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::ILike {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::SimilarTo {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::RLike {
+            expr: left,
+            pattern: right,
+            ..
+        } => {
+            walk_sql_expr(left, f);
+            walk_sql_expr(right, f);
+        },
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsNotFalse(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::Ceil { expr, .. }
+        | SQLExpr::Floor { expr, .. }
+        | SQLExpr::Extract { expr, .. }
+        | SQLExpr::Trim { expr, .. }
+        | SQLExpr::Subscript { expr, .. }
+        | SQLExpr::InSubquery { expr, .. } => walk_sql_expr(expr, f),
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => {
+            walk_sql_expr(expr, f);
+            walk_sql_expr(low, f);
+            walk_sql_expr(high, f);
+        },
+        SQLExpr::InList { expr, list, .. } => {
+            walk_sql_expr(expr, f);
+            list.iter().for_each(|e| walk_sql_expr(e, f));
+        },
+        SQLExpr::Tuple(exprs) => exprs.iter().for_each(|e| walk_sql_expr(e, f)),
+        SQLExpr::Array(arr) => arr.elem.iter().for_each(|e| walk_sql_expr(e, f)),
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            operand
+                .iter()
+                .chain(else_result)
+                .for_each(|e| walk_sql_expr(e, f));
+            conditions
+                .iter()
+                .chain(results)
+                .for_each(|e| walk_sql_expr(e, f));
+        },
+        SQLExpr::Function(function) => {
+            if let FunctionArguments::List(list) = &function.args {
+                for arg in &list.args {
+                    let (FunctionArg::Named { arg, .. }
+                    | FunctionArg::ExprNamed { arg, .. }
+                    | FunctionArg::Unnamed(arg)) = arg;
+                    if let FunctionArgExpr::Expr(e) = arg {
+                        walk_sql_expr(e, f);
+                    }
+                }
+            }
+        },
+        _ => {},
+    }
+}
@@ -1,7 +1,7 @@
 //! This module supports mapping SQL datatypes to Polars datatypes.
 //!
 //! It also provides utility functions for working with SQL datatypes.
-use polars_core::datatypes::{DataType, TimeUnit};
+use polars_core::datatypes::{DataType, TimeUnit, TimeZone};
 use polars_error::{PolarsResult, polars_bail};
 use polars_plan::dsl::{Expr, lit};
 use sqlparser::ast::{
@@ -10,6 +10,7 @@ use sqlparser::ast::{
 
 polars_utils::regex_cache::cached_regex! {
     static DATETIME_LITERAL_RE = r"^\d{4}-[01]\d-[0-3]\d[ T](?:[01][0-9]|2[0-3]):[0-5][0-9](?::[0-5][0-9](\.\d{1,9})?)?$";
+    static DATETIME_OFFSET_LITERAL_RE = r"^\d{4}-[01]\d-[0-3]\d[ T](?:[01][0-9]|2[0-3]):[0-5][0-9](?::[0-5][0-9](\.\d{1,9})?)?(?:Z|[+-]\d{2}(?::?\d{2})?)$";
     static DATE_LITERAL_RE = r"^\d{4}-[01]\d-[0-3]\d$";
     static TIME_LITERAL_RE = r"^(?:[01][0-9]|2[0-3]):[0-5][0-9](?::[0-5][0-9](\.\d{1,9})?)?$";
 }
@@ -36,6 +37,11 @@ pub fn is_iso_datetime(value: &str) -> bool {
     DATETIME_LITERAL_RE.is_match(value)
 }
 
+/// Check for an ISO datetime with a UTC offset, eg: '2024-01-01 10:00:00+02:00'.
+pub fn is_iso_datetime_with_offset(value: &str) -> bool {
+    DATETIME_OFFSET_LITERAL_RE.is_match(value)
+}
+
 pub fn is_iso_date(value: &str) -> bool {
     DATE_LITERAL_RE.is_match(value)
 }
@@ -56,14 +62,19 @@ fn timeunit_from_precision(prec: &Option<u64>) -> PolarsResult<TimeUnit> {
     })
 }
 
-pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataType> {
+/// Map a SQL datatype to the equivalent Polars datatype; timestamps "WITH TIME ZONE"
+/// are in the given (session) time zone.
+pub(crate) fn map_sql_dtype_to_polars(
+    dtype: &SQLDataType,
+    time_zone: &TimeZone,
+) -> PolarsResult<DataType> {
     Ok(match dtype {
         // ---------------------------------
         // array/list
         // ---------------------------------
         SQLDataType::Array(ArrayElemTypeDef::AngleBracket(inner_type))
         | SQLDataType::Array(ArrayElemTypeDef::SquareBracket(inner_type, _)) => {
-            DataType::List(Box::new(map_sql_dtype_to_polars(inner_type)?))
+            DataType::List(Box::new(map_sql_dtype_to_polars(inner_type, time_zone)?))
         },
 
         // ---------------------------------
@@ -99,6 +110,7 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         SQLDataType::UInt16 => DataType::UInt16,
         SQLDataType::UInt32 => DataType::UInt32,
         SQLDataType::UInt64 => DataType::UInt64,
+        SQLDataType::UInt128 => DataType::UInt128,
         SQLDataType::UnsignedTinyInt(_) => DataType::UInt8,
         SQLDataType::UnsignedInt(_) | SQLDataType::UnsignedInteger(_) => DataType::UInt32,
         SQLDataType::UnsignedInt2(_) | SQLDataType::UnsignedSmallInt(_) => DataType::UInt16,
@@ -144,16 +156,18 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         SQLDataType::Date => DataType::Date,
         SQLDataType::Interval => DataType::Duration(TimeUnit::Microseconds),
         SQLDataType::Time(_, tz) => match tz {
-            TimezoneInfo::None => DataType::Time,
+            TimezoneInfo::None | TimezoneInfo::WithoutTimeZone => DataType::Time,
             _ => {
                 polars_bail!(SQLInterface: "`time` with timezone is not supported; found tz={}", tz)
             },
         },
         SQLDataType::Datetime(prec) => DataType::Datetime(timeunit_from_precision(prec)?, None),
         SQLDataType::Timestamp(prec, tz) => match tz {
-            TimezoneInfo::None => DataType::Datetime(timeunit_from_precision(prec)?, None),
-            _ => {
-                polars_bail!(SQLInterface: "`timestamp` with timezone is not (yet) supported")
+            TimezoneInfo::None | TimezoneInfo::WithoutTimeZone => {
+                DataType::Datetime(timeunit_from_precision(prec)?, None)
+            },
+            TimezoneInfo::WithTimeZone | TimezoneInfo::Tz => {
+                DataType::Datetime(timeunit_from_precision(prec)?, Some(time_zone.clone()))
             },
         },
 
@@ -184,6 +198,7 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
                 "uint2" | "usmallint" => DataType::UInt16,
                 "uint4" | "uinteger" | "uint" => DataType::UInt32,
                 "uint8" | "ubigint" => DataType::UInt64,
+                "uhugeint" => DataType::UInt128,
                 _ => {
                     polars_bail!(SQLInterface: "datatype {:?} is not currently supported", value)
                 },
@@ -197,3 +212,33 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         },
     })
 }
+
+/// Map a Polars datatype to the name of the equivalent SQL datatype (as reported by the
+/// `information_schema` and `DESCRIBE`).
+pub(crate) fn map_polars_dtype_to_sql(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Boolean => "BOOLEAN".to_string(),
+        DataType::Int8 => "TINYINT".to_string(),
+        DataType::Int16 => "SMALLINT".to_string(),
+        DataType::Int32 => "INTEGER".to_string(),
+        DataType::Int64 => "BIGINT".to_string(),
+        DataType::Int128 => "HUGEINT".to_string(),
+        DataType::UInt8 => "UTINYINT".to_string(),
+        DataType::UInt16 => "USMALLINT".to_string(),
+        DataType::UInt32 => "UINTEGER".to_string(),
+        DataType::UInt64 => "UBIGINT".to_string(),
+        DataType::UInt128 => "UHUGEINT".to_string(),
+        DataType::Float32 => "REAL".to_string(),
+        DataType::Float64 => "DOUBLE PRECISION".to_string(),
+        DataType::Decimal(Some(p), Some(s)) => format!("DECIMAL({p},{s})"),
+        DataType::String => "VARCHAR".to_string(),
+        DataType::Binary => "BYTEA".to_string(),
+        DataType::Date => "DATE".to_string(),
+        DataType::Time => "TIME".to_string(),
+        DataType::Datetime(_, None) => "TIMESTAMP".to_string(),
+        DataType::Datetime(_, Some(_)) => "TIMESTAMP WITH TIME ZONE".to_string(),
+        DataType::Duration(_) => "INTERVAL".to_string(),
+        DataType::List(inner) => format!("{}[]", map_polars_dtype_to_sql(inner)),
+        dtype => dtype.to_string().to_uppercase(),
+    }
+}
@@ -0,0 +1,229 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx(dialect: SQLDialect) -> SQLContext {
+    let df = df! {
+        "x" => [7i64, -7, 6],
+        "y" => [2i64, 2, 3],
+        "n" => [Some(2), None, Some(1)],
+        "Mixed" => ["a", "b", "c"],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new().with_dialect(dialect);
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+fn assert_sql_result(dialect: SQLDialect, sql: &str, expected: DataFrame) {
+    let mut ctx = create_ctx(dialect);
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_dialect_from_str() {
+    assert_eq!(
+        "postgres".parse::<SQLDialect>().unwrap(),
+        SQLDialect::PostgreSQL
+    );
+    assert_eq!("DuckDB".parse::<SQLDialect>().unwrap(), SQLDialect::DuckDB);
+    assert!("oracle".parse::<SQLDialect>().is_err());
+}
+
+#[test]
+fn test_integer_division() {
+    assert_sql_result(
+        SQLDialect::Generic,
+        "SELECT x / y AS q FROM df",
+        df! { "q" => [3.5, -3.5, 2.0] }.unwrap(),
+    );
+    assert_sql_result(
+        SQLDialect::PostgreSQL,
+        "SELECT x / y AS q, x / 2.0 AS f FROM df",
+        df! {
+            "q" => [3i64, -3, 2],
+            "f" => [3.5, -3.5, 3.0],
+        }
+        .unwrap(),
+    );
+    assert_sql_result(
+        SQLDialect::MySQL,
+        "SELECT x DIV y AS q, x / y AS f FROM df",
+        df! {
+            "q" => [3i64, -3, 2],
+            "f" => [3.5, -3.5, 2.0],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_identifier_case() {
+    // PostgreSQL folds unquoted identifiers to lowercase
+    assert_sql_result(
+        SQLDialect::PostgreSQL,
+        r#"SELECT X, "Mixed" FROM DF WHERE Y = 3"#,
+        df! {
+            "x" => [6i64],
+            "Mixed" => ["c"],
+        }
+        .unwrap(),
+    );
+    let mut ctx = create_ctx(SQLDialect::PostgreSQL);
+    assert!(ctx.execute("SELECT Mixed FROM df").is_err());
+
+    // the generic dialect is case-sensitive
+    let mut ctx = create_ctx(SQLDialect::Generic);
+    assert!(ctx.execute("SELECT X FROM df").is_err());
+
+    // DuckDB, MySQL and Snowflake identifiers are case-insensitive
+    for dialect in [SQLDialect::DuckDB, SQLDialect::MySQL, SQLDialect::Snowflake] {
+        assert_sql_result(
+            dialect,
+            "SELECT MIXED, mixed AS m2 FROM DF AS t WHERE T.X = 6",
+            df! {
+                "Mixed" => ["c"],
+                "m2" => ["c"],
+            }
+            .unwrap(),
+        );
+    }
+    assert_sql_result(
+        SQLDialect::MySQL,
+        "SELECT `Mixed` FROM `df` WHERE `y` = 3",
+        df! { "Mixed" => ["c"] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_identifier_case_resolves_referenced_tables() {
+    // only the schemas of the tables that a statement references are resolved
+    let mut ctx = create_ctx(SQLDialect::DuckDB);
+    let invalid = df! { "a" => [1] }.unwrap().lazy().select([col("missing")]);
+    ctx.register("invalid", invalid);
+    let actual = ctx.execute("SELECT MIXED FROM DF WHERE Y = 3").unwrap();
+    assert!(
+        actual
+            .collect()
+            .unwrap()
+            .equals(&df! { "Mixed" => ["c"] }.unwrap())
+    );
+    assert!(ctx.execute("SELECT * FROM INVALID").is_err());
+}
+
+#[test]
+fn test_nulls_ordering() {
+    for (dialect, asc, desc) in [
+        (
+            SQLDialect::Generic,
+            [Some(1), Some(2), None],
+            [None, Some(2), Some(1)],
+        ),
+        (
+            SQLDialect::PostgreSQL,
+            [Some(1), Some(2), None],
+            [None, Some(2), Some(1)],
+        ),
+        (
+            SQLDialect::MySQL,
+            [None, Some(1), Some(2)],
+            [Some(2), Some(1), None],
+        ),
+        (
+            SQLDialect::DuckDB,
+            [Some(1), Some(2), None],
+            [Some(2), Some(1), None],
+        ),
+    ] {
+        assert_sql_result(
+            dialect,
+            "SELECT n FROM df ORDER BY n",
+            df! { "n" => asc }.unwrap(),
+        );
+        assert_sql_result(
+            dialect,
+            "SELECT n FROM df ORDER BY n DESC",
+            df! { "n" => desc }.unwrap(),
+        );
+        // explicit NULLS FIRST/LAST is respected by every dialect
+        assert_sql_result(
+            dialect,
+            "SELECT n FROM df ORDER BY n NULLS FIRST",
+            df! { "n" => [None, Some(1), Some(2)] }.unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_function_aliases() {
+    assert_sql_result(
+        SQLDialect::Snowflake,
+        r#"
+        SELECT
+          NVL(n, 0) AS n,
+          IFF(x > 0, 'pos', 'neg') AS sign,
+          CAST(DATEADD(month, 1, DATE '2024-01-31') AS VARCHAR) AS dt
+        FROM df
+    "#,
+        df! {
+            "n" => [2, 0, 1],
+            "sign" => ["pos", "neg", "pos"],
+            "dt" => ["2024-02-29", "2024-02-29", "2024-02-29"],
+        }
+        .unwrap(),
+    );
+    for dialect in [SQLDialect::MySQL, SQLDialect::Snowflake] {
+        assert_sql_result(
+            dialect,
+            "SELECT CAST(TIMESTAMPADD(DAY, -1, DATE '2024-03-01') AS VARCHAR) AS dt",
+            df! { "dt" => ["2024-02-29"] }.unwrap(),
+        );
+    }
+    // an offset that doesn't fit the duration is an error, rather than an overflow
+    let mut ctx = create_ctx(SQLDialect::Snowflake);
+    assert!(
+        ctx.execute("SELECT DATEADD(millennium, 9223372036854775807, DATE '2024-01-31')")
+            .is_err()
+    );
+    // NVL is not an alias in the generic dialect
+    let mut ctx = create_ctx(SQLDialect::Generic);
+    assert!(ctx.execute("SELECT NVL(n, 0) FROM df").is_err());
+}
+
+#[test]
+fn test_date_trunc() {
+    for dialect in [
+        SQLDialect::Generic,
+        SQLDialect::DuckDB,
+        SQLDialect::PostgreSQL,
+        SQLDialect::Snowflake,
+    ] {
+        assert_sql_result(
+            dialect,
+            "SELECT CAST(DATE_TRUNC('month', DATE '2024-05-17') AS VARCHAR) AS dt",
+            df! { "dt" => ["2024-05-01"] }.unwrap(),
+        );
+    }
+    assert_sql_result(
+        SQLDialect::DuckDB,
+        "SELECT CAST(DATETRUNC('year', DATE '2024-05-17') AS VARCHAR) AS dt",
+        df! { "dt" => ["2024-01-01"] }.unwrap(),
+    );
+    // Snowflake's TRUNC takes the date part last
+    assert_sql_result(
+        SQLDialect::Snowflake,
+        "SELECT CAST(TRUNC(DATE '2024-05-17', 'month') AS VARCHAR) AS dt",
+        df! { "dt" => ["2024-05-01"] }.unwrap(),
+    );
+    // the argument order is not guessed
+    let mut ctx = create_ctx(SQLDialect::Generic);
+    assert!(
+        ctx.execute("SELECT DATE_TRUNC(DATE '2024-05-17', 'month')")
+            .is_err()
+    );
+}
@@ -185,3 +185,103 @@ fn test_corr_group_by() {
 
     assert_eq!(expected, actual, "expected {expected:?}, got {actual:?}");
 }
+
+#[test]
+fn test_aggregate_filter() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df_corr());
+    let sql = r#"
+    SELECT
+        c,
+        SUM(a) FILTER (WHERE b > 5) AS s,
+        COUNT(*) FILTER (WHERE b > 5) AS n,
+        MAX(a) AS m
+    FROM df
+    GROUP BY c
+    ORDER BY c"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "c" => ["a", "b"],
+        "s" => [7, 11],
+        "n" => [2 as IdxSize, 2],
+        "m" => [4, 6],
+    }
+    .unwrap();
+    assert_eq!(expected, actual, "expected {expected:?}, got {actual:?}");
+}
+
+#[test]
+fn test_percentile_within_group() {
+    for &q in &[0.25, 0.5, 0.75] {
+        let expr = col("Data").quantile(lit(q), QuantileMethod::Linear);
+        let sql_expr = format!("PERCENTILE_CONT({q}) WITHIN GROUP (ORDER BY Data)");
+        let (expected, actual) = create_expected(expr, &sql_expr);
+        assert!(
+            expected.equals(&actual),
+            "q: {q}: expected {expected:?}, got {actual:?}"
+        );
+
+        let expr = col("Data").quantile(lit(q), QuantileMethod::Equiprobable);
+        let sql_expr = format!("PERCENTILE_DISC({q}) WITHIN GROUP (ORDER BY Data)");
+        let (expected, actual) = create_expected(expr, &sql_expr);
+        assert!(
+            expected.equals(&actual),
+            "q: {q}: expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    // a descending ordering mirrors the fraction
+    let expr = col("Data").quantile(lit(0.75), QuantileMethod::Linear);
+    let sql_expr = "PERCENTILE_CONT(0.25) WITHIN GROUP (ORDER BY Data DESC)";
+    let (expected, actual) = create_expected(expr, sql_expr);
+    assert!(expected.equals(&actual));
+}
+
+#[test]
+fn test_percentile_disc_descending() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df! { "x" => [1, 2, 3, 4] }.unwrap().lazy());
+    let sql = r#"
+    SELECT
+        PERCENTILE_DISC(0.0) WITHIN GROUP (ORDER BY x DESC) AS p0,
+        PERCENTILE_DISC(0.25) WITHIN GROUP (ORDER BY x DESC) AS p25,
+        PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY x DESC) AS p50,
+        PERCENTILE_DISC(0.6) WITHIN GROUP (ORDER BY x DESC) AS p60,
+        PERCENTILE_DISC(1.0) WITHIN GROUP (ORDER BY x DESC) AS p100
+    FROM df"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "p0" => [4],
+        "p25" => [4],
+        "p50" => [3],
+        "p60" => [2],
+        "p100" => [1],
+    }
+    .unwrap();
+    assert_eq!(expected, actual, "expected {expected:?}, got {actual:?}");
+}
+
+#[test]
+fn test_mode_within_group() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df! { "x" => [1, 2, 2, 3, 3, 4] }.unwrap().lazy());
+    let sql = r#"
+    SELECT
+        MODE() WITHIN GROUP (ORDER BY x) AS lo,
+        MODE() WITHIN GROUP (ORDER BY x DESC) AS hi
+    FROM df"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! { "lo" => [2], "hi" => [3] }.unwrap();
+    assert_eq!(expected, actual, "expected {expected:?}, got {actual:?}");
+}
+
+#[test]
+fn test_within_group_unsupported_function() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    let sql = "SELECT SUM(Data) WITHIN GROUP (ORDER BY Data) FROM df";
+    assert!(ctx.execute(sql).is_err());
+}
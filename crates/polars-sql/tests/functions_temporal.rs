@@ -0,0 +1,147 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+        "id" => [1, 2],
+        "ts" => ["2024-01-01 12:00:00", "2024-07-01 12:00:00"],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("ts").str().to_datetime(
+        Some(TimeUnit::Microseconds),
+        None,
+        StrptimeOptions::default(),
+        lit("raise"),
+    ));
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn time_zone(tz: &str) -> TimeZone {
+    TimeZone::opt_try_new(Some(tz)).unwrap().unwrap()
+}
+
+fn assert_sql_result(ctx: &mut SQLContext, sql: &str, expected: DataFrame) {
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_timestamptz_cast() {
+    let mut ctx = create_ctx().with_time_zone("Europe/Amsterdam").unwrap();
+    let df = ctx
+        .execute(
+            "SELECT ts::timestamptz AS tz, CAST(ts AS TIMESTAMP WITH TIME ZONE) AS tz2 FROM df",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected_dtype =
+        DataType::Datetime(TimeUnit::Microseconds, Some(time_zone("Europe/Amsterdam")));
+    assert_eq!(df.column("tz").unwrap().dtype(), &expected_dtype);
+    assert_eq!(df.column("tz2").unwrap().dtype(), &expected_dtype);
+
+    // naive values are local times in the session time zone (and round-trip)
+    assert_sql_result(
+        &mut ctx,
+        r#"
+        SELECT
+          STRFTIME(ts::timestamptz, '%Y-%m-%d %H:%M%z') AS tz,
+          ts::timestamptz::timestamp = ts AS roundtrip
+        FROM df ORDER BY id
+    "#,
+        df! {
+            "tz" => ["2024-01-01 12:00+0100", "2024-07-01 12:00+0200"],
+            "roundtrip" => [true, true],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_timestamptz_literal() {
+    let mut ctx = create_ctx();
+    assert_sql_result(
+        &mut ctx,
+        r#"
+        SELECT
+          STRFTIME(TIMESTAMPTZ '2024-01-01 10:30:00', '%H:%M%z') AS naive,
+          STRFTIME(TIMESTAMPTZ '2024-01-01 10:30:00+02:00', '%H:%M%z') AS with_offset
+    "#,
+        df! {
+            "naive" => ["10:30+0000"],
+            "with_offset" => ["08:30+0000"],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_at_time_zone() {
+    let mut ctx = create_ctx();
+    assert_sql_result(
+        &mut ctx,
+        r#"
+        SELECT
+          STRFTIME(ts AT TIME ZONE 'America/New_York', '%H:%M%z') AS from_local,
+          STRFTIME(ts::timestamptz AT TIME ZONE 'Asia/Tokyo', '%H:%M') AS to_local
+        FROM df ORDER BY id
+    "#,
+        df! {
+            "from_local" => ["17:00+0000", "16:00+0000"],
+            "to_local" => ["21:00", "21:00"],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_set_time_zone() {
+    let mut ctx = create_ctx();
+    for sql in ["SET TIME ZONE 'Asia/Tokyo'", "SET TIMEZONE = 'Asia/Tokyo'"] {
+        ctx.execute(sql).unwrap();
+        assert_sql_result(
+            &mut ctx,
+            "SELECT STRFTIME(TIMESTAMPTZ '2024-01-01 00:00:00+00:00', '%H:%M%z') AS ts",
+            df! { "ts" => ["09:00+0900"] }.unwrap(),
+        );
+        ctx.execute("SET TIME ZONE DEFAULT").unwrap();
+    }
+    assert!(ctx.execute("SET TIME ZONE 'Not/AZone'").is_err());
+    assert!(SQLContext::new().with_time_zone("Not/AZone").is_err());
+}
+
+#[test]
+fn test_current_timestamp() {
+    let mut ctx = create_ctx().with_time_zone("Europe/Amsterdam").unwrap();
+    let df = ctx
+        .execute(
+            r#"
+        SELECT
+          CURRENT_TIMESTAMP AS now,
+          LOCALTIMESTAMP AS local_now,
+          CURRENT_DATE AS today,
+          NOW() AS now2
+        FROM df
+    "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(
+        df.dtypes(),
+        vec![
+            DataType::Datetime(TimeUnit::Microseconds, Some(time_zone("Europe/Amsterdam"))),
+            DataType::Datetime(TimeUnit::Microseconds, None),
+            DataType::Date,
+            DataType::Datetime(TimeUnit::Microseconds, Some(time_zone("Europe/Amsterdam"))),
+        ]
+    );
+}
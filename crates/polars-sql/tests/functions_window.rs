@@ -0,0 +1,298 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let sales = df! {
+      "Year" => [2018, 2018, 2019, 2019, 2020, 2020],
+      "Country" => ["US", "UK", "US", "UK", "US", "UK"],
+      "Sales" => [1000, 2000, 3000, 4000, 5000, 6000]
+    }
+    .unwrap();
+    let events = df! {
+      "t" => [1, 2, 4, 5, 9],
+      "d" => ["2024-01-01", "2024-01-02", "2024-01-04", "2024-01-05", "2024-01-09"],
+      "v" => [1, 2, 3, 4, 5],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("d").cast(DataType::Date));
+
+    let readings = df! {
+      "k" => ["a", "a", "a", "a", "b", "b"],
+      "t" => [1, 2, 3, 4, 1, 2],
+      "v" => [Some(1), None, Some(3), None, None, Some(6)],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", sales.lazy());
+    ctx.register("events", events);
+    ctx.register("readings", readings.lazy());
+    ctx
+}
+
+fn assert_sql_result(sql: &str, expected: DataFrame) {
+    let mut ctx = create_ctx();
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_window_frame_rows_moving_average() {
+    assert_sql_result(
+        r#"
+        SELECT
+          Country,
+          AVG(Sales) OVER (
+            PARTITION BY Country ORDER BY Year ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+          ) AS ma
+        FROM df
+        ORDER BY Country, Year
+    "#,
+        df! {
+            "Country" => ["UK", "UK", "UK", "US", "US", "US"],
+            "ma" => [2000.0, 3000.0, 5000.0, 1000.0, 2000.0, 4000.0],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_window_frame_rows_centered() {
+    assert_sql_result(
+        r#"
+        SELECT
+          Sales,
+          CAST(SUM(Sales) OVER (
+            ORDER BY Sales ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+          ) AS DOUBLE) AS s
+        FROM df
+        ORDER BY Sales
+    "#,
+        df! {
+            "Sales" => [1000, 2000, 3000, 4000, 5000, 6000],
+            "s" => [3000.0, 6000.0, 9000.0, 12000.0, 15000.0, 11000.0],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_window_frame_rows_unbounded() {
+    assert_sql_result(
+        r#"
+        SELECT
+          Country,
+          MAX(Sales) OVER (
+            PARTITION BY Country ORDER BY Year
+            ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+          ) AS max_sales,
+          COUNT(*) OVER (
+            PARTITION BY Country ORDER BY Year
+            ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+          ) AS remaining
+        FROM df
+        ORDER BY Country, Year
+    "#,
+        df! {
+            "Country" => ["UK", "UK", "UK", "US", "US", "US"],
+            "max_sales" => [6000, 6000, 6000, 5000, 5000, 5000],
+            "remaining" => [3 as IdxSize, 2, 1, 3, 2, 1],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_window_frame_range() {
+    assert_sql_result(
+        r#"
+        SELECT
+          t,
+          CAST(SUM(v) OVER (
+            ORDER BY t RANGE BETWEEN 2 PRECEDING AND CURRENT ROW
+          ) AS DOUBLE) AS by_int,
+          CAST(SUM(v) OVER (
+            ORDER BY d RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW
+          ) AS DOUBLE) AS by_date
+        FROM events
+        ORDER BY t
+    "#,
+        df! {
+            "t" => [1, 2, 4, 5, 9],
+            "by_int" => [1.0, 3.0, 5.0, 7.0, 5.0],
+            "by_date" => [1.0, 3.0, 5.0, 7.0, 5.0],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_named_window() {
+    assert_sql_result(
+        r#"
+        SELECT
+          Country,
+          AVG(Sales) OVER w AS ma,
+          CAST(SUM(Sales) OVER (
+            w_ordered ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+          ) AS DOUBLE) AS running
+        FROM df
+        WINDOW
+          w_ordered AS (PARTITION BY Country ORDER BY Year),
+          w AS (w_ordered ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)
+        ORDER BY Country, Year
+    "#,
+        df! {
+            "Country" => ["UK", "UK", "UK", "US", "US", "US"],
+            "ma" => [2000.0, 3000.0, 5000.0, 1000.0, 2000.0, 4000.0],
+            "running" => [2000.0, 6000.0, 12000.0, 1000.0, 4000.0, 9000.0],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_undefined_named_window() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT SUM(Sales) OVER w FROM df";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_lag_lead() {
+    assert_sql_result(
+        r#"
+        SELECT
+          k,
+          t,
+          LAG(v) OVER (PARTITION BY k ORDER BY t) AS prev,
+          LEAD(v, 1, 0) OVER (PARTITION BY k ORDER BY t) AS next,
+          LAG(v, 2) OVER (ORDER BY k, t) AS prev2
+        FROM readings
+        ORDER BY k, t
+    "#,
+        df! {
+            "k" => ["a", "a", "a", "a", "b", "b"],
+            "t" => [1, 2, 3, 4, 1, 2],
+            "prev" => [None, Some(1), None, Some(3), None, None],
+            "next" => [None, Some(3), None, Some(0), Some(6), Some(0)],
+            "prev2" => [None, None, Some(1), None, Some(3), None],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_ignore_nulls() {
+    assert_sql_result(
+        r#"
+        SELECT
+          k,
+          t,
+          LAG(v) IGNORE NULLS OVER (PARTITION BY k ORDER BY t) AS prev,
+          LEAD(v) IGNORE NULLS OVER (PARTITION BY k ORDER BY t) AS next,
+          FIRST_VALUE(v) IGNORE NULLS OVER (PARTITION BY k ORDER BY t) AS first_v,
+          LAST_VALUE(v) IGNORE NULLS OVER (PARTITION BY k ORDER BY t) AS last_v,
+          LAST_VALUE(v) IGNORE NULLS OVER (
+            PARTITION BY k ORDER BY t
+            ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+          ) AS last_in_partition
+        FROM readings
+        ORDER BY k, t
+    "#,
+        df! {
+            "k" => ["a", "a", "a", "a", "b", "b"],
+            "t" => [1, 2, 3, 4, 1, 2],
+            "prev" => [None, Some(1), Some(1), Some(3), None, None],
+            "next" => [Some(3), Some(3), None, None, Some(6), None],
+            "first_v" => [Some(1), Some(1), Some(1), Some(1), None, Some(6)],
+            "last_v" => [Some(1), Some(1), Some(3), Some(3), None, Some(6)],
+            "last_in_partition" => [3, 3, 3, 3, 6, 6],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_ignore_nulls_unsupported_function() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT SUM(v) IGNORE NULLS OVER (ORDER BY t) FROM readings";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_ranking_functions() {
+    assert_sql_result(
+        r#"
+        SELECT
+          k,
+          t,
+          ROW_NUMBER() OVER (PARTITION BY k ORDER BY t DESC) AS rn,
+          RANK() OVER (ORDER BY v NULLS FIRST) AS rnk,
+          DENSE_RANK() OVER (ORDER BY v NULLS FIRST) AS dense_rnk
+        FROM readings
+        ORDER BY k, t
+    "#,
+        df! {
+            "k" => ["a", "a", "a", "a", "b", "b"],
+            "t" => [1, 2, 3, 4, 1, 2],
+            "rn" => [4 as IdxSize, 3, 2, 1, 2, 1],
+            "rnk" => [4 as IdxSize, 1, 5, 1, 1, 6],
+            "dense_rnk" => [2 as IdxSize, 1, 3, 1, 1, 4],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_qualify() {
+    assert_sql_result(
+        r#"
+        SELECT Country, Year, Sales
+        FROM df
+        QUALIFY ROW_NUMBER() OVER (PARTITION BY Country ORDER BY Sales DESC) = 1
+        ORDER BY Country
+    "#,
+        df! {
+            "Country" => ["UK", "US"],
+            "Year" => [2020, 2020],
+            "Sales" => [6000, 5000],
+        }
+        .unwrap(),
+    );
+    assert_sql_result(
+        r#"
+        SELECT Country, SUM(Sales) OVER (PARTITION BY Country) AS total
+        FROM df
+        QUALIFY total > 9000 AND Year = 2019
+    "#,
+        df! {
+            "Country" => ["UK"],
+            "total" => [12000],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_qualify_group_by() {
+    assert_sql_result(
+        r#"
+        SELECT Year, SUM(Sales) AS total
+        FROM df
+        GROUP BY Year
+        QUALIFY RANK() OVER (ORDER BY total DESC) <= 2
+        ORDER BY Year
+    "#,
+        df! {
+            "Year" => [2019, 2020],
+            "total" => [7000, 11000],
+        }
+        .unwrap(),
+    );
+}
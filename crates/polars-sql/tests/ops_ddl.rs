@@ -0,0 +1,186 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+        "id" => [1, 2, 3],
+        "name" => ["a", "b", "c"],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+fn assert_sql_result(ctx: &mut SQLContext, sql: &str, expected: DataFrame) {
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_create_table_with_columns() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE TABLE t (id INTEGER NOT NULL, price DECIMAL(10,2), ts TIMESTAMP)")
+        .unwrap();
+    let df = ctx.execute("SELECT * FROM t").unwrap().collect().unwrap();
+    assert_eq!(df.height(), 0);
+    assert_eq!(
+        df.dtypes(),
+        vec![
+            DataType::Int32,
+            DataType::Decimal(Some(10), Some(2)),
+            DataType::Datetime(TimeUnit::Microseconds, None),
+        ]
+    );
+
+    // inserted values are cast to the column types
+    ctx.execute("INSERT INTO t (id, price) VALUES (1, 2.5)")
+        .unwrap();
+    assert_sql_result(
+        &mut ctx,
+        "SELECT id FROM t",
+        df! { "id" => [1i32] }.unwrap(),
+    );
+    assert!(ctx.execute("INSERT INTO t (price) VALUES (1.0)").is_err());
+}
+
+#[test]
+fn test_not_null_constraint_dml() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE TABLE t (id INTEGER NOT NULL, v INTEGER)")
+        .unwrap();
+    ctx.execute("INSERT INTO t VALUES (1, 10), (2, 20)")
+        .unwrap();
+    for sql in [
+        "UPDATE t SET id = NULL WHERE v = 20",
+        "MERGE INTO t USING df s ON t.id = s.id WHEN MATCHED THEN UPDATE SET id = NULL",
+        "MERGE INTO t USING df s ON t.id = s.id WHEN NOT MATCHED THEN INSERT (v) VALUES (s.id)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected error for: {sql}");
+    }
+    // failed statements leave the table unchanged
+    assert_sql_result(
+        &mut ctx,
+        "SELECT * FROM t ORDER BY id",
+        df! { "id" => [1i32, 2], "v" => [10i32, 20] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_create_table_invalid() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "CREATE TABLE t (id INTEGER, id VARCHAR)",
+        "CREATE TABLE t (id INTEGER DEFAULT 0)",
+        "CREATE TABLE t (id NOT_A_TYPE)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected error for: {sql}");
+    }
+}
+
+#[test]
+fn test_create_view() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE VIEW v (key, label) AS SELECT id, UPPER(name) FROM df WHERE id > 1")
+        .unwrap();
+    assert_sql_result(
+        &mut ctx,
+        "SELECT * FROM v ORDER BY key",
+        df! {
+            "key" => [2, 3],
+            "label" => ["B", "C"],
+        }
+        .unwrap(),
+    );
+    assert!(ctx.execute("CREATE VIEW v AS SELECT 1").is_err());
+    assert!(ctx.execute("CREATE VIEW df AS SELECT 1").is_err());
+    ctx.execute("CREATE OR REPLACE VIEW v AS SELECT id FROM df WHERE id = 1")
+        .unwrap();
+    assert_sql_result(&mut ctx, "SELECT * FROM v", df! { "id" => [1] }.unwrap());
+
+    // views cannot be modified, or dropped as tables
+    assert!(ctx.execute("INSERT INTO v VALUES (4)").is_err());
+    assert!(ctx.execute("TRUNCATE v").is_err());
+    assert!(ctx.execute("DROP TABLE v").is_err());
+    assert!(ctx.execute("DROP VIEW df").is_err());
+    ctx.execute("DROP VIEW v").unwrap();
+    assert_eq!(ctx.get_tables(), vec!["df".to_string()]);
+}
+
+#[test]
+fn test_view_reflects_base_table_changes() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE VIEW v AS SELECT id FROM df WHERE id > 1")
+        .unwrap();
+    ctx.execute("CREATE VIEW w AS SELECT COUNT(*) AS n FROM v")
+        .unwrap();
+    ctx.execute("INSERT INTO df VALUES (4, 'd')").unwrap();
+    assert_sql_result(
+        &mut ctx,
+        "SELECT * FROM v ORDER BY id",
+        df! { "id" => [2, 3, 4] }.unwrap(),
+    );
+    assert_sql_result(
+        &mut ctx,
+        "SELECT * FROM w",
+        df! { "n" => [3 as IdxSize] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_information_schema() {
+    let mut ctx = create_ctx();
+    ctx.execute("CREATE TABLE t (x BIGINT NOT NULL, y VARCHAR)")
+        .unwrap();
+    ctx.execute("CREATE VIEW v AS SELECT id FROM df").unwrap();
+    assert_sql_result(
+        &mut ctx,
+        "SELECT table_name, table_type FROM information_schema.tables ORDER BY table_name",
+        df! {
+            "table_name" => ["df", "t", "v"],
+            "table_type" => ["BASE TABLE", "BASE TABLE", "VIEW"],
+        }
+        .unwrap(),
+    );
+    assert_sql_result(
+        &mut ctx,
+        r#"
+        SELECT c.column_name, c.ordinal_position, c.data_type, c.is_nullable
+        FROM information_schema.columns c
+        WHERE c.table_name = 't'
+        ORDER BY c.ordinal_position
+    "#,
+        df! {
+            "column_name" => ["x", "y"],
+            "ordinal_position" => [1, 2],
+            "data_type" => ["BIGINT", "VARCHAR"],
+            "is_nullable" => ["NO", "YES"],
+        }
+        .unwrap(),
+    );
+    assert!(
+        ctx.execute("SELECT * FROM information_schema.no_such_table")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_describe() {
+    let mut ctx = create_ctx();
+    assert_sql_result(
+        &mut ctx,
+        "DESCRIBE df",
+        df! {
+            "column_name" => ["id", "name"],
+            "data_type" => ["INTEGER", "VARCHAR"],
+            "is_nullable" => ["YES", "YES"],
+        }
+        .unwrap(),
+    );
+    assert!(ctx.execute("DESCRIBE no_such_table").is_err());
+}
@@ -0,0 +1,201 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let items = df! {
+        "id" => [1, 2, 3],
+        "name" => ["a", "b", "c"],
+        "qty" => [10, 20, 30],
+    }
+    .unwrap();
+    let updates = df! {
+        "id" => [2, 3, 4],
+        "name" => ["bb", "cc", "dd"],
+        "qty" => [0, 35, 40],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("items", items.lazy());
+    ctx.register("updates", updates.lazy());
+    ctx
+}
+
+fn assert_table_after(stmt: &str, expected: DataFrame) {
+    let mut ctx = create_ctx();
+    ctx.execute(stmt).unwrap();
+    let actual = ctx
+        .execute("SELECT * FROM items")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_insert_values() {
+    assert_table_after(
+        "INSERT INTO items VALUES (4, 'd', 40), (5, 'e', 50)",
+        df! {
+            "id" => [1, 2, 3, 4, 5],
+            "name" => ["a", "b", "c", "d", "e"],
+            "qty" => [10, 20, 30, 40, 50],
+        }
+        .unwrap(),
+    );
+    assert_table_after(
+        "INSERT INTO items (name, id) VALUES ('d', 4)",
+        df! {
+            "id" => [1, 2, 3, 4],
+            "name" => ["a", "b", "c", "d"],
+            "qty" => [Some(10), Some(20), Some(30), None],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_insert_select() {
+    assert_table_after(
+        "INSERT INTO items SELECT id, name, qty FROM updates WHERE id > 3",
+        df! {
+            "id" => [1, 2, 3, 4],
+            "name" => ["a", "b", "c", "dd"],
+            "qty" => [10, 20, 30, 40],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_insert_invalid() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "INSERT INTO items VALUES (4, 'd')",
+        "INSERT INTO items (id, colx) VALUES (4, 0)",
+        "INSERT INTO no_such_table VALUES (4, 'd', 40)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected error for: {sql}");
+    }
+}
+
+#[test]
+fn test_update() {
+    assert_table_after(
+        "UPDATE items SET qty = qty * 2, name = 'x' WHERE id >= 2",
+        df! {
+            "id" => [1, 2, 3],
+            "name" => ["a", "x", "x"],
+            "qty" => [10, 40, 60],
+        }
+        .unwrap(),
+    );
+    assert_table_after(
+        "UPDATE items SET qty = 0",
+        df! {
+            "id" => [1, 2, 3],
+            "name" => ["a", "b", "c"],
+            "qty" => [0, 0, 0],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_update_with_subquery() {
+    assert_table_after(
+        "UPDATE items SET qty = (SELECT MAX(qty) FROM updates) WHERE id IN (SELECT id FROM updates)",
+        df! {
+            "id" => [1, 2, 3],
+            "name" => ["a", "b", "c"],
+            "qty" => [10, 40, 40],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_merge() {
+    assert_table_after(
+        r#"
+        MERGE INTO items t
+        USING updates s ON t.id = s.id
+        WHEN MATCHED AND s.qty = 0 THEN DELETE
+        WHEN MATCHED THEN UPDATE SET qty = s.qty, name = s.name
+        WHEN NOT MATCHED THEN INSERT (id, name, qty) VALUES (s.id, s.name, s.qty)
+    "#,
+        df! {
+            "id" => [1, 3, 4],
+            "name" => ["a", "cc", "dd"],
+            "qty" => [10, 35, 40],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_merge_delete_keeps_untouched_rows() {
+    // Rows that no WHEN clause applies to are left as they are.
+    assert_table_after(
+        r#"
+        MERGE INTO items t
+        USING updates s ON t.id = s.id
+        WHEN MATCHED AND s.qty = 0 THEN DELETE
+    "#,
+        df! {
+            "id" => [1, 3],
+            "name" => ["a", "c"],
+            "qty" => [10, 30],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_merge_not_matched_by_source() {
+    assert_table_after(
+        r#"
+        MERGE INTO items t
+        USING (SELECT * FROM updates WHERE id < 4) s ON t.id = s.id
+        WHEN MATCHED THEN UPDATE SET qty = t.qty + s.qty
+        WHEN NOT MATCHED BY SOURCE THEN DELETE
+    "#,
+        df! {
+            "id" => [2, 3],
+            "name" => ["b", "c"],
+            "qty" => [20, 65],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_merge_multiple_matches() {
+    let mut ctx = create_ctx();
+    let dupes = df! {
+        "id" => [2, 2],
+        "name" => ["x", "y"],
+        "qty" => [1, 2],
+    }
+    .unwrap();
+    ctx.register("dupes", dupes.lazy());
+    let sql = r#"
+        MERGE INTO items t USING dupes s ON t.id = s.id
+        WHEN MATCHED AND s.qty > 100 THEN DELETE
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_merge_invalid() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        MERGE INTO items t USING updates s ON t.id = s.id
+        WHEN NOT MATCHED THEN DELETE
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
@@ -0,0 +1,126 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+        "region" => ["east", "east", "west", "west"],
+        "product" => ["a", "b", "a", "a"],
+        "sales" => [1i64, 2, 3, 4],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_group_by_rollup() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT region, product, SUM(sales) AS total, GROUPING(region, product) AS g
+        FROM df
+        GROUP BY ROLLUP(region, product)
+        ORDER BY g, region, product
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => [Some("east"), Some("east"), Some("west"), Some("east"), Some("west"), None],
+        "product" => [Some("a"), Some("b"), Some("a"), None, None, None],
+        "total" => [1i64, 2, 7, 3, 7, 10],
+        "g" => [0i32, 0, 0, 1, 1, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_group_by_cube() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT region, product, COUNT(*) AS n
+        FROM df
+        GROUP BY CUBE(region, product)
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    // (region, product): 3 groups, (region): 2, (product): 2, (): 1
+    assert_eq!(actual.height(), 8);
+    let n_total: u64 = actual
+        .column("n")
+        .unwrap()
+        .as_materialized_series()
+        .sum()
+        .unwrap();
+    // Every grouping set counts all rows.
+    assert_eq!(n_total, 16);
+}
+
+#[test]
+fn test_group_by_grouping_sets() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT region, SUM(sales) AS total
+        FROM df
+        GROUP BY GROUPING SETS ((region), ())
+        ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => [Some("east"), Some("west"), None],
+        "total" => [3i64, 7, 10],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // The MySQL-style modifier is equivalent.
+    let sql = r#"
+        SELECT region, SUM(sales) AS total
+        FROM df
+        GROUP BY region WITH ROLLUP
+        ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_grouping_requires_group_by_key() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT region, GROUPING(product) AS g, SUM(sales) AS total
+        FROM df
+        GROUP BY ROLLUP(region)
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_grouping_unaliased() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT region, product, GROUPING(region), GROUPING(product), GROUPING(region, product)
+        FROM df
+        GROUP BY CUBE(region, product)
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(
+        actual.get_column_names(),
+        &[
+            "region",
+            "product",
+            "grouping(region)",
+            "grouping(product)",
+            "grouping(region, product)",
+        ]
+    );
+}
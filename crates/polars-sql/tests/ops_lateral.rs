@@ -0,0 +1,252 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let customers = df! {
+        "id" => [1, 2, 3, 4],
+        "name" => ["a", "b", "c", "d"],
+    }
+    .unwrap();
+    let orders = df! {
+        "customer_id" => [1, 1, 2, 4, 4, 4],
+        "amount" => [10, 20, 5, 7, 8, 9],
+    }
+    .unwrap();
+    let tags = df! {
+        "id" => [1, 2, 3],
+        "tags" => [Some("x,y"), Some("z"), None],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("tags").str().split(lit(",")));
+    let events = df! {
+        "id" => [1, 2, 3],
+        "kinds" => [Some("a,b,c"), Some("d"), None],
+        "users" => ["u1", "u2,u3", "u4"],
+    }
+    .unwrap()
+    .lazy()
+    .with_columns([
+        col("kinds").str().split(lit(",")),
+        col("users").str().split(lit(",")),
+    ]);
+
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    ctx.register("tags", tags);
+    ctx.register("events", events);
+    ctx
+}
+
+fn assert_sql_result(sql: &str, expected: DataFrame) {
+    let mut ctx = create_ctx();
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_comma_join_with_where_keys() {
+    assert_sql_result(
+        r#"
+        SELECT c.name, o.amount
+        FROM customers c, orders o
+        WHERE o.customer_id = c.id AND o.amount > 8
+        ORDER BY o.amount
+    "#,
+        df! {
+            "name" => ["d", "a", "a"],
+            "amount" => [9, 10, 20],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_comma_cross_join() {
+    assert_sql_result(
+        "SELECT COUNT(*) AS n FROM customers, orders",
+        df! { "n" => [24 as IdxSize] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_lateral_unnest() {
+    assert_sql_result(
+        r#"
+        SELECT t.id, u.tag
+        FROM tags t, UNNEST(t.tags) AS u(tag)
+        ORDER BY t.id, u.tag
+    "#,
+        df! {
+            "id" => [1, 1, 2],
+            "tag" => ["x", "y", "z"],
+        }
+        .unwrap(),
+    );
+    assert_sql_result(
+        r#"
+        SELECT t.id, u.tag
+        FROM tags t LEFT JOIN LATERAL UNNEST(t.tags) AS u(tag) ON TRUE
+        ORDER BY t.id, u.tag
+    "#,
+        df! {
+            "id" => [1, 1, 2, 3],
+            "tag" => [Some("x"), Some("y"), Some("z"), None],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_lateral_unnest_with_ordinality() {
+    assert_sql_result(
+        r#"
+        SELECT e.id, u.kind, u.n
+        FROM events e, UNNEST(e.kinds) WITH ORDINALITY AS u(kind, n)
+        ORDER BY e.id, u.n
+    "#,
+        df! {
+            "id" => [1, 1, 1, 2],
+            "kind" => ["a", "b", "c", "d"],
+            "n" => [1i64, 2, 3, 1],
+        }
+        .unwrap(),
+    );
+    assert_sql_result(
+        r#"
+        SELECT e.id, u.ordinality
+        FROM events e LEFT JOIN LATERAL UNNEST(e.kinds) WITH ORDINALITY AS u(kind) ON TRUE
+        WHERE e.id > 1
+    "#,
+        df! {
+            "id" => [2, 3],
+            "ordinality" => [Some(1i64), None],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_lateral_unnest_zip() {
+    // arrays of different lengths are padded with NULLs (per row)
+    assert_sql_result(
+        r#"
+        SELECT e.id, u.kind, u.usr
+        FROM events e, UNNEST(e.kinds, e.users) AS u(kind, usr)
+        ORDER BY e.id
+    "#,
+        df! {
+            "id" => [1, 1, 1, 2, 2, 3],
+            "kind" => [Some("a"), Some("b"), Some("c"), Some("d"), None, None],
+            "usr" => [Some("u1"), None, None, Some("u2"), Some("u3"), Some("u4")],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_select_unnest_lateral() {
+    assert_sql_result(
+        "SELECT id, UNNEST(kinds) AS kind FROM events ORDER BY id",
+        df! {
+            "id" => [1, 1, 1, 2],
+            "kind" => ["a", "b", "c", "d"],
+        }
+        .unwrap(),
+    );
+    assert_sql_result(
+        r#"
+        SELECT UNNEST(kinds) AS kind, UNNEST(users) AS usr, id * 10 AS id10
+        FROM events WHERE id < 3
+    "#,
+        df! {
+            "kind" => [Some("a"), Some("b"), Some("c"), Some("d"), None],
+            "usr" => [Some("u1"), None, None, Some("u2"), Some("u3")],
+            "id10" => [10, 10, 10, 20, 20],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_unnest_literal_arrays() {
+    assert_sql_result(
+        "SELECT * FROM UNNEST(['p', 'q', 'r'], ['x']) WITH ORDINALITY AS t(a, b, n)",
+        df! {
+            "a" => ["p", "q", "r"],
+            "b" => [Some("x"), None, None],
+            "n" => [1i64, 2, 3],
+        }
+        .unwrap(),
+    );
+    assert_sql_result(
+        "SELECT * FROM UNNEST(['p', 'q']) AS t(a) WITH OFFSET AS pos",
+        df! {
+            "a" => ["p", "q"],
+            "pos" => [0i64, 1],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_lateral_top_n_per_group() {
+    assert_sql_result(
+        r#"
+        SELECT c.name, o.amount
+        FROM customers c, LATERAL (
+          SELECT amount FROM orders
+          WHERE orders.customer_id = c.id
+          ORDER BY amount DESC
+          LIMIT 2
+        ) o
+        ORDER BY c.name, o.amount DESC
+    "#,
+        df! {
+            "name" => ["a", "a", "b", "d", "d"],
+            "amount" => [20, 10, 5, 9, 8],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_left_join_lateral_aggregate() {
+    assert_sql_result(
+        r#"
+        SELECT c.name, o.total
+        FROM customers c
+        LEFT JOIN LATERAL (
+          SELECT SUM(amount) AS total FROM orders WHERE orders.customer_id = c.id
+        ) o ON TRUE
+        ORDER BY c.name
+    "#,
+        df! {
+            "name" => ["a", "b", "c", "d"],
+            "total" => [Some(30), Some(5), None, Some(24)],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_lateral_unsupported_join_type() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT * FROM customers c
+        RIGHT JOIN LATERAL (SELECT * FROM orders WHERE orders.customer_id = c.id) o ON TRUE
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_lateral_unnest_no_arguments() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT * FROM events e, LATERAL UNNEST()";
+    assert!(ctx.execute(sql).is_err());
+}
@@ -0,0 +1,114 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let sales = df! {
+        "region" => ["n", "n", "s", "n", "s"],
+        "quarter" => ["q1", "q2", "q1", "q1", "q3"],
+        "amount" => [10, 20, 30, 40, 50],
+    }
+    .unwrap();
+    let quarterly = df! {
+        "region" => ["n", "s"],
+        "q1" => [Some(50), Some(30)],
+        "q2" => [Some(20), None],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales.lazy());
+    ctx.register("quarterly", quarterly.lazy());
+    ctx
+}
+
+fn assert_sql_result(sql: &str, expected: DataFrame) {
+    let mut ctx = create_ctx();
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_pivot() {
+    assert_sql_result(
+        r#"
+        SELECT * FROM sales
+        PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2'))
+        ORDER BY region
+    "#,
+        df! {
+            "region" => ["n", "s"],
+            "q1" => [Some(50), Some(30)],
+            "q2" => [Some(20), None],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_pivot_aliases_and_default() {
+    assert_sql_result(
+        r#"
+        SELECT * FROM sales
+        PIVOT (
+          SUM(amount) AS total, COUNT(*) AS n
+          FOR quarter IN ('q1' AS first, 'q3' AS third)
+          DEFAULT ON NULL (0)
+        ) AS p
+        ORDER BY p.region
+    "#,
+        df! {
+            "region" => ["n", "s"],
+            "first_total" => [50, 30],
+            "first_n" => [2 as IdxSize, 1],
+            "third_total" => [0, 50],
+            "third_n" => [0 as IdxSize, 1],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_pivot_dynamic_values_unsupported() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (ANY))";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_unpivot() {
+    assert_sql_result(
+        r#"
+        SELECT * FROM quarterly
+        UNPIVOT (amount FOR quarter IN (q1, q2))
+        ORDER BY region, quarter
+    "#,
+        df! {
+            "region" => ["n", "n", "s"],
+            "quarter" => ["q1", "q2", "q1"],
+            "amount" => [50, 20, 30],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_unpivot_pivot_roundtrip() {
+    assert_sql_result(
+        r#"
+        SELECT * FROM quarterly
+        UNPIVOT (amount FOR quarter IN (q1, q2)) AS u
+        PIVOT (MAX(amount) FOR quarter IN ('q1', 'q2'))
+        ORDER BY region
+    "#,
+        df! {
+            "region" => ["n", "s"],
+            "q1" => [Some(50), Some(30)],
+            "q2" => [Some(20), None],
+        }
+        .unwrap(),
+    );
+}
@@ -0,0 +1,207 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let customers = df! {
+        "id" => [1, 2, 3, 4],
+        "name" => ["a", "b", "c", "d"],
+    }
+    .unwrap();
+    let orders = df! {
+        "customer_id" => [1, 1, 2, 4, 4, 4],
+        "amount" => [10, 20, 5, 7, 8, 9],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    ctx
+}
+
+fn assert_sql_result(sql: &str, expected: DataFrame) {
+    let mut ctx = create_ctx();
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_exists_correlated() {
+    assert_sql_result(
+        r#"
+        SELECT name FROM customers c
+        WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id)
+        ORDER BY name
+    "#,
+        df! { "name" => ["a", "b", "d"] }.unwrap(),
+    );
+    assert_sql_result(
+        r#"
+        SELECT name FROM customers c
+        WHERE NOT EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 5)
+        ORDER BY name
+    "#,
+        df! { "name" => ["b", "c"] }.unwrap(),
+    );
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_exists_uncorrelated() {
+    assert_sql_result(
+        r#"
+        SELECT name FROM customers
+        WHERE id > 2 AND NOT EXISTS (SELECT 1 FROM orders WHERE amount > 100)
+        ORDER BY name
+    "#,
+        df! { "name" => ["c", "d"] }.unwrap(),
+    );
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_in_correlated() {
+    assert_sql_result(
+        r#"
+        SELECT name FROM customers c
+        WHERE id IN (SELECT o.customer_id FROM orders o WHERE o.amount = c.id * 10)
+    "#,
+        df! { "name" => ["a"] }.unwrap(),
+    );
+    assert_sql_result(
+        r#"
+        SELECT name FROM customers c
+        WHERE id NOT IN (SELECT o.customer_id FROM orders o WHERE o.amount = c.id * 10)
+        ORDER BY name
+    "#,
+        df! { "name" => ["b", "c", "d"] }.unwrap(),
+    );
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_not_in_correlated_nulls() {
+    let outer = df! {
+        "k" => [1, 2, 3, 4],
+        "v" => [Some(1), None, Some(3), None],
+    }
+    .unwrap();
+    let inner = df! {
+        "k" => [1, 1, 2, 3, 3],
+        "v" => [Some(2), None, Some(9), Some(7), Some(8)],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("t", outer.lazy());
+    ctx.register("s", inner.lazy());
+
+    // A NULL in the subquery (k = 1) or a NULL outer value with a non-empty subquery (k = 2)
+    // makes NOT IN unknown, whereas NOT IN an empty subquery holds even for NULL (k = 4).
+    let actual = ctx
+        .execute(
+            r#"
+            SELECT k FROM t
+            WHERE v NOT IN (SELECT s.v FROM s WHERE s.k = t.k)
+            ORDER BY k
+        "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "k" => [3, 4] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_scalar_subquery_correlated() {
+    assert_sql_result(
+        r#"
+        SELECT * FROM customers c
+        WHERE (SELECT SUM(o.amount) FROM orders o WHERE o.customer_id = c.id) > 10
+        ORDER BY name
+    "#,
+        df! {
+            "id" => [1, 4],
+            "name" => ["a", "d"],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_scalar_subquery_count() {
+    assert_sql_result(
+        r#"
+        SELECT
+          name,
+          (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) AS n_orders
+        FROM customers c
+        ORDER BY name
+    "#,
+        df! {
+            "name" => ["a", "b", "c", "d"],
+            "n_orders" => [2 as IdxSize, 1, 0, 3],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_scalar_subquery_uncorrelated() {
+    assert_sql_result(
+        r#"
+        SELECT customer_id, amount FROM orders
+        WHERE amount > (SELECT AVG(amount) FROM orders)
+        ORDER BY amount
+    "#,
+        df! {
+            "customer_id" => [1, 1],
+            "amount" => [10, 20],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_scalar_subquery_nested_scopes() {
+    // The same subquery in the outer and in the nested SELECT is joined to both frames.
+    assert_sql_result(
+        r#"
+        SELECT name FROM customers
+        WHERE id < (SELECT MAX(customer_id) FROM orders)
+          AND id IN (
+            SELECT customer_id FROM orders
+            WHERE amount > (SELECT MAX(customer_id) FROM orders)
+          )
+    "#,
+        df! { "name" => ["a", "b"] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_scalar_subquery_multiple_rows() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT name FROM customers
+        WHERE id = (SELECT customer_id FROM orders)
+    "#;
+    assert!(ctx.execute(sql).and_then(|lf| lf.collect()).is_err());
+}
+
+#[test]
+fn test_correlated_subquery_non_equality() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+        SELECT name FROM customers c
+        WHERE (SELECT MAX(o.amount) FROM orders o WHERE o.customer_id > c.id) > 10
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
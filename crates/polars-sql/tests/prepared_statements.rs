@@ -0,0 +1,114 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+        "id" => [1, 2, 3, 4],
+        "name" => ["a", "b", "c", "d"],
+        "value" => [1.5, 2.5, 3.5, 4.5],
+    }
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+    ctx
+}
+
+fn assert_prepared_result(sql: &str, params: SQLParams, expected: DataFrame) {
+    let mut ctx = create_ctx();
+    let stmt = ctx.prepare(sql).unwrap();
+    let actual = ctx
+        .execute_prepared(&stmt, &params)
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_positional_params() {
+    assert_prepared_result(
+        "SELECT id FROM df WHERE id > $1 AND value < $2 ORDER BY id",
+        SQLParams::new().bind(1).bind(4.0),
+        df! { "id" => [2, 3] }.unwrap(),
+    );
+    // anonymous placeholders are numbered from left to right
+    assert_prepared_result(
+        "SELECT id, ? AS tag FROM df WHERE name IN (?, ?) ORDER BY id",
+        SQLParams::new()
+            .bind(PlSmallStr::from_static("x"))
+            .bind(PlSmallStr::from_static("b"))
+            .bind(PlSmallStr::from_static("d")),
+        df! {
+            "id" => [2, 4],
+            "tag" => ["x", "x"],
+        }
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_named_params() {
+    assert_prepared_result(
+        "SELECT name FROM df WHERE id >= :min_id AND id <= :max_id ORDER BY name DESC",
+        SQLParams::new()
+            .bind_named("max_id", 3)
+            .bind_named("min_id", 2),
+        df! { "name" => ["c", "b"] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_params_are_not_sql() {
+    assert_prepared_result(
+        "SELECT id FROM df WHERE name = $1",
+        SQLParams::new().bind(PlSmallStr::from_static("a' OR '1' = '1")),
+        DataFrame::empty_with_schema(&Schema::from_iter([Field::new(
+            "id".into(),
+            DataType::Int32,
+        )])),
+    );
+}
+
+#[test]
+fn test_params_limit_offset() {
+    assert_prepared_result(
+        "SELECT id FROM df ORDER BY id LIMIT $1 OFFSET $2",
+        SQLParams::new().bind(2).bind(1),
+        df! { "id" => [2, 3] }.unwrap(),
+    );
+}
+
+#[test]
+fn test_prepared_statement_reuse() {
+    let mut ctx = create_ctx();
+    let stmt = ctx.prepare("SELECT name FROM df WHERE id = $1").unwrap();
+    for (id, name) in [(1, "a"), (3, "c")] {
+        let actual = ctx
+            .execute_prepared(&stmt, &SQLParams::new().bind(id))
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(actual.equals(&df! { "name" => [name] }.unwrap()));
+    }
+}
+
+#[test]
+fn test_params_missing() {
+    let mut ctx = create_ctx();
+    let stmt = ctx.prepare("SELECT * FROM df WHERE id = $2").unwrap();
+    assert!(
+        ctx.execute_prepared(&stmt, &SQLParams::new().bind(1))
+            .is_err()
+    );
+    assert!(ctx.execute("SELECT * FROM df WHERE id = ?").is_err());
+    let stmt = ctx.prepare("SELECT * FROM df LIMIT $1").unwrap();
+    assert!(
+        ctx.execute_prepared(&stmt, &SQLParams::new().bind(1.5))
+            .is_err()
+    );
+}
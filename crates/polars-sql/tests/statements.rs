@@ -601,3 +601,224 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+#[test]
+fn test_join_on_unknown_column() {
+    let mut ctx = prepare_compound_join_context();
+    let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.missing";
+    let err = ctx.execute(sql).unwrap_err();
+    assert!(err.to_string().contains("missing"), "{err}");
+}
+
+#[test]
+fn test_join_on_same_side_equality() {
+    // `df1.a = df1.b` compares columns of the same table, so it is not a join key
+    let mut ctx = prepare_compound_join_context();
+    let sql = r#"
+        SELECT df1.a, df2.a AS a2 FROM df1
+        INNER JOIN df2 ON df1.a = df1.b AND df1.a = df2.a
+        ORDER BY df1.a
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "a" => [1, 4, 5],
+        "a2" => [1, 4, 5],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_join_on_inequality() {
+    let mut ctx = prepare_compound_join_context();
+    let sql = r#"
+        SELECT df1.a, df2.a AS a2 FROM df1
+        INNER JOIN df2 ON df1.a = df2.a - 1 AND df1.b < df2.b
+        ORDER BY df1.a
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "a" => [1, 2, 3, 4],
+        "a2" => [2, 3, 4, 5],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_join_on_between() {
+    let mut ctx = prepare_compound_join_context();
+    let sql = r#"
+        SELECT df1.a, df2.a AS a2 FROM df1
+        JOIN df2 ON df2.b BETWEEN df1.b AND df1.b + 1
+        ORDER BY df1.a, a2
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "a" => [2, 2, 3, 3, 4, 4, 5, 5],
+        "a2" => [2, 3, 3, 4, 3, 4, 4, 5],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_join_on_or() {
+    let mut ctx = prepare_compound_join_context();
+    let sql = r#"
+        SELECT df1.a, df2.a AS a2 FROM df1
+        INNER JOIN df2 ON df1.a = df2.a OR df1.b = df2.b
+        ORDER BY df1.a, a2
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "a" => [1, 2, 3, 4, 4, 5, 5],
+        "a2" => [1, 2, 3, 3, 4, 4, 5],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_left_join_on_inequality() {
+    let mut ctx = prepare_compound_join_context();
+    let sql = r#"
+        SELECT * FROM df1
+        LEFT JOIN df2 ON df1.a = df2.a AND df1.b > df2.b
+        ORDER BY df1.a
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [1, 3, 4, 4, 5],
+        "a:df2" => [Some(1), None, None, None, None],
+        "b:df2" => [Some(0), None, None, None, None],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_full_join_on_inequality() {
+    let mut ctx = prepare_compound_join_context();
+    let sql = r#"
+        SELECT df1.a, df2.a AS a2 FROM df1
+        FULL JOIN df2 ON df1.a = df2.a AND df1.b > df2.b
+        ORDER BY df1.a NULLS LAST, a2
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "a" => [Some(1), Some(2), Some(3), Some(4), Some(5), None, None, None, None],
+        "a2" => [Some(1), None, None, None, None, Some(2), Some(3), Some(4), Some(5)],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_join_on_ambiguous_column() {
+    let mut ctx = prepare_compound_join_context();
+    let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a < df2.a AND b > 1";
+    let _ = ctx.execute(sql).unwrap();
+}
+
+#[test]
+fn test_recursive_cte_union_all() {
+    let employees = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "name" => ["ceo", "cto", "cfo", "dev", "intern"],
+        "manager_id" => [None, Some(1), Some(1), Some(2), Some(4)],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees.lazy());
+
+    let sql = r#"
+        WITH RECURSIVE chain(id, name, depth) AS (
+            SELECT id, name, 0 FROM employees WHERE manager_id IS NULL
+            UNION ALL
+            SELECT e.id, e.name, c.depth + 1
+            FROM employees e
+            INNER JOIN chain c ON e.manager_id = c.id
+        )
+        SELECT * FROM chain ORDER BY id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "name" => ["ceo", "cto", "cfo", "dev", "intern"],
+        "depth" => [0, 1, 1, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_union_distinct() {
+    // The graph contains a cycle (1 -> 2 -> 3 -> 1); UNION stops once no new rows are found.
+    let edges = df! {
+        "src" => [1, 2, 3, 3],
+        "dst" => [2, 3, 1, 4],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("edges", edges.lazy());
+
+    let sql = r#"
+        WITH RECURSIVE reachable(node) AS (
+            SELECT DISTINCT src FROM edges WHERE src = 1
+            UNION
+            SELECT edges.dst FROM edges INNER JOIN reachable ON edges.src = reachable.node
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "node" => [1, 2, 3, 4] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // With UNION ALL the cycle never terminates.
+    let mut ctx = ctx.with_recursive_cte_max_iterations(10);
+    let sql = sql.replace("UNION", "UNION ALL");
+    let err = ctx.execute(&sql).err().unwrap();
+    assert!(
+        err.to_string()
+            .contains("did not terminate after 10 iterations")
+    );
+}
@@ -1,17 +1,25 @@
 use polars_core::prelude::*;
-use polars_lazy::prelude::IntoLazy;
+use polars_lazy::prelude::{IntoLazy, lit};
 use polars_plan::prelude::{GetOutput, UserDefinedFunction};
 use polars_sql::SQLContext;
-use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::function_registry::{
+    FunctionRegistry, UserDefinedAggregateFunction, UserDefinedTableFunction,
+};
 
+#[derive(Default)]
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
+    aggregates: PlHashMap<String, UserDefinedAggregateFunction>,
+    table_functions: PlHashMap<String, UserDefinedTableFunction>,
 }
 
 impl MyFunctionRegistry {
     fn new(funcs: Vec<UserDefinedFunction>) -> Self {
         let functions = funcs.into_iter().map(|f| (f.name.to_string(), f)).collect();
-        MyFunctionRegistry { functions }
+        MyFunctionRegistry {
+            functions,
+            ..Default::default()
+        }
     }
 }
 
@@ -28,6 +36,32 @@ impl FunctionRegistry for MyFunctionRegistry {
     fn contains(&self, name: &str) -> bool {
         self.functions.contains_key(name)
     }
+
+    fn register_aggregate(
+        &mut self,
+        name: &str,
+        fun: UserDefinedAggregateFunction,
+    ) -> PolarsResult<()> {
+        self.aggregates.insert(name.to_string(), fun);
+        Ok(())
+    }
+
+    fn get_udaf(&self, name: &str) -> PolarsResult<Option<UserDefinedAggregateFunction>> {
+        Ok(self.aggregates.get(name).cloned())
+    }
+
+    fn register_table_function(
+        &mut self,
+        name: &str,
+        fun: UserDefinedTableFunction,
+    ) -> PolarsResult<()> {
+        self.table_functions.insert(name.to_string(), fun);
+        Ok(())
+    }
+
+    fn get_table_function(&self, name: &str) -> PolarsResult<Option<UserDefinedTableFunction>> {
+        Ok(self.table_functions.get(name).cloned())
+    }
 }
 
 #[test]
@@ -108,3 +142,103 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+fn create_udaf_ctx() -> PolarsResult<SQLContext> {
+    let weighted_avg = UserDefinedAggregateFunction::new("weighted_avg".into(), |args| {
+        let [value, weight] = <[_; 2]>::try_from(args)
+            .map_err(|_| polars_err!(SQLSyntax: "WEIGHTED_AVG expects 2 arguments"))?;
+        Ok((value * weight.clone()).sum() / weight.sum())
+    });
+    let mut ctx = SQLContext::new().with_function_registry(Arc::new(MyFunctionRegistry::default()));
+    ctx.registry_mut()
+        .register_aggregate("weighted_avg", weighted_avg)?;
+
+    let df = df! {
+        "k" => ["a", "a", "b", "b"],
+        "v" => [1.0, 3.0, 2.0, 4.0],
+        "w" => [3.0, 1.0, 1.0, 1.0],
+    }?;
+    ctx.register("df", df.lazy());
+    Ok(ctx)
+}
+
+#[test]
+fn test_udafs() -> PolarsResult<()> {
+    let mut ctx = create_udaf_ctx()?;
+
+    let res = ctx
+        .execute("SELECT k, WEIGHTED_AVG(v, w) AS wavg FROM df GROUP BY k ORDER BY k")?
+        .collect()?;
+    let expected = df! {
+        "k" => ["a", "b"],
+        "wavg" => [1.5, 3.0],
+    }?;
+    assert!(expected.equals_missing(&res));
+
+    let res = ctx
+        .execute("SELECT WEIGHTED_AVG(v, w) OVER (PARTITION BY k) AS wavg FROM df")?
+        .collect()?;
+    let expected = df! { "wavg" => [1.5, 1.5, 3.0, 3.0] }?;
+    assert!(expected.equals_missing(&res));
+
+    let res = ctx
+        .execute("SELECT weighted_avg(v, w) AS wavg FROM df")?
+        .collect()?;
+    let expected = df! { "wavg" => [2.0] }?;
+    assert!(expected.equals_missing(&res));
+
+    // the aggregate function validates its arguments
+    assert!(ctx.execute("SELECT WEIGHTED_AVG(v) FROM df").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_udaf_must_aggregate() -> PolarsResult<()> {
+    let mut ctx = create_udaf_ctx()?;
+    let not_an_aggregate =
+        UserDefinedAggregateFunction::new("double".into(), |args| Ok(args[0].clone() * lit(2)));
+    ctx.registry_mut()
+        .register_aggregate("double", not_an_aggregate)?;
+    assert!(ctx.execute("SELECT double(v) FROM df").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_table_functions() -> PolarsResult<()> {
+    let my_source = UserDefinedTableFunction::new("my_source".into(), |args| {
+        let (Some(tag), Some(n)) = (
+            args.first().and_then(|a| a.value().get_str()),
+            args.get(1).and_then(|a| a.value().extract::<i32>()),
+        ) else {
+            polars_bail!(SQLSyntax: "MY_SOURCE expects a string and an integer argument")
+        };
+        Ok(df! {
+            "id" => (1..=n).collect::<Vec<_>>(),
+            "tag" => vec![tag; n as usize],
+        }?
+        .lazy())
+    });
+    let mut ctx = SQLContext::new().with_function_registry(Arc::new(MyFunctionRegistry::default()));
+    ctx.registry_mut()
+        .register_table_function("my_source", my_source)?;
+
+    let res = ctx
+        .execute("SELECT * FROM my_source('x', 3) WHERE id > 1")?
+        .collect()?;
+    let expected = df! {
+        "id" => [2, 3],
+        "tag" => ["x", "x"],
+    }?;
+    assert!(expected.equals_missing(&res));
+
+    let res = ctx
+        .execute("SELECT s.id FROM my_source('y', 2) AS s ORDER BY s.id DESC")?
+        .collect()?;
+    let expected = df! { "id" => [2, 1] }?;
+    assert!(expected.equals_missing(&res));
+
+    assert!(ctx.execute("SELECT * FROM my_source(3)").is_err());
+    assert!(ctx.execute("SELECT * FROM my_source(col_x, 3)").is_err());
+    assert!(ctx.execute("SELECT * FROM no_such_source(1)").is_err());
+    Ok(())
+}
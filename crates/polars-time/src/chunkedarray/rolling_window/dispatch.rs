@@ -1,3 +1,7 @@
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::_decimal_mean;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::ops::arity::try_binary_elementwise;
 use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
 use polars_ops::series::SeriesMethods;
 
@@ -153,6 +157,56 @@ where
     Series::try_from((ca.name().clone(), out))
 }
 
+/// Apply `rolling_sum` to the physical values of the decimal series `s`, raising an error
+/// instead of wrapping if a window sum does not fit.
+///
+/// The values are split into their upper and lower 64 bits, whose window sums can't overflow.
+/// Recombining them shows whether the exact window sum fits.
+#[cfg(feature = "dtype-decimal")]
+fn checked_rolling_decimal_sum(
+    s: &Series,
+    rolling_sum: impl Fn(Series) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    const LOW_BITS: i128 = u64::MAX as i128;
+
+    let physical = s.to_physical_repr();
+    let physical = physical.i128()?;
+    let high = rolling_sum(physical.apply_values(|v| v >> 64).into_series())?;
+    let low = rolling_sum(physical.apply_values(|v| v & LOW_BITS).into_series())?;
+    let out: Int128Chunked =
+        try_binary_elementwise(high.i128()?, low.i128()?, |high, low| match (high, low) {
+            (Some(high), Some(low)) => (high + (low >> 64))
+                .checked_mul(1 << 64)
+                .and_then(|high| high.checked_add(low & LOW_BITS))
+                .map(Some)
+                .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in rolling sum")),
+            _ => Ok(None),
+        })?;
+    // SAFETY: the physical type of a decimal is Int128.
+    unsafe { out.into_series().from_physical_unchecked(s.dtype()) }
+}
+
+/// Divide rolling decimal sums by the rolling count of valid values.
+#[cfg(feature = "dtype-decimal")]
+fn rolling_decimal_mean(sum: &Series, count: &Series) -> PolarsResult<Series> {
+    let sum = sum.decimal()?;
+    let count = count.cast(&DataType::UInt64)?;
+    let scale = sum.scale();
+    let out: Int128Chunked =
+        try_binary_elementwise(sum.physical(), count.u64()?, |s, c| match (s, c) {
+            (Some(s), Some(c)) => _decimal_mean(s, c as usize, scale)
+                .map(Some)
+                .ok_or_else(|| polars_err!(ComputeError: "decimal overflow in rolling mean")),
+            _ => Ok(None),
+        })?;
+    let DataType::Decimal(precision, scale) = sum.mean_dtype() else {
+        unreachable!()
+    };
+    Ok(out
+        .into_decimal_unchecked(precision, scale.unwrap())
+        .into_series())
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
@@ -161,6 +215,14 @@ pub trait SeriesOpsTime: AsSeries {
         by: &Series,
         options: RollingOptionsDynamicWindow,
     ) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if self.as_series().dtype().is_decimal() {
+            let s = self.as_series();
+            let sum = s.rolling_sum_by(by, options.clone())?;
+            let count = s.is_not_null().into_series().rolling_sum_by(by, options)?;
+            return rolling_decimal_mean(&sum, &count);
+        }
+
         let s = self.as_series().to_float()?;
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -177,6 +239,17 @@ pub trait SeriesOpsTime: AsSeries {
     /// See: [`RollingAgg::rolling_mean`]
     #[cfg(feature = "rolling_window")]
     fn rolling_mean(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.as_series().decimal() {
+            let s = self.as_series();
+            if options.weights.is_some() {
+                return s.to_float()?.rolling_mean(options)?.cast(&ca.mean_dtype());
+            }
+            let sum = s.rolling_sum(options.clone())?;
+            let count = s.is_not_null().into_series().rolling_sum(options)?;
+            return rolling_decimal_mean(&sum, &count);
+        }
+
         let s = self.as_series().to_float()?;
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -206,6 +279,11 @@ pub trait SeriesOpsTime: AsSeries {
             s = s.cast(&DataType::Int64).unwrap();
         }
 
+        #[cfg(feature = "dtype-decimal")]
+        if s.dtype().is_decimal() {
+            return checked_rolling_decimal_sum(&s, |s| s.rolling_sum_by(by, options.clone()));
+        }
+
         polars_ensure!(
             s.dtype().is_primitive_numeric() && !s.dtype().is_unknown(),
             op = "rolling_sum_by",
@@ -238,6 +316,11 @@ pub trait SeriesOpsTime: AsSeries {
             s = s.cast(&DataType::Int64).unwrap();
         }
 
+        #[cfg(feature = "dtype-decimal")]
+        if s.dtype().is_decimal() {
+            return checked_rolling_decimal_sum(&s, |s| s.rolling_sum(options.clone()));
+        }
+
         polars_ensure!(
             s.dtype().is_primitive_numeric() && !s.dtype().is_unknown(),
             op = "rolling_sum",
@@ -310,6 +393,12 @@ pub trait SeriesOpsTime: AsSeries {
             dt if dt.is_temporal() => {
                 return s.to_physical_repr().rolling_min_by(by, options)?.cast(dt);
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let out = s.to_physical_repr().rolling_min_by(by, options)?;
+                // SAFETY: the physical type of a decimal is Int128.
+                return unsafe { out.from_physical_unchecked(dt) };
+            },
             dt => {
                 polars_ensure!(
                     dt.is_primitive_numeric() && !dt.is_unknown(),
@@ -350,6 +439,12 @@ pub trait SeriesOpsTime: AsSeries {
             dt if dt.is_temporal() => {
                 return s.to_physical_repr().rolling_min(options)?.cast(dt);
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let out = s.to_physical_repr().rolling_min(options)?;
+                // SAFETY: the physical type of a decimal is Int128.
+                return unsafe { out.from_physical_unchecked(dt) };
+            },
             dt => {
                 polars_ensure!(
                     dt.is_primitive_numeric() && !dt.is_unknown(),
@@ -391,6 +486,12 @@ pub trait SeriesOpsTime: AsSeries {
             dt if dt.is_temporal() => {
                 return s.to_physical_repr().rolling_max_by(by, options)?.cast(dt);
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let out = s.to_physical_repr().rolling_max_by(by, options)?;
+                // SAFETY: the physical type of a decimal is Int128.
+                return unsafe { out.from_physical_unchecked(dt) };
+            },
             dt => {
                 polars_ensure!(
                     dt.is_primitive_numeric() && !dt.is_unknown(),
@@ -431,6 +532,12 @@ pub trait SeriesOpsTime: AsSeries {
             dt if dt.is_temporal() => {
                 return s.to_physical_repr().rolling_max(options)?.cast(dt);
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let out = s.to_physical_repr().rolling_max(options)?;
+                // SAFETY: the physical type of a decimal is Int128.
+                return unsafe { out.from_physical_unchecked(dt) };
+            },
             dt => {
                 polars_ensure!(
                     dt.is_primitive_numeric() && !dt.is_unknown(),
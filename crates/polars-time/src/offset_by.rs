@@ -58,9 +58,77 @@ fn apply_offsets_to_datetime(
     }
 }
 
-pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
-    let offsets = offsets.str()?;
+/// Add calendar intervals to timestamps, applying the months, then the days and
+/// finally the nanoseconds of every interval.
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let (offset_fn, ns_per_unit): (fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>, i64) =
+        match datetime.time_unit() {
+            TimeUnit::Milliseconds => (Duration::add_ms, 1_000_000),
+            TimeUnit::Microseconds => (Duration::add_us, 1_000),
+            TimeUnit::Nanoseconds => (Duration::add_ns, 1),
+        };
+    broadcast_try_binary_elementwise(
+        datetime.physical(),
+        offsets.physical(),
+        |timestamp_opt, offset_opt| match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => {
+                let offset = i128_to_interval(offset);
+                let t = offset_fn(
+                    &Duration::from_months(offset.months() as i64),
+                    timestamp,
+                    time_zone,
+                )?;
+                let t = offset_fn(&Duration::from_days(offset.days() as i64), t, time_zone)?;
+                Ok(Some(t + offset.ns() / ns_per_unit))
+            },
+            _ => Ok(None),
+        },
+    )
+}
 
+fn apply_any_offsets_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &Series,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    match offsets.dtype() {
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => apply_intervals_to_datetime(datetime, offsets.interval()?, time_zone),
+        _ => apply_offsets_to_datetime(datetime, offsets.str()?, time_zone),
+    }
+}
+
+/// Whether adding the single `offsets` value to a sorted column keeps it sorted.
+fn offset_preserves_sortedness(offsets: &Series, tz: Option<&TimeZone>) -> PolarsResult<bool> {
+    if offsets.len() != 1 {
+        return Ok(false);
+    }
+    match offsets.dtype() {
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => Ok(match offsets.interval()?.physical().get(0) {
+            Some(offset) => {
+                let offset = i128_to_interval(offset);
+                offset.months() == 0
+                    && (offset.days() == 0 || tz.is_none() || tz == Some(&TimeZone::UTC))
+            },
+            None => false,
+        }),
+        _ => Ok(match offsets.str()?.get(0) {
+            Some(offset) => {
+                let offset = Duration::try_parse(offset)?;
+                offset.is_constant_duration(tz)
+            },
+            None => false,
+        }),
+    }
+}
+
+pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
     polars_ensure!(
         ts.len() == offsets.len() || offsets.len() == 1 || ts.len() == 1,
         length_mismatch = "dt.offset_by",
@@ -78,16 +146,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
         DataType::Datetime(_, tz) => tz.clone(),
         _ => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dtype),
     };
-    let preserve_sortedness = match offsets.len() {
-        1 => match offsets.get(0) {
-            Some(offset) => {
-                let offset = Duration::try_parse(offset)?;
-                offset.is_constant_duration(tz.as_ref())
-            },
-            None => false,
-        },
-        _ => false,
-    };
+    let preserve_sortedness = offset_preserves_sortedness(offsets, tz.as_ref())?;
 
     let out = match dtype {
         DataType::Date => {
@@ -95,7 +154,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
                 .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap();
             let datetime = ts.datetime().unwrap();
-            let out = apply_offsets_to_datetime(datetime, offsets, None)?;
+            let out = apply_any_offsets_to_datetime(datetime, offsets, None)?;
             out.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
                 .unwrap()
                 .cast(&DataType::Date)
@@ -105,10 +164,12 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
 
             let out = match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => {
-                    apply_offsets_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
-                },
-                _ => apply_offsets_to_datetime(datetime, offsets, None)?,
+                Some(tz) => apply_any_offsets_to_datetime(
+                    datetime,
+                    offsets,
+                    tz.parse::<Tz>().ok().as_ref(),
+                )?,
+                _ => apply_any_offsets_to_datetime(datetime, offsets, None)?,
             };
             out.cast(&DataType::Datetime(*tu, tz.clone()))
         },
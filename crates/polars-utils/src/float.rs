@@ -5,6 +5,10 @@ pub unsafe trait IsFloat: private::Sealed + Sized {
         false
     }
 
+    fn is_f16() -> bool {
+        false
+    }
+
     fn is_f32() -> bool {
         false
     }
@@ -50,6 +54,7 @@ unsafe impl IsFloat for u8 {}
 unsafe impl IsFloat for u16 {}
 unsafe impl IsFloat for u32 {}
 unsafe impl IsFloat for u64 {}
+unsafe impl IsFloat for u128 {}
 unsafe impl IsFloat for &str {}
 unsafe impl IsFloat for &[u8] {}
 unsafe impl IsFloat for bool {}
@@ -66,6 +71,8 @@ mod private {
     impl Sealed for u16 {}
     impl Sealed for u32 {}
     impl Sealed for u64 {}
+    impl Sealed for u128 {}
+    impl Sealed for crate::float16::f16 {}
     impl Sealed for f32 {}
     impl Sealed for f64 {}
     impl Sealed for &str {}
@@ -75,13 +82,17 @@ mod private {
 }
 
 macro_rules! impl_is_float {
-    ($tp:ty, $is_f32:literal, $is_f64:literal) => {
+    ($tp:ty, $is_f16:literal, $is_f32:literal, $is_f64:literal) => {
         unsafe impl IsFloat for $tp {
             #[inline]
             fn is_float() -> bool {
                 true
             }
 
+            fn is_f16() -> bool {
+                $is_f16
+            }
+
             fn is_f32() -> bool {
                 $is_f32
             }
@@ -115,5 +126,6 @@ macro_rules! impl_is_float {
     };
 }
 
-impl_is_float!(f32, true, false);
-impl_is_float!(f64, false, true);
+impl_is_float!(crate::float16::f16, true, false, false);
+impl_is_float!(f32, false, true, false);
+impl_is_float!(f64, false, false, true);
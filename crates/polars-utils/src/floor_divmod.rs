@@ -73,6 +73,7 @@ impl_signed_div_mod!(i32);
 impl_signed_div_mod!(i64);
 impl_signed_div_mod!(i128);
 impl_signed_div_mod!(isize);
+impl_float_div_mod!(crate::float16::f16);
 impl_float_div_mod!(f32);
 impl_float_div_mod!(f64);
 
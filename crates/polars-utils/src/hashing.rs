@@ -158,6 +158,14 @@ impl DirtyHash for i128 {
     }
 }
 
+impl DirtyHash for u128 {
+    fn dirty_hash(&self) -> u64 {
+        (*self as u64)
+            .wrapping_mul(RANDOM_ODD)
+            .wrapping_add((*self >> 64) as u64)
+    }
+}
+
 impl DirtyHash for BytesHash<'_> {
     fn dirty_hash(&self) -> u64 {
         self.hash
@@ -195,6 +195,7 @@
 //!       Polars will be a bit slower with this feature activated as many data structures
 //!       are less cache efficient.
 //!     - `cse` - Activate common subplan elimination optimization
+//!     - `result_cache` - Persist the results of cached subplans across queries and processes
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
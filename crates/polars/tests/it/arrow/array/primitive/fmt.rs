@@ -8,6 +8,15 @@ fn debug_int32() {
     assert_eq!(format!("{array:?}"), "Int32[1, None, 2]");
 }
 
+#[test]
+fn debug_uint128() {
+    let array = UInt128Array::from(&[Some(u128::MAX), None, Some(2)]);
+    assert_eq!(
+        format!("{array:?}"),
+        "UInt128[340282366920938463463374607431768211455, None, 2]"
+    );
+}
+
 #[test]
 fn debug_date32() {
     let array = Int32Array::from(&[Some(1), None, Some(2)]).to(ArrowDataType::Date32);
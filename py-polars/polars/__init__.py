@@ -61,6 +61,7 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -68,7 +69,9 @@ from polars.datatypes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -78,6 +81,7 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
     Utf8,
 )
@@ -139,6 +143,7 @@ from polars.functions import (
     linear_spaces,
     lit,
     map_batches,
+    map_from_lists,
     map_groups,
     max,
     max_horizontal,
@@ -267,6 +272,7 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
@@ -274,7 +280,9 @@ __all__ = [
     "Int32",
     "Int64",
     "Int128",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -284,6 +292,7 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "UInt128",
     "Unknown",
     "Utf8",
     # polars.io
@@ -408,6 +417,7 @@ __all__ = [
     "linear_spaces",
     "lit",
     "map_batches",
+    "map_from_lists",
     "map_groups",
     "mean",
     "median",
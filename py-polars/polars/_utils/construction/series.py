@@ -34,7 +34,9 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
     List,
+    Map,
     Null,
     Object,
     Struct,
@@ -93,7 +95,7 @@ def sequence_to_pyseries(
         dtype = Null
 
     # lists defer to subsequent handling; identify nested type
-    elif dtype in (List, Array):
+    elif dtype in (List, Array, Map):
         python_dtype = list
 
     # infer temporal type handling
@@ -265,7 +267,12 @@ def sequence_to_pyseries(
         elif dtype == Object:
             return PySeries.new_object(name, values, strict)
         else:
-            if (inner_dtype := getattr(dtype, "inner", None)) is not None:
+            if isinstance(dtype, Map):
+                # maps are built as lists of `{key, value}` structs and cast below
+                inner_dtype = Struct({"key": dtype.key, "value": dtype.value})
+            else:
+                inner_dtype = getattr(dtype, "inner", None)
+            if inner_dtype is not None:
                 pyseries_list = [
                     None
                     if value is None
@@ -302,7 +309,12 @@ def sequence_to_pyseries(
                 if _check_for_numpy(python_dtype, check_type=False) and isinstance(
                     np.bool_(True), np.generic
                 ):
-                    dtype = numpy_char_code_to_dtype(np.dtype(python_dtype).char)
+                    np_dtype = np.dtype(python_dtype)
+                    dtype = (
+                        Float16
+                        if np_dtype == np.float16
+                        else numpy_char_code_to_dtype(np_dtype.char)
+                    )
                     return srs.cast(dtype, strict=strict, wrap_numerical=False)
                 else:
                     return srs
@@ -479,11 +491,16 @@ def numpy_to_pyseries(
     values = np.ascontiguousarray(values)
 
     if values.ndim == 1:
+        is_float16 = values.dtype == np.float16
         values, dtype = numpy_values_and_dtype(values)
         constructor = numpy_type_to_constructor(values, dtype)
-        return constructor(
+        pys = constructor(
             name, values, nan_to_null if dtype in (np.float32, np.float64) else strict
         )
+        # float16 values are passed as float32, which casts back losslessly
+        if is_float16:
+            pys = pys.cast(Float16, strict=True, wrap_numerical=False)
+        return pys
     else:
         original_shape = values.shape
         values_1d = values.reshape(-1)
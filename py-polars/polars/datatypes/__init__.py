@@ -17,6 +17,7 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -25,7 +26,9 @@ from polars.datatypes.classes import (
     Int64,
     Int128,
     IntegerType,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -36,6 +39,7 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
     Utf8,
 )
@@ -75,6 +79,7 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
@@ -83,7 +88,9 @@ __all__ = [
     "Int64",
     "Int8",
     "IntegerType",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -93,6 +100,7 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "UInt128",
     "UInt8",
     "Unknown",
     "Utf8",
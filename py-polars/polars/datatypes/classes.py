@@ -396,6 +396,31 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class UInt128(UnsignedIntegerType):
+    """
+    128-bit unsigned integer type.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
+class Float16(FloatType):
+    """
+    16-bit floating point type.
+
+    Arithmetic and aggregations are computed in 32-bit precision and the result is
+    rounded back to 16 bits.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -644,6 +669,34 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(DataType):
+    """
+    Data type representing a calendar interval.
+
+    An interval consists of a number of months, days and nanoseconds. Unlike a
+    :class:`Duration`, the months and days are calendar units: adding an interval
+    to a :class:`Date` or :class:`Datetime` respects month ends and daylight
+    saving time transitions.
+
+    Notes
+    -----
+    The underlying representation of this type is a 128-bit signed integer packing
+    the three components. Values are converted to and from Python as tuples of
+    `(months, days, nanoseconds)`.
+
+    Examples
+    --------
+    >>> s = pl.Series([(1, 0, 0), (0, 14, 0)], dtype=pl.Interval)
+    >>> s
+    shape: (2,)
+    Series: '' [interval]
+    [
+        1mo
+        14d
+    ]
+    """
+
+
 class Categories:
     """
     A named collection of categories for `Categorical`.
@@ -1084,6 +1137,59 @@ class Array(NestedType):
         return self.size
 
 
+class Map(NestedType):
+    """
+    Key-value map type.
+
+    Maps are stored as a variable length list of `{key, value}` structs and
+    correspond to the Arrow and Parquet `MAP` logical type.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys. Keys are never null.
+    value
+        The `DataType` of the values.
+
+    Examples
+    --------
+    >>> s = pl.Series(
+    ...     "a",
+    ...     [[{"key": "x", "value": 1}], [{"key": "y", "value": 2}]],
+    ...     dtype=pl.Map(pl.String, pl.Int64),
+    ... )
+    >>> s.dtype
+    Map(String, Int64)
+    """
+
+    key: PolarsDataType
+    value: PolarsDataType
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+    ) -> None:
+        self.key = polars.datatypes.parse_into_dtype(key)
+        self.value = polars.datatypes.parse_into_dtype(value)
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
 class Field:
     """
     Definition of a single field within a `Struct` DataType.
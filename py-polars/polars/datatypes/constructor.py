@@ -24,6 +24,7 @@ if not _DOCUMENTING:
     _POLARS_TYPE_TO_CONSTRUCTOR: dict[
         PolarsDataType, Callable[[str, Sequence[Any], bool], PySeries]
     ] = {
+        dt.Float16: PySeries.new_opt_f16,
         dt.Float32: PySeries.new_opt_f32,
         dt.Float64: PySeries.new_opt_f64,
         dt.Int8: PySeries.new_opt_i8,
@@ -35,10 +36,12 @@ if not _DOCUMENTING:
         dt.UInt16: PySeries.new_opt_u16,
         dt.UInt32: PySeries.new_opt_u32,
         dt.UInt64: PySeries.new_opt_u64,
+        dt.UInt128: PySeries.new_opt_u128,
         dt.Decimal: PySeries.new_decimal,
         dt.Date: PySeries.new_opt_i32,
         dt.Datetime: PySeries.new_opt_i64,
         dt.Duration: PySeries.new_opt_i64,
+        dt.Interval: PySeries.new_interval,
         dt.Time: PySeries.new_opt_i64,
         dt.Boolean: PySeries.new_opt_bool,
         dt.String: PySeries.new_str,
@@ -22,6 +22,7 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -29,7 +30,9 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -39,6 +42,7 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
 )
 from polars.dependencies import numpy as np
@@ -125,6 +129,12 @@ def unpack_dtypes(
             if include_compound:
                 unpacked.add(tp)
             unpacked.update(unpack_dtypes(tp.inner, include_compound=include_compound))
+        elif isinstance(tp, Map):
+            if include_compound:
+                unpacked.add(tp)
+            unpacked.update(
+                unpack_dtypes(tp.key, tp.value, include_compound=include_compound)
+            )
         elif isinstance(tp, Struct):
             if include_compound:
                 unpacked.add(tp)
@@ -148,6 +158,7 @@ class _DataTypeMappings:
             Datetime: "datetime",
             Decimal: "decimal",
             Duration: "duration",
+            Float16: "f16",
             Float32: "f32",
             Float64: "f64",
             Int128: "i128",
@@ -163,6 +174,7 @@ class _DataTypeMappings:
             UInt16: "u16",
             UInt32: "u32",
             UInt64: "u64",
+            UInt128: "u128",
             UInt8: "u8",
         }
 
@@ -177,6 +189,7 @@ class _DataTypeMappings:
             Datetime: datetime,
             Decimal: PyDecimal,
             Duration: timedelta,
+            Float16: float,
             Float32: float,
             Float64: float,
             Int128: int,
@@ -184,7 +197,9 @@ class _DataTypeMappings:
             Int32: int,
             Int64: int,
             Int8: int,
+            Interval: tuple,
             List: list,
+            Map: list,
             Null: None.__class__,
             Object: object,
             String: str,
@@ -193,6 +208,7 @@ class _DataTypeMappings:
             UInt16: int,
             UInt32: int,
             UInt64: int,
+            UInt128: int,
             UInt8: int,
             # the below mappings are appropriate as we restrict cat/enum to strings
             Enum: str,
@@ -0,0 +1,137 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprMapNameSpace:
+    """Namespace for map related expressions."""
+
+    _accessor = "map"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def get(self, key: IntoExpr) -> Expr:
+        """
+        Get the value associated with `key` in every map.
+
+        Maps that do not contain the key, or that are null, yield null.
+
+        Parameters
+        ----------
+        key
+            Key to look up. Accepts expression input. Strings are parsed as
+            literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "a", "value": 1}], [{"key": "b", "value": 2}]]},
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.get("a"))
+        shape: (2, 1)
+        ┌──────┐
+        │ m    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 1    │
+        │ null │
+        └──────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_get(key_pyexpr))
+
+    def keys(self) -> Expr:
+        """
+        Get the keys of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "m": [
+        ...             [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...             [{"key": "c", "value": 3}],
+        ...         ]
+        ...     },
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.keys())
+        shape: (2, 1)
+        ┌────────────┐
+        │ m          │
+        │ ---        │
+        │ list[str]  │
+        ╞════════════╡
+        │ ["a", "b"] │
+        │ ["c"]      │
+        └────────────┘
+        """
+        return wrap_expr(self._pyexpr.map_keys())
+
+    def values(self) -> Expr:
+        """
+        Get the values of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "m": [
+        ...             [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        ...             [{"key": "c", "value": 3}],
+        ...         ]
+        ...     },
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.values())
+        shape: (2, 1)
+        ┌───────────┐
+        │ m         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ [3]       │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.map_values())
+
+    def contains_key(self, key: IntoExpr) -> Expr:
+        """
+        Check if every map contains `key`.
+
+        Parameters
+        ----------
+        key
+            Key to look for. Accepts expression input. Strings are parsed as
+            literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "a", "value": 1}], [{"key": "b", "value": 2}]]},
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.contains_key("a"))
+        shape: (2, 1)
+        ┌───────┐
+        │ m     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_contains_key(key_pyexpr))
@@ -19,6 +19,7 @@ from polars.functions.as_datatype import (
     concat_str,
     duration,
     format,
+    map_from_lists,
     struct,
 )
 from polars.functions.as_datatype import date_ as date
@@ -163,6 +164,7 @@ __all__ = [
     "linear_spaces",
     "lit",
     "map_batches",
+    "map_from_lists",
     "map_groups",
     "mean",
     "mean_horizontal",
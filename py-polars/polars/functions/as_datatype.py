@@ -621,6 +621,33 @@ def concat_arr(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> E
     return wrap_expr(plr.concat_arr(exprs))
 
 
+def map_from_lists(keys: IntoExpr, values: IntoExpr) -> Expr:
+    """
+    Combine a list column of keys and a list column of values into a map column.
+
+    The lists must have the same length in every row. A row is null if either the
+    keys or the values are null.
+
+    Parameters
+    ----------
+    keys
+        List column with the keys of the map. Accepts expression input. Strings are
+        parsed as column names.
+    values
+        List column with the values of the map. Accepts expression input. Strings
+        are parsed as column names.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"k": [["a", "b"], ["c"]], "v": [[1, 2], [3]]})
+    >>> df.select(pl.map_from_lists("k", "v").alias("m")).schema
+    Schema({'m': Map(String, Int64)})
+    """
+    keys_pyexpr = parse_into_expression(keys)
+    values_pyexpr = parse_into_expression(values)
+    return wrap_expr(plr.map_from_lists(keys_pyexpr, values_pyexpr))
+
+
 @overload
 def struct(
     *exprs: IntoExpr | Iterable[IntoExpr],
@@ -28,6 +28,7 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
 )
 from polars.datatypes._parse import parse_py_type_into_dtype
 from polars.datatypes.group import (
@@ -277,7 +278,7 @@ def integer_dtype_from_nbits(
         (64, False): Int64,
         (64, True): UInt64,
         (128, False): Int128,
-        (128, True): Int128,  # UInt128 not (yet?) supported
+        (128, True): UInt128,
     }.get((bits, unsigned), None)
 
     if dtype is None and default is not None:
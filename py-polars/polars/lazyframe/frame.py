@@ -67,6 +67,7 @@ from polars.datatypes import (
     Datetime,
     Duration,
     Enum,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -82,6 +83,7 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
     is_polars_dtype,
     parse_into_datatype_expr,
@@ -1154,6 +1156,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         *,
         format: ExplainFormat = "plain",
         optimized: bool = True,
+        statistics: bool = False,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -1182,6 +1185,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             If this is set to `True` the subsequent
             optimization flags control which optimizations
             run.
+        statistics
+            Annotate every node of the optimized plan with its (estimated)
+            number of rows. Only supported for the `'plain'` format of the
+            optimized plan.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
         type_coercion
             Do type coercion optimization.
 
@@ -1284,6 +1295,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             if tree_format:
                 format = "tree"
 
+        if statistics and (format != "plain" or not optimized):
+            msg = "`statistics=True` is only supported for the optimized 'plain' format"
+            raise ValueError(msg)
+
         engine = _select_engine(engine)
 
         if engine == "streaming":
@@ -1295,6 +1310,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
             if format == "tree":
                 return ldf.describe_optimized_plan_tree()
+            elif statistics:
+                return ldf.describe_optimized_plan_with_statistics()
             else:
                 return ldf.describe_optimized_plan()
 
@@ -6621,6 +6638,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     UInt16,
                     UInt32,
                     UInt64,
+                    UInt128,
+                    Float16,
                     Float32,
                     Float64,
                     Decimal,
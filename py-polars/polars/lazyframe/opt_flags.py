@@ -43,6 +43,9 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        join_reorder: None | bool = None,
+        eager_aggregation: None | bool = None,
+        result_cache: None | bool = None,
     ) -> None:
         self._pyoptflags = PyOptFlags.default()
         self.update(
@@ -56,6 +59,9 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            join_reorder=join_reorder,
+            eager_aggregation=eager_aggregation,
+            result_cache=result_cache,
         )
 
     @classmethod
@@ -77,6 +83,9 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        join_reorder: None | bool = None,
+        eager_aggregation: None | bool = None,
+        result_cache: None | bool = None,
     ) -> QueryOptFlags:
         """Create new empty set off optimizations."""
         optflags = QueryOptFlags()
@@ -92,6 +101,9 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            join_reorder=join_reorder,
+            eager_aggregation=eager_aggregation,
+            result_cache=result_cache,
         )
 
     def update(
@@ -107,6 +119,9 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        join_reorder: None | bool = None,
+        eager_aggregation: None | bool = None,
+        result_cache: None | bool = None,
     ) -> QueryOptFlags:
         """Update the current optimization flags."""
         if predicate_pushdown is not None:
@@ -129,6 +144,12 @@ class QueryOptFlags:
             self.check_order_observe = check_order_observe
         if fast_projection is not None:
             self.fast_projection = fast_projection
+        if join_reorder is not None:
+            self.join_reorder = join_reorder
+        if eager_aggregation is not None:
+            self.eager_aggregation = eager_aggregation
+        if result_cache is not None:
+            self.result_cache = result_cache
 
         return self
 
@@ -241,6 +262,33 @@ class QueryOptFlags:
     def fast_projection(self, value: bool) -> None:
         self._pyoptflags.fast_projection = value
 
+    @property
+    def join_reorder(self) -> bool:
+        """Reorder chains of inner joins based on the estimated number of rows of their inputs."""  # noqa: W505
+        return self._pyoptflags.join_reorder
+
+    @join_reorder.setter
+    def join_reorder(self, value: bool) -> None:
+        self._pyoptflags.join_reorder = value
+
+    @property
+    def eager_aggregation(self) -> bool:
+        """Push decomposable aggregations below the joins they aggregate."""
+        return self._pyoptflags.eager_aggregation
+
+    @eager_aggregation.setter
+    def eager_aggregation(self, value: bool) -> None:
+        self._pyoptflags.eager_aggregation = value
+
+    @property
+    def result_cache(self) -> bool:
+        """Persist the results of cached subplans across queries and processes."""
+        return self._pyoptflags.result_cache
+
+    @result_cache.setter
+    def result_cache(self, value: bool) -> None:
+        self._pyoptflags.result_cache = value
+
     def __str__(self) -> str:
         return f"""
 QueryOptFlags {{
@@ -257,6 +305,9 @@ QueryOptFlags {{
     collapse_joins: {self.collapse_joins}
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
+    join_reorder: {self.join_reorder}
+    eager_aggregation: {self.eager_aggregation}
+    result_cache: {self.result_cache}
 
     eager: {self._pyoptflags.eager}
     streaming: {self._pyoptflags.streaming}
@@ -68,6 +68,7 @@ from polars.datatypes import (
     Float64,
     Int32,
     Int64,
+    Interval,
     List,
     Null,
     Object,
@@ -1066,6 +1067,14 @@ class Series:
             other = pl.Series("", [None])
 
         if isinstance(other, Series):
+            if (
+                op_s in ("add", "sub")
+                and Interval in (self.dtype, other.dtype)
+                and self.dtype != other.dtype
+            ):
+                # calendar arithmetic with intervals is resolved by the query engine
+                lhs, rhs = (other, self) if "rhs" in op_ffi else (self, other)
+                return pl.select(getattr(F.lit(lhs), op_s)(F.lit(rhs))).to_series()
             return self._from_pyseries(getattr(self._s, op_s)(other._s))
         elif _check_for_numpy(other) and isinstance(other, np.ndarray):
             return self._from_pyseries(getattr(self._s, op_s)(Series(other)._s))
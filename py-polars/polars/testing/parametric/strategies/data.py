@@ -56,6 +56,7 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
 )
 from polars.testing.parametric.strategies._utils import flexhash
 from polars.testing.parametric.strategies.dtype import (
@@ -365,6 +366,7 @@ _STATIC_STRATEGIES: dict[DataTypeClass, SearchStrategy[Any]] = {
     UInt16: integers(16, signed=False),
     UInt32: integers(32, signed=False),
     UInt64: integers(64, signed=False),
+    UInt128: integers(128, signed=False),
     Time: times(),
     Date: dates(),
     String: strings(),
@@ -16,9 +16,9 @@ use polars_python::cloud_client;
 use polars_python::cloud_server;
 use polars_python::conversion::PyCategories;
 use polars_python::dataframe::PyDataFrame;
+use polars_python::expr::PyExpr;
 use polars_python::expr::datatype::PyDataTypeExpr;
 use polars_python::expr::selector::PySelector;
-use polars_python::expr::PyExpr;
 use polars_python::functions::PyStringCacheHolder;
 #[cfg(not(target_arch = "wasm32"))]
 use polars_python::lazyframe::PyInProcessQuery;
@@ -176,6 +176,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::as_struct))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::map_from_lists))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::coalesce))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::field)).unwrap();
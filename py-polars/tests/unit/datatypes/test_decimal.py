@@ -7,7 +7,7 @@ from dataclasses import dataclass
 from decimal import Decimal as D
 from math import ceil, floor
 from random import choice, randrange, seed
-from typing import Any, Callable, NamedTuple
+from typing import TYPE_CHECKING, Any, Callable, NamedTuple
 
 import pyarrow as pa
 import pytest
@@ -15,6 +15,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 @pytest.fixture(scope="module")
 def permutations_int_dec_none() -> list[tuple[D | int | None, ...]]:
@@ -249,7 +252,7 @@ def test_decimal_arithmetic() -> None:
         pl.Decimal(precision=None, scale=2),
         pl.Decimal(precision=None, scale=6),
         pl.Decimal(precision=None, scale=2),
-        pl.Decimal(precision=None, scale=14),
+        pl.Decimal(precision=34, scale=14),
     ]
 
     assert out.to_dict(as_series=False) == {
@@ -317,9 +320,10 @@ def test_decimal_aggregations() -> None:
             "sum": [D("10.20"), D("9100.13")],
             "min": [D("0.10"), D("100.01")],
             "max": [D("10.10"), D("9000.12")],
-            "mean": [5.1, 4550.065],
+            "mean": [D("5.1"), D("4550.065")],
             "median": [5.1, 4550.065],
-        }
+        },
+        schema_overrides={"mean": pl.Decimal(None, 6)},
     )
     assert_frame_equal(result, expected)
 
@@ -335,9 +339,10 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
+            "mean": [D("2277.5825")],
             "median": [55.055],
-        }
+        },
+        schema_overrides={"mean": pl.Decimal(None, 6)},
     )
     assert_frame_equal(res, expected)
 
@@ -588,24 +593,24 @@ def test_decimal_arithmetic_schema() -> None:
 
 def test_decimal_arithmetic_schema_float_20369() -> None:
     s = pl.Series("x", [1.0], dtype=pl.Decimal(15, 2))
-    assert_series_equal((s - 1.0), pl.Series("x", [0.0], dtype=pl.Decimal(None, 2)))
+    assert_series_equal((s - 1.0), pl.Series("x", [0.0], dtype=pl.Decimal(16, 2)))
     assert_series_equal(
-        (3.0 - s), pl.Series("literal", [2.0], dtype=pl.Decimal(None, 2))
+        (3.0 - s), pl.Series("literal", [2.0], dtype=pl.Decimal(16, 2))
     )
     assert_series_equal(
-        (3.0 / s), pl.Series("literal", [3.0], dtype=pl.Decimal(None, 6))
+        (3.0 / s), pl.Series("literal", [3.0], dtype=pl.Decimal(21, 6))
     )
     assert_series_equal(
-        (s / 3.0), pl.Series("x", [0.333333], dtype=pl.Decimal(None, 6))
+        (s / 3.0), pl.Series("x", [0.333333], dtype=pl.Decimal(21, 6))
     )
 
-    assert_series_equal((s + 1.0), pl.Series("x", [2.0], dtype=pl.Decimal(None, 2)))
+    assert_series_equal((s + 1.0), pl.Series("x", [2.0], dtype=pl.Decimal(16, 2)))
     assert_series_equal(
-        (1.0 + s), pl.Series("literal", [2.0], dtype=pl.Decimal(None, 2))
+        (1.0 + s), pl.Series("literal", [2.0], dtype=pl.Decimal(16, 2))
     )
-    assert_series_equal((s * 1.0), pl.Series("x", [1.0], dtype=pl.Decimal(None, 4)))
+    assert_series_equal((s * 1.0), pl.Series("x", [1.0], dtype=pl.Decimal(31, 4)))
     assert_series_equal(
-        (1.0 * s), pl.Series("literal", [1.0], dtype=pl.Decimal(None, 4))
+        (1.0 * s), pl.Series("literal", [1.0], dtype=pl.Decimal(31, 4))
     )
 
 
@@ -758,3 +763,124 @@ def test_decimal32_decimal64_22946() -> None:
             ]
         ),
     )
+
+
+def test_decimal_arithmetic_precision_inference() -> None:
+    df = pl.DataFrame(
+        {"a": [D("12.34")], "b": [D("5.678")]},
+        schema={"a": pl.Decimal(10, 2), "b": pl.Decimal(5, 3)},
+    )
+    out = df.select(
+        add=pl.col.a + pl.col.b,
+        sub=pl.col.a - pl.col.b,
+        mul=pl.col.a * pl.col.b,
+    )
+    assert out.schema == pl.Schema(
+        {
+            "add": pl.Decimal(12, 3),
+            "sub": pl.Decimal(12, 3),
+            "mul": pl.Decimal(16, 5),
+        }
+    )
+    assert out.row(0) == (D("18.018"), D("6.662"), D("70.06652"))
+    assert df.lazy().select(pl.col.a / pl.col.b).collect_schema() == (
+        df.select(pl.col.a / pl.col.b).schema
+    )
+
+
+def test_decimal_arithmetic_overflow() -> None:
+    s = pl.Series([10**37], dtype=pl.Decimal(38, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        s * s
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        pl.Series([10**37] * 20, dtype=pl.Decimal(38, 0)).sum()
+
+    s = pl.Series([9 * 10**37, 9 * 10**37, -(9 * 10**37)], dtype=pl.Decimal(38, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        s.rolling_sum(2)
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        s.rolling_mean(2)
+    # Only the exact window sums need to fit.
+    assert s.rolling_sum(3).to_list() == [None, None, D(9 * 10**37)]
+
+
+def test_decimal_round_modes() -> None:
+    s = pl.Series([D("2.5"), D("3.5"), D("-2.5"), D("0.25")], dtype=pl.Decimal(5, 2))
+    assert s.round(0).to_list() == [D("2"), D("4"), D("-2"), D("0")]
+    assert s.round(1, mode="half_to_even").to_list() == [
+        D("2.5"),
+        D("3.5"),
+        D("-2.5"),
+        D("0.2"),
+    ]
+    assert s.round(0, mode="half_away_from_zero").to_list() == [
+        D("3"),
+        D("4"),
+        D("-3"),
+        D("0"),
+    ]
+    assert s.round(1, mode="half_away_from_zero").to_list() == [
+        D("2.5"),
+        D("3.5"),
+        D("-2.5"),
+        D("0.3"),
+    ]
+
+
+def test_decimal_rolling() -> None:
+    s = pl.Series("a", [D("1.00"), D("2.00"), D("-3.50"), None], pl.Decimal(10, 2))
+
+    assert_series_equal(
+        s.rolling_sum(2),
+        pl.Series("a", [None, D("3.00"), D("-1.50"), None], pl.Decimal(10, 2)),
+    )
+    assert_series_equal(
+        s.rolling_min(2),
+        pl.Series("a", [None, D("1.00"), D("-3.50"), None], pl.Decimal(10, 2)),
+    )
+    assert_series_equal(
+        s.rolling_max(2),
+        pl.Series("a", [None, D("2.00"), D("2.00"), None], pl.Decimal(10, 2)),
+    )
+    assert_series_equal(
+        s.rolling_mean(2, min_samples=1),
+        pl.Series("a", [D("1"), D("1.5"), D("-0.75"), D("-3.5")], pl.Decimal(14, 6)),
+    )
+
+
+def test_decimal_var_std() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2],
+            "x": [D(v) for v in ["1.00", "2.00", "3.00", "4.00", "6.00"]],
+        },
+        schema={"g": pl.Int64, "x": pl.Decimal(10, 2)},
+    )
+    assert df["x"].var() == pytest.approx(3.7)
+    assert df["x"].std() == pytest.approx(3.7**0.5)
+
+    out = df.group_by("g", maintain_order=True).agg(
+        var=pl.col.x.var(), std=pl.col.x.std()
+    )
+    assert out["var"].to_list() == pytest.approx([1.0, 2.0])
+    assert out["std"].to_list() == pytest.approx([1.0, 2.0**0.5])
+
+
+def test_decimal_parquet_statistics_pruning(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {"x": [D(v) for v in ["-5.25", "-1.50", "0.00", "1.75", "3.00", "8.10"]]},
+        schema={"x": pl.Decimal(10, 2)},
+    )
+    path = tmp_path / "decimal.parquet"
+    df.write_parquet(path, row_group_size=2, statistics=True)
+
+    for predicate in [
+        pl.col.x > D("-1.5"),
+        pl.col.x <= D("-1.50"),
+        pl.col.x == D("1.75"),
+        pl.col.x.is_between(D("-2"), D("2")),
+    ]:
+        assert_frame_equal(
+            pl.scan_parquet(path).filter(predicate).collect(),
+            df.filter(predicate),
+        )
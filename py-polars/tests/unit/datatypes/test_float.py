@@ -308,4 +308,33 @@ def test_arrow_float16_read_empty_20946() -> None:
 
     df = pl.from_arrow(table)
     assert df.shape == (0, 1)
-    assert df.schema == pl.Schema([("float_column", pl.Float32)])  # type: ignore[union-attr]
+    assert df.schema == pl.Schema([("float_column", pl.Float16)])  # type: ignore[union-attr]
+
+
+def test_float16() -> None:
+    s = pl.Series("a", [1.5, None, -2.0, 0.1], dtype=pl.Float16)
+    assert s.dtype == pl.Float16
+    assert "f16" in str(s)
+    assert s.to_list() == [1.5, None, -2.0, 0.0999755859375]
+
+    assert (s + 1).dtype == pl.Float16
+    assert (s * 2).to_list() == [3.0, None, -4.0, 0.199951171875]
+    assert (s + pl.Series([1], dtype=pl.Float32)).dtype == pl.Float32
+    assert (s + pl.Series([1], dtype=pl.Int8)).dtype == pl.Float16
+    assert (s + pl.Series([1], dtype=pl.Int32)).dtype == pl.Float64
+
+    assert s.sum() == pytest.approx(-0.4, abs=1e-3)
+    assert s.min() == -2.0
+    assert s.max() == 1.5
+    assert s.mean() == pytest.approx(-0.1333, abs=1e-3)
+
+    assert (s > 0).to_list() == [True, None, False, True]
+    assert s.sort().to_list() == [None, -2.0, 0.0999755859375, 1.5]
+
+    assert s.cast(pl.Float64).to_list() == [1.5, None, -2.0, 0.0999755859375]
+    assert pl.Series([1, 70000]).cast(pl.Float16).to_list() == [1.0, float("inf")]
+
+    df = pl.DataFrame({"a": [1.0, 1.0, 2.0], "b": [1, 2, 3]}).cast({"a": pl.Float16})
+    out = df.group_by("a", maintain_order=True).agg(pl.col("b").sum())
+    assert out.schema["a"] == pl.Float16
+    assert out.to_dict(as_series=False) == {"a": [1.0, 2.0], "b": [3, 3]}
@@ -29,3 +29,21 @@ def test_compare_zero_with_uint64_16798() -> None:
     df = pl.Series("a", [(1 << 63), 0], dtype=pl.UInt64).to_frame()
     assert df.select(pl.col("a") >= 0).item(0, 0)
     assert df.select(pl.col("a") == 0).item(0, 0) is False
+
+
+def test_uint128() -> None:
+    big = 2**128 - 1
+    s = pl.Series("a", [big, 1, None, big], dtype=pl.UInt128)
+    assert s.dtype == pl.UInt128
+    assert s.to_list() == [big, 1, None, big]
+    assert s.max() == big
+    assert (s - 1).to_list() == [big - 1, 0, None, big - 1]
+    assert s.n_unique() == 3
+    assert s.sort().to_list() == [None, 1, big, big]
+
+    df = pl.DataFrame({"a": s, "b": [1, 2, 3, 4]})
+    out = df.group_by("a", maintain_order=True).agg(pl.col("b").sum())
+    assert out.to_dict(as_series=False) == {"a": [big, 1, None], "b": [5, 2, 3]}
+
+    assert pl.Series([1, 2], dtype=pl.UInt64).cast(pl.UInt128).dtype == pl.UInt128
+    assert "u128" in str(s)
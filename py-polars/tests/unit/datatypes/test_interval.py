@@ -0,0 +1,132 @@
+from __future__ import annotations
+
+import io
+from datetime import date, datetime, timedelta
+from zoneinfo import ZoneInfo
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_interval_construction() -> None:
+    s = pl.Series("iv", [(1, 2, 3), None, (-1, 0, 0)], dtype=pl.Interval)
+    assert s.dtype == pl.Interval
+    assert s.to_list() == [(1, 2, 3), None, (-1, 0, 0)]
+    assert s[0] == (1, 2, 3)
+    assert s.null_count() == 1
+
+
+def test_interval_repr() -> None:
+    s = pl.Series([(1, 0, 0), (0, 14, 3_600_000_000_000), (0, 0, 0)], dtype=pl.Interval)
+    assert s.cast(pl.String).to_list() == ["1mo", "14d 1h", "0d"]
+
+
+def test_interval_add_to_date_month_end() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 1, 31), date(2023, 1, 31), date(2024, 3, 15)],
+            "period": [(1, 0, 0), (1, 0, 0), (0, 30, 0)],
+        },
+        schema={"start": pl.Date, "period": pl.Interval},
+    )
+    out = df.select(end=pl.col("start") + pl.col("period"))
+    expected = pl.DataFrame(
+        {"end": [date(2024, 2, 29), date(2023, 2, 28), date(2024, 4, 14)]}
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_interval_add_to_datetime_dst() -> None:
+    tz = "Europe/Amsterdam"
+    s = pl.Series([datetime(2024, 3, 30, 12)]).dt.replace_time_zone(tz)
+    one_day = pl.Series([(0, 1, 0)], dtype=pl.Interval)
+    one_day_fixed = pl.Series([(0, 0, 24 * 3_600 * 1_000_000_000)], dtype=pl.Interval)
+
+    assert (s + one_day).item() == datetime(2024, 3, 31, 12, tzinfo=ZoneInfo(tz))
+    assert (s + one_day_fixed).item() == datetime(2024, 3, 31, 13, tzinfo=ZoneInfo(tz))
+
+
+def test_interval_sub_and_negate() -> None:
+    df = pl.DataFrame(
+        {"ts": [datetime(2024, 3, 31, 6)], "iv": [(1, 1, 3_600_000_000_000)]},
+        schema={"ts": pl.Datetime("us"), "iv": pl.Interval},
+    )
+    out = df.select(
+        sub=pl.col("ts") - pl.col("iv"),
+        neg=-pl.col("iv"),
+    )
+    assert out["sub"].item() == datetime(2024, 2, 28, 5)
+    assert out["neg"].item() == (-1, -1, -3_600_000_000_000)
+
+
+def test_interval_per_row_offsets() -> None:
+    df = pl.DataFrame(
+        {
+            "billed": [date(2024, 1, 31), date(2024, 1, 31), None],
+            "period": [(1, 0, 0), (12, 0, 0), (3, 0, 0)],
+        },
+        schema={"billed": pl.Date, "period": pl.Interval},
+    )
+    out = df.select(pl.col("billed") + pl.col("period"))
+    assert out["billed"].to_list() == [date(2024, 2, 29), date(2025, 1, 31), None]
+
+
+def test_interval_cast_from_duration() -> None:
+    s = pl.Series([timedelta(days=1, seconds=1), None])
+    out = s.cast(pl.Interval)
+    assert out.to_list() == [(0, 0, 86_401_000_000_000), None]
+
+
+def test_interval_sql_per_row() -> None:
+    df = pl.DataFrame(
+        {"dt": [date(2024, 1, 31)], "iv": [(1, 0, 0)]},
+        schema={"dt": pl.Date, "iv": pl.Interval},
+    )
+    out = df.sql("SELECT dt + iv AS a, dt + INTERVAL '1 month 1 day' AS b FROM self")
+    assert out.row(0) == (date(2024, 2, 29), date(2024, 3, 1))
+
+
+def test_interval_arrow_roundtrip() -> None:
+    s = pl.Series("iv", [(1, 2, 3), None], dtype=pl.Interval)
+    arr = s.to_arrow()
+    assert arr.type == pa.month_day_nano_interval()
+    assert arr.to_pylist() == [pa.MonthDayNano([1, 2, 3]), None]
+
+    out = pl.from_arrow(
+        pa.array([pa.MonthDayNano([1, 2, 3]), None], pa.month_day_nano_interval())
+    )
+    assert isinstance(out, pl.Series)
+    assert_series_equal(out, s, check_names=False)
+
+
+def test_interval_parquet_roundtrip() -> None:
+    # Parquet stores the milliseconds as an unsigned 32-bit integer.
+    thirty_days_ns = 30 * 86_400_000 * 1_000_000
+    df = pl.DataFrame(
+        {"iv": [(1, 2, 3_000_000), None, (0, 40, thirty_days_ns)]},
+        schema={"iv": pl.Interval},
+    )
+    f = io.BytesIO()
+    df.write_parquet(f, statistics=True)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    # The sort order of parquet intervals is undefined.
+    stats = pq.ParquetFile(f).metadata.row_group(0).column(0).statistics
+    assert stats is None or not stats.has_min_max
+
+
+@pytest.mark.parametrize(
+    "value",
+    [(-1, 0, 0), (0, -2, 0), (0, 0, -3_000_000), (0, 0, 1_500), (0, 0, 2**32 * 10**6)],
+)
+def test_interval_parquet_unrepresentable(value: tuple[int, int, int]) -> None:
+    df = pl.DataFrame({"iv": [value]}, schema={"iv": pl.Interval})
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="cannot be written to parquet"
+    ):
+        df.write_parquet(io.BytesIO())
@@ -0,0 +1,147 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ShapeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def map_df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "m": [
+                [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+                [{"key": "c", "value": None}],
+                [],
+                None,
+            ]
+        },
+        schema={"m": pl.Map(pl.String, pl.Int64)},
+    )
+
+
+def test_map_dtype() -> None:
+    dtype = pl.Map(pl.String, pl.Int64)
+    assert dtype.key == pl.String
+    assert dtype.value == pl.Int64
+    assert dtype == pl.Map
+    assert dtype == pl.Map(pl.String, pl.Int64)
+    assert dtype != pl.Map(pl.String, pl.Int32)
+    assert dtype != pl.List(pl.Struct({"key": pl.String, "value": pl.Int64}))
+    assert dtype.is_nested()
+    assert repr(dtype) == "Map(String, Int64)"
+    assert hash(dtype) == hash(pl.Map(pl.String, pl.Int64))
+
+
+def test_map_cast_from_list_of_struct() -> None:
+    s = pl.Series(
+        [[{"key": 1, "value": "x"}], None],
+        dtype=pl.List(pl.Struct({"key": pl.Int64, "value": pl.String})),
+    )
+    out = s.cast(pl.Map(pl.Int64, pl.String))
+    assert out.dtype == pl.Map(pl.Int64, pl.String)
+    assert_series_equal(out.cast(s.dtype), s)
+
+
+def test_map_get(map_df: pl.DataFrame) -> None:
+    out = map_df.select(
+        a=pl.col("m").map.get("a"),
+        b=pl.col("m").map.get("b"),
+        c=pl.col("m").map.get("c"),
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, None, None, None],
+            "b": [2, None, None, None],
+            "c": [None, None, None, None],
+        },
+        schema={"a": pl.Int64, "b": pl.Int64, "c": pl.Int64},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_map_get_expr_key(map_df: pl.DataFrame) -> None:
+    out = map_df.with_columns(k=pl.Series(["b", "c", "a", "a"])).select(
+        pl.col("m").map.get(pl.col("k"))
+    )
+    assert_series_equal(
+        out.to_series(), pl.Series("m", [2, None, None, None], dtype=pl.Int64)
+    )
+
+
+def test_map_get_sliced() -> None:
+    df = pl.DataFrame(
+        {
+            "m": [
+                [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+                [{"key": "c", "value": 3}, {"key": "a", "value": 4}],
+                [{"key": "b", "value": 5}],
+            ],
+            "k": ["b", "a", "b"],
+        },
+        schema={"m": pl.Map(pl.String, pl.Int64), "k": pl.String},
+    ).slice(1)
+    out = df.select(
+        get=pl.col("m").map.get(pl.col("k")),
+        contains=pl.col("m").map.contains_key("a"),
+    )
+    expected = pl.DataFrame(
+        {"get": [4, 5], "contains": [True, False]},
+        schema={"get": pl.Int64, "contains": pl.Boolean},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_map_keys_values(map_df: pl.DataFrame) -> None:
+    out = map_df.select(
+        keys=pl.col("m").map.keys(),
+        values=pl.col("m").map.values(),
+    )
+    expected = pl.DataFrame(
+        {
+            "keys": [["a", "b"], ["c"], [], None],
+            "values": [[1, 2], [None], [], None],
+        },
+        schema={"keys": pl.List(pl.String), "values": pl.List(pl.Int64)},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_map_contains_key(map_df: pl.DataFrame) -> None:
+    out = map_df.select(
+        a=pl.col("m").map.contains_key("a"),
+        c=pl.col("m").map.contains_key("c"),
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [True, False, False, None],
+            "c": [False, True, False, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_map_from_lists(map_df: pl.DataFrame) -> None:
+    df = pl.DataFrame(
+        {
+            "k": [["a", "b"], ["c"], [], None],
+            "v": [[1, 2], [None], [], [3]],
+        },
+        schema={"k": pl.List(pl.String), "v": pl.List(pl.Int64)},
+    )
+    out = df.select(m=pl.map_from_lists("k", "v"))
+    assert_frame_equal(out, map_df)
+
+
+def test_map_from_lists_length_mismatch() -> None:
+    df = pl.DataFrame({"k": [["a", "b"]], "v": [[1]]})
+    with pytest.raises(ShapeError):
+        df.select(pl.map_from_lists("k", "v"))
+
+
+def test_map_explode(map_df: pl.DataFrame) -> None:
+    out = map_df.explode("m")
+    assert out.schema == {"m": pl.Struct({"key": pl.String, "value": pl.Int64})}
+    assert out.height == 5
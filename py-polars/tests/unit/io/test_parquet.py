@@ -1362,13 +1362,37 @@ def test_parquet_pyarrow_map() -> None:
         assert_frame_equal(
             pl.read_parquet(
                 f,
-                schema={"x": pl.List(pl.Struct({"key": pl.Int32, "value": pl.Int32}))},
+                schema={"x": pl.Map(pl.Int32, pl.Int32)},
                 missing_columns=missing_columns,  # type: ignore[arg-type]
             ).explode(["x"]),
             expected,
         )
 
 
+def test_parquet_map_roundtrip() -> None:
+    df = pl.DataFrame(
+        {
+            "x": [
+                [{"key": "a", "value": 1}, {"key": "b", "value": None}],
+                None,
+                [],
+            ]
+        },
+        schema={"x": pl.Map(pl.String, pl.Int64)},
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(f)
+
+    f.seek(0)
+    assert pa.types.is_map(pq.read_schema(f).field("x").type)
+
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert out.schema == {"x": pl.Map(pl.String, pl.Int64)}
+    assert_frame_equal(out, df)
+
+
 @pytest.mark.parametrize(
     ("s", "elem"),
     [
@@ -2259,7 +2283,7 @@ def test_decode_f16() -> None:
         }
     )
 
-    df = pl.Series("x", values, pl.Float32).to_frame()
+    df = pl.Series("x", values, pl.Float16).to_frame()
 
     f = io.BytesIO()
     pq.write_table(table, f)
@@ -3503,6 +3527,31 @@ def test_roundtrip_int128() -> None:
     assert_series_equal(pl.read_parquet(f).to_series(), s)
 
 
+def test_roundtrip_uint128() -> None:
+    f = io.BytesIO()
+    s = pl.Series("a", [1, None, 2**128 - 1], pl.UInt128)
+    s.to_frame().write_parquet(f)
+    f.seek(0)
+    assert_series_equal(pl.read_parquet(f).to_series(), s)
+
+
+def test_roundtrip_float16() -> None:
+    f = io.BytesIO()
+    s = pl.Series("a", [1.5, None, -0.25, float("inf"), 65504.0], pl.Float16)
+    s.to_frame().write_parquet(f)
+    f.seek(0)
+    assert_series_equal(pl.read_parquet(f).to_series(), s)
+
+    f.seek(0)
+    assert pq.read_schema(f).field("a").type == pa.float16()
+
+    f.seek(0)
+    assert_series_equal(
+        pl.scan_parquet(f).filter(pl.col.a > 1.0).collect().to_series(),
+        pl.Series("a", [1.5, float("inf"), 65504.0], pl.Float16),
+    )
+
+
 def test_write_nested_categoricals() -> None:
     df = pl.select(
         pl.lit(pl.Series("col", ["a", "b"], dtype=pl.Categorical)).implode().implode(),
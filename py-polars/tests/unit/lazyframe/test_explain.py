@@ -37,3 +37,14 @@ def test_lf_explain_tree_format_deprecated() -> None:
 
     with pytest.deprecated_call():
         lf.explain(tree_format=True)
+
+
+def test_lf_explain_statistics() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": [5, 6, 7, 8]})
+
+    assert "[rows: 4]" in lf.explain(statistics=True)
+
+    with pytest.raises(ValueError, match="only supported for the optimized 'plain'"):
+        lf.explain(statistics=True, format="tree")
+    with pytest.raises(ValueError, match="only supported for the optimized 'plain'"):
+        lf.explain(statistics=True, optimized=False)
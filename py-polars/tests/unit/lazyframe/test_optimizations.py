@@ -1,10 +1,16 @@
+from __future__ import annotations
+
 import itertools
+from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 def test_is_null_followed_by_all() -> None:
     lf = pl.LazyFrame({"group": [0, 0, 0, 1], "val": [6, 0, None, None]})
@@ -385,3 +391,30 @@ def test_fused_correct_name() -> None:
         opts,
     )
     assert_frame_equal(opts, pl.DataFrame({"a": [2, 6, 12]}))
+
+
+@pytest.mark.parametrize("flag", ["join_reorder", "eager_aggregation", "result_cache"])
+def test_opt_flags_opt_in(
+    flag: str, monkeypatch: pytest.MonkeyPatch, tmp_path: Path
+) -> None:
+    # don't write to the default result cache directory
+    monkeypatch.setenv("POLARS_RESULT_CACHE_DIR", str(tmp_path))
+
+    # these optimizations are disabled by default
+    assert not getattr(pl.QueryOptFlags(), flag)
+
+    optflags = pl.QueryOptFlags(**{flag: True})
+    assert getattr(optflags, flag)
+    assert f"{flag}: True" in str(optflags)
+
+    setattr(optflags, flag, False)
+    assert not getattr(optflags, flag)
+    assert getattr(pl.QueryOptFlags.none(**{flag: True}), flag)
+
+    lf = pl.LazyFrame({"a": [1, 2, 3]}).join(
+        pl.LazyFrame({"a": [2, 3], "b": [4, 5]}), on="a"
+    )
+    assert_frame_equal(
+        lf.collect(optimizations=pl.QueryOptFlags(**{flag: True})),
+        lf.collect(),
+    )
@@ -5,7 +5,7 @@ from typing import Any
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+from polars.exceptions import SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -201,6 +201,26 @@ def test_unnest_table_function(array_keyword: str) -> None:
         )
 
 
+def test_unnest_table_function_with_ordinality() -> None:
+    with pl.SQLContext(df=None, eager=True) as ctx:
+        res = ctx.execute(
+            """
+            SELECT * FROM
+              UNNEST([1, 2, 3], ['ww', 'xx']) WITH ORDINALITY AS tbl (x, y, n)
+            """
+        )
+        assert_frame_equal(
+            res,
+            pl.DataFrame(
+                {
+                    "x": [1, 2, 3],
+                    "y": ["ww", "xx", None],
+                    "n": [1, 2, 3],
+                }
+            ),
+        )
+
+
 def test_unnest_table_function_errors() -> None:
     with pl.SQLContext(df=None, eager=True) as ctx:
         with pytest.raises(
@@ -226,9 +246,3 @@ def test_unnest_table_function_errors() -> None:
             match=r"UNNEST table must have an alias",
         ):
             ctx.execute("SELECT * FROM UNNEST([1, 2, 3])")
-
-        with pytest.raises(
-            SQLInterfaceError,
-            match=r"UNNEST tables do not \(yet\) support WITH OFFSET|ORDINALITY",
-        ):
-            ctx.execute("SELECT * FROM UNNEST([1, 2, 3]) tbl (colx) WITH OFFSET")
@@ -309,49 +309,57 @@ def test_join_misc_16255() -> None:
 
 
 @pytest.mark.parametrize(
-    "constraint", ["tbl.a != tbl.b", "tbl.a > tbl.b", "a >= b", "a < b", "b <= a"]
-)
-def test_non_equi_joins(constraint: str) -> None:
-    # no support (yet) for non equi-joins in polars joins
-    # TODO: integrate awareness of new IEJoin
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"only equi-join constraints \(combined with 'AND'\) are currently supported",
+    ("constraint", "expected"),
+    [
+        (
+            "t1.a != t2.b",
+            [(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 2), (3, 4)],
         ),
-        pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx,
-    ):
-        ctx.execute(
+        ("t1.a > t2.b", [(1, None), (2, None), (3, 2)]),
+        ("a >= b", [(1, None), (2, 2), (3, 2), (3, 3)]),
+        ("a < b", [(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]),
+        ("b <= a", [(1, None), (2, 2), (3, 2), (3, 3)]),
+    ],
+)
+def test_non_equi_joins(
+    constraint: str, expected: list[tuple[int, int | None]]
+) -> None:
+    with pl.SQLContext(
+        {
+            "t1": pl.DataFrame({"a": [1, 2, 3]}),
+            "t2": pl.DataFrame({"b": [4, 3, 2]}),
+        }
+    ) as ctx:
+        res = ctx.execute(
             f"""
-            SELECT *
-            FROM tbl
-            LEFT JOIN tbl ON {constraint}  -- not an equi-join
-            """
+            SELECT a, b
+            FROM t1
+            LEFT JOIN t2 ON {constraint}  -- not an equi-join
+            ORDER BY a, b
+            """,
+            eager=True,
         )
+    assert res.rows() == expected
 
 
 def test_implicit_joins() -> None:
-    # no support for this yet; ensure we catch it
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"not currently supported .* use explicit JOIN syntax instead",
-        ),
-        pl.SQLContext(
-            {
-                "tbl": pl.DataFrame(
-                    {"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]}
-                )
-            }
-        ) as ctx,
-    ):
-        ctx.execute(
+    with pl.SQLContext(
+        {"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]})}
+    ) as ctx:
+        res = ctx.execute(
             """
             SELECT t1.*
             FROM tbl AS t1, tbl AS t2
             WHERE t1.a = t2.b
-            """
+            ORDER BY t1.a
+            """,
+            eager=True,
         )
+    assert res.to_dict(as_series=False) == {
+        "a": [2, 3],
+        "b": [3, 2],
+        "c": ["y", "z"],
+    }
 
 
 @pytest.mark.parametrize(